@@ -0,0 +1,259 @@
+use crate::elements::{Extent, Function, LabelPlacement, Point};
+use crate::math::{self, Vec2D};
+use crate::misc::SegVecRoot;
+use crate::plotfn::{self, Node};
+
+// # Analysis
+
+/// The notable features of the visible portion of the graph of a [`Function`]. 
+/// See [`analyze`].
+///
+/// Each list of features is sorted by increasing input.
+pub struct Analysis {
+    pub zeros: Vec<Feature>,
+    pub extrema: Vec<Feature>,
+    pub inflections: Vec<Feature>
+}
+
+impl Analysis {
+    /// Iterates over all features, zeros first, then extrema, then inflection points.
+    pub fn features(&self) -> impl Iterator<Item = &Feature> {
+        self.zeros.iter().chain(self.extrema.iter()).chain(self.inflections.iter())
+    }
+
+    /// Constructs a [`Point`] marking each feature. If `labeled` is true, each point is labeled
+    /// with the coordinates of the feature, see [`Feature::label_tex`].
+    pub fn points(&self, labeled: bool) -> Vec<Point<'_>> {
+        self.features().map(|feature| feature.point(labeled)).collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Feature {
+    /// The input of the function at which the feature occurs. This is an x-coordinate for
+    /// [`crate::elements::FunctionKind::OfX`] and a y-coordinate for 
+    /// [`crate::elements::FunctionKind::OfY`].
+    pub input: f64,
+    pub pos: Vec2D,
+    pub kind: FeatureKind,
+
+    /// The coordinates of the feature rounded to [`LABEL_DECIMAL_PLACES`] decimal places and
+    /// formatted as TeX, for instance `(1.571, 1)`.
+    pub label_tex: String
+}
+
+impl Feature {
+    /// Constructs a [`Point`] marking this feature. If `labeled` is true, the point is labeled
+    /// with the coordinates of the feature, see [`Feature::label_tex`].
+    pub fn point(&self, labeled: bool) -> Point<'_> {
+        match labeled {
+            true => Point::new_labeled(self.pos, &self.label_tex, self.kind.placement()),
+            false => Point::new_default(self.pos),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FeatureKind { Zero, Minimum, Maximum, Inflection, Intersection }
+
+impl FeatureKind {
+    /// The placement of the label of a feature of this kind which is least likely to overlap
+    /// the graph.
+    pub fn placement(&self) -> LabelPlacement {
+        match self {
+            FeatureKind::Zero => LabelPlacement::BelowRight,
+            FeatureKind::Minimum => LabelPlacement::Below,
+            FeatureKind::Maximum => LabelPlacement::Above,
+            FeatureKind::Inflection => LabelPlacement::Right,
+            FeatureKind::Intersection => LabelPlacement::AboveLeft,
+        }
+    }
+}
+
+pub const LABEL_DECIMAL_PLACES: i32 = 3;
+
+/// Finds the zeros, local extrema and inflection points of the given function within the
+/// given extent.
+///
+/// The function is first sampled exactly as it would be plotted, see [`crate::plotfn::plotfn`].
+/// Features are bracketed by sign changes of the function, its first derivative and its second
+/// derivative, respectively, between adjacent samples. The brackets are then refined using
+/// Brent's method, see [`crate::math::brent`]. The derivatives are approximated numerically.
+///
+/// Since brackets never span a break in the sampled path, features are only found within 
+/// the visible portion of the graph, and no zero is reported at a discontinuity where the 
+/// function changes sign, such as `1/x` at zero. Zeros at which the graph touches but does
+/// not cross the axis, such as `x^2` at zero, are found as extrema whose value is zero.
+pub fn analyze(function: &Function, extent: &Extent) -> Analysis {
+    let runs = sample_runs(function, extent);
+    
+    let f = |t: f64| (function.eval)(t);
+    let df = |t: f64| math::derivative(&function.eval, t);
+    let ddf = |t: f64| math::second_derivative(&function.eval, t);
+
+    // Values of the derivatives which are smaller than the noise floor are considered to
+    // have no sign, otherwise rounding error would produce spurious extrema and inflection
+    // points in linear and constant functions.
+    let (domain, codomain) = function.extent_intervals(extent);
+    let domain_len = domain.len();
+    let codomain_len = codomain.len();
+    let df_floor = NOISE_FLOOR_FACTOR * codomain_len / domain_len;
+    let ddf_floor = NOISE_FLOOR_FACTOR * codomain_len / (domain_len * domain_len);
+    let tolerance = domain_len * ROOT_TOLERANCE_FACTOR;
+
+    let mut zeros: Vec<f64> = Vec::new();
+    let mut extrema: Vec<f64> = Vec::new();
+    let mut inflections: Vec<f64> = Vec::new();
+    for run in &runs {
+        find_sign_changes(&f, run, 0.0, tolerance, &mut zeros);
+        find_sign_changes(&df, run, df_floor, tolerance, &mut extrema);
+        find_sign_changes(&ddf, run, ddf_floor, tolerance, &mut inflections);
+    }
+
+    let mut analysis = Analysis { zeros: Vec::new(), extrema: Vec::new(), inflections: Vec::new() };
+    for input in extrema {
+        let kind = match ddf(input) < 0.0 {
+            true => FeatureKind::Maximum,
+            false => FeatureKind::Minimum,
+        };
+        // The graph may touch the axis at this extremum without crossing it.
+        let touches = f(input).abs() <= codomain_len * TOUCH_TOLERANCE_FACTOR;
+        if touches && !zeros.iter().any(|zero| (zero - input).abs() <= tolerance) {
+            zeros.push(input);
+        }
+        let feature = new_feature(function, input, kind);
+        analysis.extrema.push(feature);
+    }
+    zeros.sort_by(f64::total_cmp);
+    analysis.zeros = zeros.into_iter()
+        .map(|input| new_feature(function, input, FeatureKind::Zero))
+        .collect();
+    analysis.inflections = inflections.into_iter()
+        .map(|input| new_feature(function, input, FeatureKind::Inflection))
+        .collect();
+    return analysis;
+}
+
+// # Intersections
+
+/// Finds the points at which the graphs of `f` and `g` cross within the given extent.
+/// The returned features are of kind [`FeatureKind::Intersection`] and sorted by increasing input.
+///
+/// Both functions are sampled exactly as they would be plotted, see [`sample_runs`], and
+/// intersections are bracketed by sign changes of `f - g` between adjacent samples of either
+/// function. A bracket is only considered where both graphs are continuous, therefore no
+/// intersection is reported where one of the functions is undefined or jumps across the other,
+/// such as `1/x` and `-1/x` at zero. Points at which the graphs touch but do not cross are
+/// not found.
+///
+/// This procedure will panic if `f` and `g` are not of the same [`crate::elements::FunctionKind`].
+pub fn intersections(f: &Function, g: &Function, extent: &Extent) -> Vec<Feature> {
+    assert_eq!(f.kind, g.kind, "Cannot intersect a function of x with a function of y.");
+    
+    let f_runs = sample_runs(f, extent);
+    let g_runs = sample_runs(g, extent);
+    let (domain, _) = f.extent_intervals(extent);
+    let tolerance = domain.len() * ROOT_TOLERANCE_FACTOR;
+    let h = |t: f64| (f.eval)(t) - (g.eval)(t);
+
+    let mut roots: Vec<f64> = Vec::new();
+    for f_run in &f_runs {
+        for g_run in &g_runs {
+            // The interval on which both graphs are continuous.
+            let begin = f64::max(f_run[0], g_run[0]);
+            let end = f64::min(f_run[f_run.len() - 1], g_run[g_run.len() - 1]);
+            if begin >= end { continue; }
+            
+            let mut merged: Vec<f64> = f_run.iter().chain(g_run.iter()).copied()
+                .filter(|t| begin < *t && *t < end)
+                .collect();
+            merged.push(begin);
+            merged.push(end);
+            merged.sort_by(f64::total_cmp);
+            merged.dedup();
+            find_sign_changes(&h, &merged, 0.0, tolerance, &mut roots);
+        }
+    }
+    roots.sort_by(f64::total_cmp);
+    roots.dedup_by(|a, b| (*a - *b).abs() <= tolerance);
+
+    return roots.into_iter()
+        .map(|input| new_feature(f, input, FeatureKind::Intersection))
+        .filter(|feature| extent.brect.includes(&feature.pos))
+        .collect();
+}
+
+/// The width of the bracket at which Brent's method halts, relative to the length of the domain.
+pub const ROOT_TOLERANCE_FACTOR: f64 = 1e-12;
+
+/// The magnitude of the value of the function at an extremum below which the extremum is
+/// considered to be a zero, relative to the length of the codomain.
+pub const TOUCH_TOLERANCE_FACTOR: f64 = 1e-9;
+
+/// The magnitude of a derivative below which it is considered to be zero, relative to the
+/// average slope of the extent.
+pub const NOISE_FLOOR_FACTOR: f64 = 1e-6;
+
+/// Samples the given function as it would be plotted within the given extent and partitions
+/// the samples into the continuous stretches of the graph. Each stretch is a sorted list of
+/// inputs.
+pub fn sample_runs(function: &Function, extent: &Extent) -> Vec<Vec<f64>> {
+    let Some(params) = function.plotfn_params(extent) else { return Vec::new() };
+    let mut root: SegVecRoot<Node> = SegVecRoot::default();
+    let mut buf = root.extend();
+    plotfn::plotfn(&function.eval, &mut buf, params);
+    
+    let mut runs: Vec<Vec<f64>> = Vec::new();
+    for node in buf.as_slice().iter() {
+        match node {
+            Node::Break => runs.push(Vec::new()),
+            Node::Anchor(anchor) => {
+                if runs.is_empty() { runs.push(Vec::new()); }
+                runs.last_mut().unwrap().push(anchor.input);
+            },
+        }
+    }
+    runs.retain(|run| run.len() >= 2);
+    return runs;
+}
+
+/// Finds the roots of `g` between the samples of `run` by refining each sign change. Samples
+/// whose magnitude does not exceed `floor` are skipped, so a sign change is detected across
+/// any number of such samples.
+pub(crate) fn find_sign_changes(g: &dyn Fn(f64) -> f64, run: &[f64], floor: f64, tolerance: f64,
+    roots: &mut Vec<f64>)
+{
+    // The most recent sample with a definite sign.
+    let mut last: Option<(f64, f64)> = None;
+    for &input in run {
+        let value = g(input);
+        if value.is_nan() { 
+            last = None;
+            continue; 
+        }
+        if value.abs() <= floor { continue; }
+        if let Some((last_input, last_value)) = last {
+            if last_value.signum() != value.signum() {
+                if let Some(root) = math::brent(g, last_input, input, tolerance) {
+                    roots.push(root);
+                }
+            }
+        }
+        last = Some((input, value));
+    }
+}
+
+fn new_feature(function: &Function, input: f64, kind: FeatureKind) -> Feature {
+    let pos = function.coordinate_at(input);
+    let label_tex = format!("({}, {})", format_decimal(pos.x), format_decimal(pos.y));
+    Feature { input, pos, kind, label_tex }
+}
+
+/// Formats `value` rounded to [`LABEL_DECIMAL_PLACES`] decimal places without trailing zeros.
+pub(crate) fn format_decimal(value: f64) -> String {
+    let scale = 10f64.powi(LABEL_DECIMAL_PLACES);
+    let rounded = (value * scale).round() / scale;
+    // Avoid printing negative zero.
+    let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+    return rounded.to_string();
+}
@@ -2,10 +2,51 @@
 
 use crate::elements::axis::{AxisDefaultStyleClass, write_axis_default_style_class};
 use crate::elements::axis::{TickDefaultStyleClass, write_tick_default_style_class};
+use crate::elements::axis::{ArrowheadDefaultStyleClass, write_arrowhead_default_style_class};
+use crate::elements::axis::{Axis, Arrowhead, ArrowheadEnds, AxisPosition, SymbolicTickLabel};
 use crate::elements::function::{FunctionDefaultStyleClass, write_function_default_style_class};
-use crate::elements::{CoordinatePlane, Extent, TickLabelKind, Function, FunctionKind};
-use crate::math::{Vec2D, BoundingRect, ClosedInterval, NonDecreasing};
-use crate::plotfn::{self, PlotFnParams};
+use crate::elements::legend::{Legend, LegendPosition, Corner, SwatchKind};
+use crate::elements::legend::{LegendDefaultStyleClass, write_legend_default_style_class};
+use crate::elements::line::{Line, LineExtension, LineDefaultStyleClass, write_line_default_style_class};
+use crate::elements::point::{Point, PointFill, PointLabel, LabelPlacement};
+use crate::elements::point::{PointDefaultStyleClass, write_point_default_style_class};
+use crate::elements::point::{OpenPointDefaultStyleClass, write_open_point_default_style_class};
+use crate::elements::region::{Region, RegionDefaultStyleClass, write_region_default_style_class};
+use crate::elements::region::{RegionBoundaryDefaultStyleClass, write_region_boundary_default_style_class};
+use crate::elements::region::StrictRegionBoundaryDefaultStyleClass;
+use crate::elements::region::write_strict_region_boundary_default_style_class;
+use crate::elements::series::{Series, Marker, MarkerShape};
+use crate::elements::series::{SeriesDefaultStyleClass, write_series_default_style_class};
+use crate::elements::series::{MarkerDefaultStyleClass, write_marker_default_style_class};
+use crate::elements::series::ErrorBars;
+use crate::elements::series::{ErrorBarDefaultStyleClass, write_error_bar_default_style_class};
+use crate::elements::series::{ErrorBarCapDefaultStyleClass, write_error_bar_cap_default_style_class};
+use crate::elements::band::{Band, BandDefaultStyleClass, write_band_default_style_class};
+use crate::elements::histogram::Histogram;
+use crate::elements::bar_chart::{BarChart, Bar, BarDefaultStyleClass, write_bar_default_style_class};
+use crate::elements::stems::{Stems, StemDefaultStyleClass, write_stem_default_style_class};
+use crate::elements::annotation::Annotation;
+use crate::elements::sequence::{Sequence, SequenceDefaultStyleClass, write_sequence_default_style_class};
+use crate::elements::sequence::{Cobweb, CobwebDefaultStyleClass, write_cobweb_default_style_class};
+use crate::elements::epsilon_delta::EpsilonDelta;
+use crate::elements::epsilon_delta::{EpsilonDeltaBandDefaultStyleClass, EpsilonDeltaGuideDefaultStyleClass};
+use crate::elements::epsilon_delta::write_epsilon_delta_band_default_style_class;
+use crate::elements::epsilon_delta::write_epsilon_delta_guide_default_style_class;
+use crate::elements::geometry::{AngleMark, CongruenceMark, ParallelMark, MarkStroke};
+use crate::elements::domain_coloring::DomainColoring;
+use crate::elements::domain_coloring::{DomainColoringDefaultStyleClass, write_domain_coloring_default_style_class};
+use crate::elements::contour::{Contour, ContourDefaultStyleClass, write_contour_default_style_class};
+use crate::elements::contour::{ContourBandDefaultStyleClass, write_contour_band_default_style_class};
+use crate::elements::geometry::{GeometryMarkDefaultStyleClass, write_geometry_mark_default_style_class};
+use crate::elements::number_line::{NumberLine, NumberLineInterval};
+use crate::elements::number_line::{NumberLineIntervalDefaultStyleClass, write_number_line_interval_default_style_class};
+use crate::elements::figure::{Figure, Panel};
+use crate::elements::inset::{Inset, InsetDefaultStyleClass, write_inset_default_style_class};
+use crate::elements::inset::{InsetFrameDefaultStyleClass, write_inset_frame_default_style_class};
+use crate::elements::{CoordinatePlane, Extent, TickLabelKind, Function};
+use crate::elements::function::{CurveLabel, CurveLabelAnchor};
+use crate::math::{Vec2D, BoundingRect, ClosedInterval, NonDecreasing, Interpolation};
+use crate::plotfn;
 use crate::misc::{SegVec, SegVecRoot};
 use crate::typography::TeXRenderer;
 use crate::style::Stylesheet;
@@ -14,180 +55,1250 @@ pub fn codegen<T, W>(out: &mut W, cplane: &CoordinatePlane, stylesheet: Styleshe
 -> std::io::Result<()> 
 where T: TeXRenderer, W: std::io::Write
 {
-    let mut ctx = CodegenCtx { stylesheet, tex_renderer, out };
+    let mut ctx = CodegenCtx { stylesheet, tex_renderer, out, next_id: 0, contour_band_count: 0 };
     ctx.codegen_cplane(cplane)?;
     return Ok(());
 }
 
+/// Compiles the given [`Figure`] into a standalone SVG, in which each panel is a nested `<svg>`
+/// and a single stylesheet is shared by all panels.
+pub fn codegen_figure<T, W>(out: &mut W, figure: &Figure, stylesheet: Stylesheet, 
+    tex_renderer: &mut T)
+-> std::io::Result<()> 
+where T: TeXRenderer, W: std::io::Write
+{
+    let mut ctx = CodegenCtx { stylesheet, tex_renderer, out, next_id: 0, contour_band_count: 0 };
+    ctx.codegen_figure(figure)?;
+    return Ok(());
+}
+
+/// Compiles the given [`NumberLine`] into a standalone SVG, in the same manner as [`codegen`]
+/// compiles a [`CoordinatePlane`].
+pub fn codegen_number_line<T, W>(out: &mut W, line: &NumberLine, stylesheet: Stylesheet, 
+    tex_renderer: &mut T)
+-> std::io::Result<()> 
+where T: TeXRenderer, W: std::io::Write
+{
+    let mut ctx = CodegenCtx { stylesheet, tex_renderer, out, next_id: 0, contour_band_count: 0 };
+    ctx.codegen_number_line(line)?;
+    return Ok(());
+}
+
 struct CodegenCtx<'a, T, W> 
 where T: TeXRenderer, 
       W: std::io::Write
 { 
     stylesheet: Stylesheet<'a>,
     tex_renderer: &'a mut T,
-    out: &'a mut W
+    out: &'a mut W,
+
+    /// The numeric suffix of the next unique `id` attribute. Elements which are referenced
+    /// by other elements, like `<marker>` definitions, are identified using [`Self::gen_id`].
+    next_id: usize,
+
+    /// The greatest number of bands of any filled contour map drawn so far which is assigned
+    /// the default style class. The default stylesheet grades the opacity of this many bands.
+    contour_band_count: usize
 }
 
 impl<'a, T, W> CodegenCtx<'a, T, W> 
 where T: TeXRenderer,
       W: std::io::Write
 {
-    fn codegen_stylesheet(&mut self, cplane: &CoordinatePlane) -> std::io::Result<()> {
-        // Compute which style classes from the default stylesheet are in use.
-        let def_fn_count = cplane.fns.iter().filter(|f| f.apply_default_style_class).count();
-        let mut def_axis_count = 0usize;
-        let mut def_tick_count = 0usize;
-        if let Some(axis) = &cplane.horizontal_axis {
-            if axis.apply_default_style_class {
-                def_axis_count += 1;
-            }
-            if axis.tick.apply_default_style_class {
-                def_tick_count += 1;
-            }
-        }
-        if let Some(axis) = &cplane.vertical_axis {
-            if axis.apply_default_style_class {
-                def_axis_count += 1;
-            }
-            if axis.tick.apply_default_style_class {
-                def_tick_count += 1;
-            }
-        }
-        let total_def = def_fn_count + def_axis_count + def_tick_count;
+    fn gen_id(&mut self, prefix: &str) -> String {
+        let id = format!("{}-{}", prefix, self.next_id);
+        self.next_id += 1;
+        return id;
+    }
 
+    fn codegen_stylesheet(&mut self, usage: &DefaultStyleClassUsage) -> std::io::Result<()> {
         // In this case we need not print a style tag at all.
-        if total_def == 0 && self.stylesheet.custom.is_none() { return Ok(()); };
+        if usage.total() == 0 && self.stylesheet.custom.is_none() { return Ok(()); };
 
         write!(self.out, "<style>")?;
         write!(self.out, "<![CDATA[")?;
 
         // Write the default stylesheet.
-        if def_fn_count > 0 {
-            write_function_default_style_class(self.out, &self.stylesheet.defaults.function)?;
+        let defaults = &self.stylesheet.defaults;
+        if usage.function > 0 {
+            write_function_default_style_class(self.out, &defaults.function)?;
+        }
+        if usage.axis > 0 {
+            write_axis_default_style_class(self.out, &defaults.axis)?;
+        }
+        if usage.tick > 0 {
+            write_tick_default_style_class(self.out, &defaults.tick)?;
+        }
+        if usage.arrowhead > 0 {
+            write_arrowhead_default_style_class(self.out, &defaults.arrowhead)?;
         }
-        if def_axis_count > 0 {
-            write_axis_default_style_class(self.out, &self.stylesheet.defaults.axis)?;
+        if usage.legend > 0 {
+            write_legend_default_style_class(self.out, &defaults.legend)?;
         }
-        if def_tick_count > 0 {
-            write_tick_default_style_class(self.out, &self.stylesheet.defaults.tick)?;
+        if usage.line > 0 {
+            write_line_default_style_class(self.out, &defaults.line)?;
+        }
+        if usage.point > 0 {
+            write_point_default_style_class(self.out, &defaults.point)?;
+        }
+        // The open point class must follow the point class so that it takes precedence.
+        if usage.open_point > 0 {
+            write_open_point_default_style_class(self.out, &defaults.open_point)?;
+        }
+        if usage.region > 0 {
+            write_region_default_style_class(self.out, &defaults.region)?;
+        }
+        if usage.region_boundary > 0 {
+            write_region_boundary_default_style_class(self.out, &defaults.region_boundary)?;
+        }
+        // The strict boundary class must follow the boundary class so that it takes precedence.
+        if usage.strict_region_boundary > 0 {
+            write_strict_region_boundary_default_style_class(self.out,
+                &defaults.strict_region_boundary)?;
+        }
+        if usage.series > 0 {
+            write_series_default_style_class(self.out, &defaults.series)?;
+        }
+        if usage.marker > 0 {
+            write_marker_default_style_class(self.out, &defaults.marker)?;
+        }
+        if usage.error_bar > 0 {
+            write_error_bar_default_style_class(self.out, &defaults.error_bar)?;
+        }
+        if usage.error_bar_cap > 0 {
+            write_error_bar_cap_default_style_class(self.out, &defaults.error_bar_cap)?;
+        }
+        if usage.band > 0 {
+            write_band_default_style_class(self.out, &defaults.band)?;
+        }
+        if usage.bar > 0 {
+            write_bar_default_style_class(self.out, &defaults.bar)?;
+        }
+        if usage.stem > 0 {
+            write_stem_default_style_class(self.out, &defaults.stem)?;
+        }
+        if usage.sequence > 0 {
+            write_sequence_default_style_class(self.out, &defaults.sequence)?;
+        }
+        if usage.cobweb > 0 {
+            write_cobweb_default_style_class(self.out, &defaults.cobweb)?;
+        }
+        if usage.epsilon_delta_band > 0 {
+            write_epsilon_delta_band_default_style_class(self.out, &defaults.epsilon_delta_band)?;
+        }
+        if usage.epsilon_delta_guide > 0 {
+            write_epsilon_delta_guide_default_style_class(self.out, 
+                &defaults.epsilon_delta_guide)?;
+        }
+        if usage.geometry_mark > 0 {
+            write_geometry_mark_default_style_class(self.out, &defaults.geometry_mark)?;
+        }
+        if usage.domain_coloring > 0 {
+            write_domain_coloring_default_style_class(self.out, &defaults.domain_coloring)?;
+        }
+        if usage.contour > 0 {
+            write_contour_default_style_class(self.out, &defaults.contour)?;
+        }
+        if usage.contour_band > 0 {
+            write_contour_band_default_style_class(self.out, &defaults.contour_band, 
+                self.contour_band_count)?;
+        }
+        if usage.number_line_interval > 0 {
+            write_number_line_interval_default_style_class(self.out, 
+                &defaults.number_line_interval)?;
+        }
+        if usage.inset > 0 {
+            write_inset_default_style_class(self.out, &defaults.inset)?;
+        }
+        if usage.inset_frame > 0 {
+            write_inset_frame_default_style_class(self.out, &defaults.inset_frame)?;
         }
         
         // Write the typographic stylesheet.
         self.tex_renderer.dump_css(self.out)?;
 
-        // Write the custom stylesheet.
-        if let Some(custom_styles) = self.stylesheet.custom {
-            write!(self.out, "{}", custom_styles)?;
+        // Write the custom stylesheet.
+        if let Some(custom_styles) = self.stylesheet.custom {
+            write!(self.out, "{}", custom_styles)?;
+        }
+
+        write!(self.out, "]]>")?;
+        write!(self.out, "</style>")?;
+        
+        return Ok(())
+    }
+    
+    fn codegen_cplane(&mut self, cplane: &CoordinatePlane) 
+    -> std::io::Result<()>
+    { 
+        if cplane.extent.area() == 0.0 { return Ok(()); }
+        let viewbox = calc_viewbox(cplane);
+    
+        write!(self.out, "<svg")?;
+        write!(self.out, " viewBox=\"{} {} {} {}\"", viewbox.x, viewbox.y, viewbox.width,
+            viewbox.height)?;
+        write!(self.out, " xmlns=\"http://www.w3.org/2000/svg\"")?;
+        write!(self.out, " preserveAspectRatio=\"xMinYMin meet\"")?;
+        write!(self.out, ">")?;    
+        self.codegen_cplane_elements(cplane, &cplane.extent, &viewbox)?;
+
+        // Make sure to generate the stylesheet last. The TeXRenderer may generate
+        // a specially tailored minimal stylesheet based on the requests it received over
+        // the duratino of the compilation.
+        let mut usage = DefaultStyleClassUsage::default();
+        usage.tally(cplane);
+        self.codegen_stylesheet(&usage)?;
+        write!(self.out, "</svg>")?;
+        return Ok(())
+    }
+
+    /// Emits the elements of the given plane drawn over `extent`, which is ordinarily the
+    /// extent of the plane itself, without the enclosing `<svg>` element or the stylesheet.
+    /// `viewbox` is the visible area of the enclosing `<svg>` element.
+    fn codegen_cplane_elements(&mut self, cplane: &CoordinatePlane, extent: &Extent,
+        viewbox: &ViewBox)
+    -> std::io::Result<()>
+    {
+        for coloring in &cplane.domain_colorings {
+            self.codegen_domain_coloring(extent, coloring)?;
+        }
+        for region in &cplane.regions { self.codegen_region(extent, region)?; }
+        let mut contour_labels: Vec<Annotation> = Vec::new();
+        for contour in &cplane.contours {
+            contour_labels.extend(self.codegen_contour(extent, contour)?);
+        }
+        for band in &cplane.bands { self.codegen_band(extent, band)?; }
+        for histogram in &cplane.histograms { self.codegen_histogram(extent, histogram)?; }
+        for chart in &cplane.bar_charts { self.codegen_bar_chart(extent, chart)?; }
+        for figure in &cplane.epsilon_deltas {
+            self.codegen_epsilon_delta_bands(extent, figure)?;
+        }
+         {
+            let mut buf: SegVecRoot<plotfn::Node> = SegVecRoot::default();
+            for function in &cplane.fns {
+                self.codegen_fnplot(extent, viewbox, function, buf.extend())?;
+            }
+            for piece in cplane.piecewise.iter().flat_map(|p| p.pieces.iter()) {
+                self.codegen_fnplot(extent, viewbox, &piece.function, buf.extend())?;
+            }
+            for figure in &cplane.epsilon_deltas {
+                self.codegen_fnplot(extent, viewbox, &figure.function, buf.extend())?;
+            }
+        }
+        for series in &cplane.series { self.codegen_series(extent, series)?; }
+        for stems in &cplane.stems { self.codegen_stems(extent, stems)?; }
+        for sequence in &cplane.sequences { self.codegen_sequence(extent, sequence)?; }
+        for cobweb in &cplane.cobwebs { self.codegen_cobweb(extent, cobweb)?; }
+        for figure in &cplane.epsilon_deltas {
+            self.codegen_epsilon_delta_guides(extent, figure)?;
+        }
+        for line in &cplane.lines { self.codegen_line(extent, line)?; }
+        for mark in &cplane.angle_marks { self.codegen_angle_mark(extent, mark)?; }
+        for mark in &cplane.congruence_marks { 
+            self.codegen_congruence_mark(extent, mark)?; 
+        }
+        for mark in &cplane.parallel_marks { self.codegen_parallel_mark(extent, mark)?; }
+        for axis in cplane.horizontal_axes() { self.codegen_horizontal_axis(extent, axis)?; }
+        for axis in cplane.vertical_axes() { self.codegen_vertical_axis(extent, axis)?; }
+        for axis in cplane.horizontal_axes() { 
+            self.codegen_horizontal_axis_ticks(extent, axis)?; 
+        }
+        for axis in cplane.vertical_axes() { self.codegen_vertical_axis_ticks(extent, axis)?; }
+        
+        let vertical_axis_brect = calc_vertical_axis_brect(cplane, extent);
+        for axis in cplane.horizontal_axes() {
+            self.codegen_horizontal_axis_tick_labels(extent, axis, vertical_axis_brect)?;
+        }
+        let horizontal_axis_brect = calc_horizontal_axis_brect(cplane, extent);
+        for axis in cplane.vertical_axes() {
+            self.codegen_vertical_axis_tick_labels(extent, axis, horizontal_axis_brect)?;
+        }
+        for axis in cplane.horizontal_axes() {
+            self.codegen_horizontal_axis_title(extent, axis)?;
+        }
+        for axis in cplane.vertical_axes() { self.codegen_vertical_axis_title(extent, axis)?; }
+        for point in &cplane.points { self.codegen_point(extent, point)?; }
+        for piecewise in &cplane.piecewise {
+            for point in piecewise.endpoints() { self.codegen_point(extent, &point)?; }
+        }
+        for stems in &cplane.stems {
+            for point in stems.heads() { self.codegen_point(extent, &point)?; }
+        }
+        for sequence in &cplane.sequences {
+            for point in sequence.dots() { self.codegen_point(extent, &point)?; }
+        }
+        for figure in &cplane.epsilon_deltas {
+            self.codegen_point(extent, &figure.point())?;
+        }
+        for annotation in &cplane.annotations {
+            self.codegen_annotation(extent, annotation)?;
+        }
+        for figure in &cplane.epsilon_deltas {
+            for label in figure.labels(&extent.brect) {
+                self.codegen_annotation(extent, &label)?;
+            }
+        }
+        for mark in &cplane.angle_marks { self.codegen_angle_mark_label(extent, mark)?; }
+        for label in &contour_labels { self.codegen_annotation(extent, label)?; }
+        for inset in &cplane.insets { self.codegen_inset(extent, inset, &cplane.fns)?; }
+        if let Some(legend) = &cplane.legend {
+            self.codegen_legend(extent, legend)?;
+        }
+        return Ok(())
+    }
+
+    fn codegen_figure(&mut self, figure: &Figure) -> std::io::Result<()> {
+        let placed: Vec<(&Panel, BoundingRect)> = figure.panels.iter()
+            .zip(calc_panel_rects(figure))
+            .filter_map(|(panel, rect)| Some((panel, rect?)))
+            .collect();
+        if placed.is_empty() { return Ok(()); }
+        let min_x = placed.iter().map(|(_, rect)| rect.x.begin()).fold(f64::INFINITY, f64::min);
+        let max_x = placed.iter().map(|(_, rect)| rect.x.end()).fold(f64::NEG_INFINITY, f64::max);
+        let min_y = placed.iter().map(|(panel, rect)| rect.y.begin() - panel.title_height())
+            .fold(f64::INFINITY, f64::min);
+        let max_y = placed.iter().map(|(_, rect)| rect.y.end()).fold(f64::NEG_INFINITY, f64::max);
+
+        write!(self.out, "<svg")?;
+        write!(self.out, " viewBox=\"{} {} {} {}\"", min_x, min_y, max_x - min_x, max_y - min_y)?;
+        write!(self.out, " xmlns=\"http://www.w3.org/2000/svg\"")?;
+        write!(self.out, " preserveAspectRatio=\"xMinYMin meet\"")?;
+        write!(self.out, ">")?;
+        let mut usage = DefaultStyleClassUsage::default();
+        for (panel, rect) in &placed {
+            let viewbox = calc_viewbox(&panel.cplane);
+            write!(self.out, "<svg")?;
+            write!(self.out, " x=\"{}\"", rect.x.begin())?;
+            write!(self.out, " y=\"{}\"", rect.y.begin())?;
+            write!(self.out, " width=\"{}\"", rect.x.len())?;
+            write!(self.out, " height=\"{}\"", rect.y.len())?;
+            write!(self.out, " viewBox=\"{} {} {} {}\"", viewbox.x, viewbox.y, viewbox.width,
+                viewbox.height)?;
+            write!(self.out, " preserveAspectRatio=\"xMidYMid meet\"")?;
+            write!(self.out, ">")?;
+            self.codegen_cplane_elements(&panel.cplane, &panel.cplane.extent, &viewbox)?;
+            write!(self.out, "</svg>")?;
+            if let Some(title) = &panel.title {
+                let label = PointLabel {
+                    tex: title.tex,
+                    placement: LabelPlacement::Above,
+                    typography_height: title.typography_height,
+                    width: title.width
+                };
+                let center = Vec2D { x: 0.5 * (rect.x.begin() + rect.x.end()), y: rect.y.begin() };
+                self.codegen_point_label(center, 0.0, &label)?;
+            }
+            usage.tally(&panel.cplane);
+        }
+
+        // The panels share a single stylesheet, which covers the default style classes used
+        // by any of them.
+        self.codegen_stylesheet(&usage)?;
+        write!(self.out, "</svg>")?;
+        return Ok(())
+    }
+
+    
+    fn codegen_number_line(&mut self, line: &NumberLine) -> std::io::Result<()> {
+        let extent = line.extent();
+        if extent.area() == 0.0 { return Ok(()); }
+        let bound = extent.transform().apply(extent.brect.top_right());
+
+        write!(self.out, "<svg")?;
+        write!(self.out, " viewBox=\"0 0 {} {}\"", bound.x, bound.y)?;
+        write!(self.out, " xmlns=\"http://www.w3.org/2000/svg\"")?;
+        write!(self.out, " preserveAspectRatio=\"xMinYMin meet\"")?;
+        write!(self.out, ">")?;
+        self.codegen_horizontal_axis(&extent, &line.axis)?;
+        self.codegen_horizontal_axis_ticks(&extent, &line.axis)?;
+        self.codegen_horizontal_axis_tick_labels(&extent, &line.axis, None)?;
+        self.codegen_horizontal_axis_title(&extent, &line.axis)?;
+        for interval in &line.intervals {
+            self.codegen_number_line_interval(&extent, interval)?;
+        }
+        for interval in &line.intervals {
+            for dot in interval.endpoints(line.range) { self.codegen_point(&extent, &dot)?; }
+        }
+
+        let mut usage = DefaultStyleClassUsage::default();
+        usage.tally_number_line(line);
+        self.codegen_stylesheet(&usage)?;
+        write!(self.out, "</svg>")?;
+        return Ok(())
+    }
+
+    /// Emits the segment of the given interval which lies within the extent, capped by 
+    /// arrowheads at the ends which continue beyond it.
+    fn codegen_number_line_interval(&mut self, extent: &Extent, interval: &NumberLineInterval)
+    -> std::io::Result<()>
+    {
+        let Some((begin, end)) = interval.segment(extent.brect.x) else { return Ok(()) };
+        let transform = extent.transform();
+        let marker_id = match interval.arrowhead(extent.brect.x) {
+            Some(arrowhead) => Some((self.codegen_arrowhead_marker(&arrowhead)?, arrowhead.ends)),
+            None => None,
+        };
+        let start = transform.apply(Vec2D { x: begin, y: 0.0 });
+        let stop = transform.apply(Vec2D { x: end, y: 0.0 });
+        write_line_prefix(self.out, start, stop)?;
+        write!(self.out, " class=\"")?;
+        if interval.apply_default_style_class {
+            write!(self.out, " {}", NumberLineIntervalDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = interval.style_class {
+            write!(self.out, " {}", class)?;
+        }
+        write!(self.out, "\"")?;
+        if let Some((id, ends)) = marker_id {
+            write_marker_attributes(self.out, &id, ends)?;
+        }
+        write!(self.out, "/>")?;
+        return Ok(());
+    }
+
+    fn codegen_fnplot(&mut self, extent: &Extent, viewbox: &ViewBox, function: &Function,
+        mut buf: SegVec<plotfn::Node>) 
+    -> std::io::Result<()>
+    {    
+        // The domain of the function does not meet the extent, so there is nothing to draw.
+        let Some(params) = function.plotfn_params(extent) else { return Ok(()) };
+        let transform = extent.transform();
+        let stats = plotfn::plotfn(&function.eval, &mut buf, params); 
+        // println!("{:#?}", stats);
+           
+        write!(self.out, "<path")?;
+        write!(self.out, " class=\"")?;
+        if function.apply_default_style_class {
+            write!(self.out, " {}", FunctionDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = function.style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, " d=\"")?;
+    
+        let mut broken = true;
+        for node in buf.as_slice().iter() {
+            match node {
+                plotfn::Node::Break => broken = true,
+                plotfn::Node::Anchor(anchor) => {
+                    match broken {
+                        true => write!(self.out, "M")?,
+                        false => write!(self.out, "L")?,
+                    }
+                    broken = false;
+    
+                    let coord = transform.apply(function.coordinate_at(anchor.input));
+                    write!(self.out, " {} {} ", coord.x, coord.y)?;
+                },
+            }
+        }
+        
+        write!(self.out, "\"/>")?;
+
+        if let Some(label) = &function.label {
+            self.codegen_curve_label(extent, viewbox, function, label, &buf.as_slice())?;
+        }
+        return Ok(())
+    }
+
+    /// Emits the label of a function plot. `nodes` is the sampled path of the function as
+    /// computed by [`plotfn::plotfn`]. The label is kept within `viewbox` where possible.
+    fn codegen_curve_label(&mut self, extent: &Extent, viewbox: &ViewBox, function: &Function,
+        label: &CurveLabel, nodes: &[plotfn::Node])
+    -> std::io::Result<()>
+    {
+        let transform = extent.transform();
+        // Partition the sampled path into its continuous stretches. Each anchor is paired
+        // with its normalized coordinate.
+        let mut runs: Vec<Vec<(f64, Vec2D)>> = Vec::new();
+        for node in nodes {
+            match node {
+                plotfn::Node::Break => runs.push(Vec::new()),
+                plotfn::Node::Anchor(anchor) => {
+                    if runs.is_empty() { runs.push(Vec::new()); }
+                    let coord = transform.apply(function.coordinate_at(anchor.input));
+                    runs.last_mut().unwrap().push((anchor.input, coord));
+                },
+            }
+        }
+
+        // The anchor point and the direction of the path at the anchor point.
+        let mut found: Option<(Vec2D, Vec2D)> = None;
+        match label.anchor {
+            CurveLabelAnchor::At(input) => {
+                'search: for run in &runs {
+                    for pair in run.windows(2) {
+                        let ((a_input, a), (b_input, b)) = (pair[0], pair[1]);
+                        if input < f64::min(a_input, b_input) { continue; }
+                        if input > f64::max(a_input, b_input) { continue; }
+                        let t = match a_input == b_input {
+                            true => 0.0,
+                            false => (input - a_input) / (b_input - a_input),
+                        };
+                        let point = Vec2D { x: a.x + t * (b.x - a.x), y: a.y + t * (b.y - a.y) };
+                        found = Some((point, Vec2D { x: b.x - a.x, y: b.y - a.y }));
+                        break 'search;
+                    }
+                }
+            },
+            CurveLabelAnchor::Auto => {
+                let arc_len = |run: &Vec<(f64, Vec2D)>| -> f64 {
+                    run.windows(2).map(|pair| (pair[1].1 - pair[0].1).len()).sum()
+                };
+                let longest = runs.iter()
+                    .filter(|run| run.len() >= 2)
+                    .max_by(|a, b| arc_len(a).total_cmp(&arc_len(b)));
+                if let Some(run) = longest {
+                    let mut remaining = 0.5 * arc_len(run);
+                    for pair in run.windows(2) {
+                        let (a, b) = (pair[0].1, pair[1].1);
+                        let len = (b - a).len();
+                        if remaining <= len {
+                            let t = if len == 0.0 { 0.0 } else { remaining / len };
+                            let point = Vec2D { x: a.x + t * (b.x - a.x), y: a.y + t * (b.y - a.y) };
+                            found = Some((point, Vec2D { x: b.x - a.x, y: b.y - a.y }));
+                            break;
+                        }
+                        remaining -= len;
+                    }
+                }
+            },
+        }
+        let Some((point, direction)) = found else { return Ok(()); };
+
+        // Prefer the side of the path which faces upward in the viewport, or if the path is 
+        // vertical, the side which faces leftward.
+        let direction_len = f64::hypot(direction.x, direction.y);
+        let mut normal = match direction_len == 0.0 {
+            true => Vec2D { x: 0.0, y: -1.0 },
+            false => Vec2D { x: direction.y / direction_len, y: -direction.x / direction_len },
+        };
+        if normal.y > 0.0 || (normal.y == 0.0 && normal.x > 0.0) {
+            normal = Vec2D { x: -normal.x, y: -normal.y };
+        }
+
+        // The distance from the center of the label to its edge along the normal.
+        let support = 0.5 * ((normal.x.abs() * label.width) 
+            + (normal.y.abs() * label.typography_height));
+        let place = |normal: Vec2D| Vec2D {
+            x: point.x + normal.x * (label.offset + support),
+            y: point.y + normal.y * (label.offset + support)
+        };
+        let fits = |center: Vec2D| {
+            center.x - (0.5 * label.width) >= viewbox.x
+                && center.x + (0.5 * label.width) <= viewbox.x + viewbox.width
+                && center.y - (0.5 * label.typography_height) >= viewbox.y
+                && center.y + (0.5 * label.typography_height) <= viewbox.y + viewbox.height
+        };
+        let mut center = place(normal);
+        if !fits(center) {
+            let opposite = place(Vec2D { x: -normal.x, y: -normal.y });
+            if fits(opposite) { center = opposite; }
+        }
+        
+        write!(self.out, "<svg")?;
+        write!(self.out, " x=\"{}\"", center.x - (0.5 * label.width))?;
+        write!(self.out, " y=\"{}\"", center.y - (0.5 * label.typography_height))?;
+        write!(self.out, " width=\"{}\"", label.width)?;
+        write!(self.out, " height=\"{}\"", label.typography_height)?;
+        write!(self.out, ">")?;
+        self.tex_renderer.render_str(label.tex, self.out, None)?;
+        write!(self.out, "</svg>")?;
+        return Ok(());
+    }
+
+    fn codegen_line(&mut self, extent: &Extent, line: &Line) -> std::io::Result<()> {
+        let transform = extent.transform();
+        let clipped = match line.extension {
+            LineExtension::Segment => extent.brect.clip_segment(line.a, line.b),
+            LineExtension::Infinite => extent.brect.clip_line(line.a, line.b),
+        };
+        let Some((a, b)) = clipped else { return Ok(()); };
+        if !a.x.is_finite() || !a.y.is_finite() || !b.x.is_finite() || !b.y.is_finite() { 
+            return Ok(()); 
+        }
+        
+        write_line_prefix(self.out, transform.apply(a), transform.apply(b))?;
+        write!(self.out, " class=\"")?;
+        if line.apply_default_style_class {
+            write!(self.out, " {}", LineDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = line.style_class {
+            write!(self.out, " {}", class)?;
+        }
+        write!(self.out, "\"")?;
+        write!(self.out, "/>")?;
+        return Ok(());
+    }
+
+    fn codegen_region(&mut self, extent: &Extent, region: &Region) -> std::io::Result<()> {
+        let trace = region.trace(extent);
+        if !trace.fill.is_empty() {
+            write!(self.out, "<path")?;
+            write!(self.out, " class=\"")?;
+            if region.apply_default_style_class {
+                write!(self.out, " {}", RegionDefaultStyleClass::NAME)?;
+            }
+            if let Some(class) = region.style_class { write!(self.out, " {}", class)?; }
+            write!(self.out, "\"")?;
+            write!(self.out, " d=\"")?;
+            for polygon in &trace.fill {
+                self.write_polyline(extent, polygon)?;
+                write!(self.out, "Z ")?;
+            }
+            write!(self.out, "\"/>")?;
+        }
+        
+        if !region.draw_boundary || trace.boundary.is_empty() { return Ok(()); }
+        write!(self.out, "<path")?;
+        write!(self.out, " class=\"")?;
+        if region.apply_default_style_class {
+            write!(self.out, " {}", RegionBoundaryDefaultStyleClass::NAME)?;
+            if region.is_strict() {
+                write!(self.out, " {}", StrictRegionBoundaryDefaultStyleClass::NAME)?;
+            }
+        }
+        if let Some(class) = region.boundary_style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, " d=\"")?;
+        for polyline in &trace.boundary { self.write_polyline(extent, polyline)?; }
+        write!(self.out, "\"/>")?;
+        return Ok(());
+    }
+
+    fn codegen_series(&mut self, extent: &Extent, series: &Series) -> std::io::Result<()> {
+        let transform = extent.transform();
+        match series.interpolation {
+            _ if !series.mode.has_polyline() => {},
+            Interpolation::Linear => self.codegen_series_polyline(extent, series)?,
+            _ => self.codegen_series_splines(extent, series)?,
+        }
+        if let Some(error_bars) = &series.error_bars {
+            self.codegen_error_bars(extent, &series.samples, error_bars)?;
+        }
+
+        if !series.mode.has_markers() { return Ok(()); }
+        if series.markers(&extent.brect).next().is_none() { return Ok(()); }
+        let id = self.gen_id("yapl-marker");
+        write!(self.out, "<defs>")?;
+        self.codegen_marker_shape(&series.marker, &id)?;
+        write!(self.out, "</defs>")?;
+        for pos in series.markers(&extent.brect) {
+            let center = transform.apply(pos);
+            write!(self.out, "<use")?;
+            write!(self.out, " href=\"#{}\"", id)?;
+            write!(self.out, " x=\"{}\"", center.x)?;
+            write!(self.out, " y=\"{}\"", center.y)?;
+            write!(self.out, " class=\"")?;
+            if series.marker.apply_default_style_class {
+                write!(self.out, " {}", MarkerDefaultStyleClass::NAME)?;
+            }
+            if let Some(class) = series.marker.style_class { write!(self.out, " {}", class)?; }
+            write!(self.out, "\"")?;
+            write!(self.out, "/>")?;
+        }
+        return Ok(());
+    }
+
+    fn codegen_error_bars(&mut self, extent: &Extent, samples: &[(f64, f64)], 
+        error_bars: &ErrorBars)
+    -> std::io::Result<()>
+    {
+        let bars = error_bars.bars(samples, &extent.brect);
+        if bars.is_empty() { return Ok(()); }
+        let transform = extent.transform();
+        write!(self.out, "<path")?;
+        write!(self.out, " class=\"")?;
+        if error_bars.apply_default_style_class {
+            write!(self.out, " {}", ErrorBarDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = error_bars.style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, " d=\"")?;
+        for bar in &bars { self.write_polyline(extent, &[bar.a, bar.b])?; }
+        write!(self.out, "\"/>")?;
+
+        if !bars.iter().any(|bar| bar.caps[0] || bar.caps[1]) { return Ok(()); }
+        write!(self.out, "<path")?;
+        write!(self.out, " class=\"")?;
+        if error_bars.apply_default_style_class {
+            write!(self.out, " {}", ErrorBarCapDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = error_bars.style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, " d=\"")?;
+        let h = 0.5 * error_bars.cap_len;
+        for bar in &bars {
+            for (end, capped) in [bar.a, bar.b].into_iter().zip(bar.caps) {
+                if !capped { continue; }
+                let end = transform.apply(end);
+                let (dx, dy) = match bar.is_horizontal {
+                    true => (0.0, h),
+                    false => (h, 0.0),
+                };
+                write!(self.out, "M {} {} L {} {} ", end.x - dx, end.y - dy, end.x + dx, end.y + dy)?;
+            }
+        }
+        write!(self.out, "\"/>")?;
+        return Ok(());
+    }
+
+    /// Emits the filled bands, if any, followed by the level curves of the given contour map.
+    /// The labels are returned rather than emitted, so that they are drawn together with the
+    /// annotations, above all other elements.
+    fn codegen_contour(&mut self, extent: &Extent, contour: &Contour)
+    -> std::io::Result<Vec<Annotation<'static>>>
+    {
+        let trace = contour.trace(extent);
+        if contour.filled && contour.apply_default_style_class {
+            self.contour_band_count = usize::max(self.contour_band_count, trace.levels.len() + 1);
+        }
+        for (k, polygons) in trace.bands.iter().enumerate() {
+            if polygons.is_empty() { continue; }
+            write!(self.out, "<path")?;
+            write!(self.out, " class=\"")?;
+            if contour.apply_default_style_class {
+                write!(self.out, " {}", ContourBandDefaultStyleClass::NAME)?;
+            }
+            write!(self.out, " {}{}", Contour::BAND_CLASS_PREFIX, k)?;
+            if let Some(class) = contour.band_style_class { write!(self.out, " {}", class)?; }
+            write!(self.out, "\"")?;
+            write!(self.out, " d=\"")?;
+            for polygon in polygons {
+                self.write_polyline(extent, polygon)?;
+                write!(self.out, "Z ")?;
+            }
+            write!(self.out, "\"/>")?;
+        }
+        for (i, polylines) in trace.lines.iter().enumerate() {
+            if polylines.is_empty() { continue; }
+            write!(self.out, "<path")?;
+            write!(self.out, " class=\"")?;
+            if contour.apply_default_style_class {
+                write!(self.out, " {}", ContourDefaultStyleClass::NAME)?;
+            }
+            write!(self.out, " {}{}", Contour::LEVEL_CLASS_PREFIX, i)?;
+            if let Some(class) = contour.style_class { write!(self.out, " {}", class)?; }
+            write!(self.out, "\"")?;
+            write!(self.out, " d=\"")?;
+            for polyline in polylines { self.write_polyline(extent, polyline)?; }
+            write!(self.out, "\"/>")?;
+        }
+        return Ok(trace.labels.into_iter().map(|(_, label)| label).collect());
+    }
+
+    fn codegen_band(&mut self, extent: &Extent, band: &Band) -> std::io::Result<()> {
+        let polygons = band.polygons(extent);
+        if polygons.is_empty() { return Ok(()); }
+        write!(self.out, "<path")?;
+        write!(self.out, " class=\"")?;
+        if band.apply_default_style_class {
+            write!(self.out, " {}", BandDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = band.style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, " d=\"")?;
+        for polygon in &polygons {
+            self.write_polyline(extent, polygon)?;
+            write!(self.out, "Z ")?;
+        }
+        write!(self.out, "\"/>")?;
+        return Ok(());
+    }
+
+    fn codegen_histogram(&mut self, extent: &Extent, histogram: &Histogram) -> std::io::Result<()> {
+        for (bar, rect) in histogram.rects(&extent.brect) {
+            self.codegen_bar(extent, &bar, rect, histogram.apply_default_style_class,
+                histogram.style_class)?;
+        }
+        return Ok(());
+    }
+
+    fn codegen_bar_chart(&mut self, extent: &Extent, chart: &BarChart) -> std::io::Result<()> {
+        for (bar, rect) in chart.rects(&extent.brect) {
+            self.codegen_bar(extent, &bar, rect, chart.apply_default_style_class,
+                chart.style_class)?;
+        }
+
+        // The category labels are centered beneath the bottom edge of the extent.
+        let transform = extent.transform();
+        for bar in &chart.bars {
+            let Some(tex) = bar.label else { continue; };
+            if !extent.brect.x.includes(bar.center()) { continue; }
+            let anchor = transform.apply(Vec2D { x: bar.center(), y: extent.brect.y.begin() });
+            let x = anchor.x - (0.5 * chart.label_width);
+            let y = anchor.y + (0.25 * chart.typography_height);
+            write!(self.out, "<svg")?;
+            write!(self.out, " x=\"{}\"", x)?;
+            write!(self.out, " y=\"{}\"", y)?;
+            write!(self.out, " width=\"{}\"", chart.label_width)?;
+            write!(self.out, " height=\"{}\"", chart.typography_height)?;
+            write!(self.out, ">")?;
+            self.tex_renderer.render_str(tex, self.out, Some("xMidYMin"))?;
+            write!(self.out, "</svg>")?;
+        }
+        return Ok(());
+    }
+
+    fn codegen_epsilon_delta_bands(&mut self, extent: &Extent, figure: &EpsilonDelta) 
+    -> std::io::Result<()>
+    {
+        let transform = extent.transform();
+        for rect in figure.bands(&extent.brect).into_iter().flatten() {
+            let top_left = transform.apply(
+                Vec2D { x: rect.x.begin(), y: rect.y.end() });
+            let bottom_right = transform.apply(
+                Vec2D { x: rect.x.end(), y: rect.y.begin() });
+            write!(self.out, "<rect")?;
+            write!(self.out, " x=\"{}\"", top_left.x)?;
+            write!(self.out, " y=\"{}\"", top_left.y)?;
+            write!(self.out, " width=\"{}\"", bottom_right.x - top_left.x)?;
+            write!(self.out, " height=\"{}\"", bottom_right.y - top_left.y)?;
+            write!(self.out, " class=\"")?;
+            if figure.apply_default_style_class {
+                write!(self.out, " {}", EpsilonDeltaBandDefaultStyleClass::NAME)?;
+            }
+            if let Some(class) = figure.band_style_class { write!(self.out, " {}", class)?; }
+            write!(self.out, "\"")?;
+            write!(self.out, "/>")?;
+        }
+        return Ok(());
+    }
+
+    fn codegen_epsilon_delta_guides(&mut self, extent: &Extent, figure: &EpsilonDelta) 
+    -> std::io::Result<()>
+    {
+        let polylines = figure.guides(&extent.brect);
+        if polylines.is_empty() { return Ok(()); }
+        write!(self.out, "<path")?;
+        write!(self.out, " class=\"")?;
+        if figure.apply_default_style_class {
+            write!(self.out, " {}", EpsilonDeltaGuideDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = figure.guide_style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, " d=\"")?;
+        for polyline in &polylines { self.write_polyline(extent, polyline)?; }
+        write!(self.out, "\"/>")?;
+        return Ok(());
+    }
+
+    fn codegen_angle_mark(&mut self, extent: &Extent, mark: &AngleMark) -> std::io::Result<()> {
+        if !extent.brect.includes(&mark.vertex) { return Ok(()); }
+        self.codegen_geometry_mark(&mark.strokes(extent), mark.apply_default_style_class,
+            mark.style_class)
+    }
+
+    fn codegen_angle_mark_label(&mut self, extent: &Extent, mark: &AngleMark)
+    -> std::io::Result<()>
+    {
+        if !extent.brect.includes(&mark.vertex) { return Ok(()); }
+        let (Some(tex), Some(center)) = (mark.label, mark.label_center(extent)) else {
+            return Ok(());
+        };
+        let label = PointLabel {
+            tex,
+            placement: LabelPlacement::Center,
+            typography_height: mark.typography_height,
+            width: mark.label_width
+        };
+        self.codegen_point_label(center, 0.0, &label)
+    }
+
+    fn codegen_congruence_mark(&mut self, extent: &Extent, mark: &CongruenceMark) 
+    -> std::io::Result<()>
+    {
+        if !extent.brect.includes(&(0.5 * (mark.a + mark.b))) { return Ok(()); }
+        self.codegen_geometry_mark(&mark.strokes(extent), mark.apply_default_style_class,
+            mark.style_class)
+    }
+
+    fn codegen_parallel_mark(&mut self, extent: &Extent, mark: &ParallelMark) 
+    -> std::io::Result<()>
+    {
+        if !extent.brect.includes(&(0.5 * (mark.a + mark.b))) { return Ok(()); }
+        self.codegen_geometry_mark(&mark.strokes(extent), mark.apply_default_style_class,
+            mark.style_class)
+    }
+
+    /// Emits the given strokes of a geometry mark, which are in the normalized coordinate
+    /// system already, as a single `<path>`.
+    fn codegen_geometry_mark(&mut self, strokes: &[MarkStroke], apply_default_style_class: bool,
+        style_class: Option<&str>)
+    -> std::io::Result<()>
+    {
+        if strokes.is_empty() { return Ok(()); }
+        write!(self.out, "<path")?;
+        write!(self.out, " class=\"")?;
+        if apply_default_style_class {
+            write!(self.out, " {}", GeometryMarkDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, " d=\"")?;
+        for stroke in strokes {
+            match stroke {
+                MarkStroke::Arc { start, end, radius, clockwise } => {
+                    write!(self.out, "M {} {} ", start.x, start.y)?;
+                    write!(self.out, "A {} {} 0 0 {} {} {} ", radius, radius, 
+                        if *clockwise { 1 } else { 0 }, end.x, end.y)?;
+                },
+                MarkStroke::Polyline(vertices) => {
+                    for (i, vertex) in vertices.iter().enumerate() {
+                        match i {
+                            0 => write!(self.out, "M")?,
+                            _ => write!(self.out, "L")?,
+                        }
+                        write!(self.out, " {} {} ", vertex.x, vertex.y)?;
+                    }
+                },
+            }
+        }
+        write!(self.out, "\"/>")?;
+        return Ok(());
+    }
+
+    /// Emits the given bar, whose rectangle `rect` has already been clipped to the extent.
+    /// The classes of the bar follow the classes of its chart.
+    fn codegen_bar(&mut self, extent: &Extent, bar: &Bar, rect: BoundingRect, 
+        apply_default_style_class: bool, style_class: Option<&str>)
+    -> std::io::Result<()>
+    {
+        let transform = extent.transform();
+        let top_left = transform.apply(Vec2D { x: rect.x.begin(), y: rect.y.end() });
+        let bottom_right = transform.apply(
+            Vec2D { x: rect.x.end(), y: rect.y.begin() });
+        write!(self.out, "<rect")?;
+        write!(self.out, " x=\"{}\"", top_left.x)?;
+        write!(self.out, " y=\"{}\"", top_left.y)?;
+        write!(self.out, " width=\"{}\"", bottom_right.x - top_left.x)?;
+        write!(self.out, " height=\"{}\"", bottom_right.y - top_left.y)?;
+        write!(self.out, " class=\"")?;
+        if apply_default_style_class {
+            write!(self.out, " {}", BarDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = style_class { write!(self.out, " {}", class)?; }
+        if let Some(class) = bar.style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, "/>")?;
+        return Ok(());
+    }
+
+    fn codegen_stems(&mut self, extent: &Extent, stems: &Stems) -> std::io::Result<()> {
+        let transform = extent.transform();
+        for (stem, a, b) in stems.lines(&extent.brect) {
+            let a = transform.apply(a);
+            let b = transform.apply(b);
+            write_line_prefix(self.out, a, b)?;
+            write!(self.out, " class=\"")?;
+            if stems.apply_default_style_class {
+                write!(self.out, " {}", StemDefaultStyleClass::NAME)?;
+            }
+            if let Some(class) = stems.style_class { write!(self.out, " {}", class)?; }
+            if let Some(class) = stem.style_class { write!(self.out, " {}", class)?; }
+            write!(self.out, "\"")?;
+            write!(self.out, "/>")?;
+        }
+        return Ok(());
+    }
+
+    fn codegen_sequence(&mut self, extent: &Extent, sequence: &Sequence) -> std::io::Result<()> {
+        if sequence.stems { self.codegen_stems(extent, &sequence.to_stems())?; }
+        if !sequence.connect { return Ok(()); }
+        let polylines = sequence.polylines(&extent.brect);
+        if polylines.is_empty() { return Ok(()); }
+        write!(self.out, "<path")?;
+        write!(self.out, " class=\"")?;
+        if sequence.apply_default_style_class {
+            write!(self.out, " {}", SequenceDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = sequence.style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, " d=\"")?;
+        for polyline in &polylines { self.write_polyline(extent, polyline)?; }
+        write!(self.out, "\"/>")?;
+        return Ok(());
+    }
+
+    fn codegen_cobweb(&mut self, extent: &Extent, cobweb: &Cobweb) -> std::io::Result<()> {
+        let polylines = cobweb.polylines(&extent.brect);
+        if polylines.is_empty() { return Ok(()); }
+        write!(self.out, "<path")?;
+        write!(self.out, " class=\"")?;
+        if cobweb.apply_default_style_class {
+            write!(self.out, " {}", CobwebDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = cobweb.style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, " d=\"")?;
+        for polyline in &polylines { self.write_polyline(extent, polyline)?; }
+        write!(self.out, "\"/>")?;
+        return Ok(());
+    }
+
+    fn codegen_series_polyline(&mut self, extent: &Extent, series: &Series) -> std::io::Result<()> {
+        let polylines = series.polylines(&extent.brect);
+        if polylines.is_empty() { return Ok(()); }
+        write!(self.out, "<path")?;
+        self.write_series_class(series)?;
+        write!(self.out, " d=\"")?;
+        for polyline in &polylines { self.write_polyline(extent, polyline)?; }
+        write!(self.out, "\"/>")?;
+        return Ok(());
+    }
+
+    /// Emits the splines through the samples of the given series as cubic Bézier curves. Since
+    /// Bézier curves cannot be clipped exactly by a rectangle, the path is clipped to the extent
+    /// by a `<clipPath>` instead.
+    fn codegen_series_splines(&mut self, extent: &Extent, series: &Series) -> std::io::Result<()> {
+        let splines = series.splines();
+        if splines.is_empty() { return Ok(()); }
+        let transform = extent.transform();
+        let id = self.codegen_extent_clip_path(extent)?;
+        write!(self.out, "<path")?;
+        self.write_series_class(series)?;
+        write!(self.out, " clip-path=\"url(#{})\"", id)?;
+        write!(self.out, " d=\"")?;
+        for spline in &splines {
+            for i in 0..spline.segments() {
+                let [p0, p1, p2, p3] = spline.bezier(i).map(|p| transform.apply(p));
+                if i == 0 { write!(self.out, "M {} {} ", p0.x, p0.y)?; }
+                write!(self.out, "C {} {} {} {} {} {} ", p1.x, p1.y, p2.x, p2.y, p3.x, p3.y)?;
+            }
+        }
+        write!(self.out, "\"/>")?;
+        return Ok(());
+    }
+
+    /// Emits a `<clipPath>` definition of the rectangle of the given extent and returns its id.
+    fn codegen_extent_clip_path(&mut self, extent: &Extent) -> std::io::Result<String> {
+        let transform = extent.transform();
+        let id = self.gen_id("yapl-clip");
+        let top_left = transform.apply(
+            Vec2D { x: extent.brect.x.begin(), y: extent.brect.y.end() });
+        let bottom_right = transform.apply(
+            Vec2D { x: extent.brect.x.end(), y: extent.brect.y.begin() });
+        write!(self.out, "<defs>")?;
+        write!(self.out, "<clipPath id=\"{}\">", id)?;
+        write!(self.out, "<rect")?;
+        write!(self.out, " x=\"{}\"", top_left.x)?;
+        write!(self.out, " y=\"{}\"", top_left.y)?;
+        write!(self.out, " width=\"{}\"", bottom_right.x - top_left.x)?;
+        write!(self.out, " height=\"{}\"", bottom_right.y - top_left.y)?;
+        write!(self.out, "/>")?;
+        write!(self.out, "</clipPath>")?;
+        write!(self.out, "</defs>")?;
+        return Ok(id);
+    }
+
+    /// Emits the rasterized domain coloring as an `<image>` covering the extent. The image is
+    /// clipped to the extent so that smoothing at its edges cannot bleed into the margins.
+    fn codegen_domain_coloring(&mut self, extent: &Extent, coloring: &DomainColoring)
+    -> std::io::Result<()>
+    {
+        let transform = extent.transform();
+        let raster = coloring.rasterize(extent);
+        let id = self.codegen_extent_clip_path(extent)?;
+        let top_left = transform.apply(
+            Vec2D { x: extent.brect.x.begin(), y: extent.brect.y.end() });
+        let bottom_right = transform.apply(
+            Vec2D { x: extent.brect.x.end(), y: extent.brect.y.begin() });
+        write!(self.out, "<image")?;
+        write!(self.out, " class=\"")?;
+        if coloring.apply_default_style_class {
+            write!(self.out, " {}", DomainColoringDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = coloring.style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        write!(self.out, " x=\"{}\"", top_left.x)?;
+        write!(self.out, " y=\"{}\"", top_left.y)?;
+        write!(self.out, " width=\"{}\"", bottom_right.x - top_left.x)?;
+        write!(self.out, " height=\"{}\"", bottom_right.y - top_left.y)?;
+        write!(self.out, " preserveAspectRatio=\"none\"")?;
+        write!(self.out, " clip-path=\"url(#{})\"", id)?;
+        write!(self.out, " href=\"{}\"", raster.to_data_uri())?;
+        write!(self.out, "/>")?;
+        return Ok(());
+    }
+
+    fn write_series_class(&mut self, series: &Series) -> std::io::Result<()> {
+        write!(self.out, " class=\"")?;
+        if series.apply_default_style_class {
+            write!(self.out, " {}", SeriesDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = series.style_class { write!(self.out, " {}", class)?; }
+        write!(self.out, "\"")?;
+        return Ok(());
+    }
+
+    /// Emits the shape of the given marker centered at the origin and identified by `id`.
+    /// The shape is not assigned any classes, so that it inherits its style from each `<use>`.
+    fn codegen_marker_shape(&mut self, marker: &Marker, id: &str) -> std::io::Result<()> {
+        let h = 0.5 * marker.size;
+        let data = match marker.shape {
+            MarkerShape::Circle => {
+                write!(self.out, "<circle id=\"{}\" r=\"{}\"/>", id, h)?;
+                return Ok(());
+            },
+            MarkerShape::Square => format!("M {} {} L {} {} L {} {} L {} {} Z", 
+                -h, -h, h, -h, h, h, -h, h),
+            MarkerShape::Diamond => format!("M 0 {} L {} 0 L 0 {} L {} 0 Z", -h, h, h, -h),
+            MarkerShape::Triangle => format!("M 0 {} L {} {} L {} {} Z", -h, h, h, -h, h),
+            MarkerShape::Cross => format!("M {} {} L {} {} M {} {} L {} {}",
+                -h, -h, h, h, -h, h, h, -h),
+            MarkerShape::Plus => format!("M {} 0 L {} 0 M 0 {} L 0 {}", -h, h, -h, h),
+        };
+        write!(self.out, "<path id=\"{}\" d=\"{}\"/>", id, data)?;
+        return Ok(());
+    }
+
+    /// Writes the commands of an SVG path which visits each of the given coordinates in order,
+    /// beginning with a move to the first.
+    fn write_polyline(&mut self, extent: &Extent, coords: &[Vec2D]) -> std::io::Result<()> {
+        let transform = extent.transform();
+        for (i, coord) in coords.iter().enumerate() {
+            let coord = transform.apply(*coord);
+            match i == 0 {
+                true => write!(self.out, "M")?,
+                false => write!(self.out, "L")?,
+            }
+            write!(self.out, " {} {} ", coord.x, coord.y)?;
+        }
+        return Ok(());
+    }
+
+    fn codegen_point(&mut self, extent: &Extent, point: &Point) -> std::io::Result<()> {
+        if !extent.brect.includes(&point.pos) { return Ok(()); }
+        let transform = extent.transform();
+        let center = transform.apply(point.pos);
+
+        write!(self.out, "<circle")?;
+        write!(self.out, " cx=\"{}\"", center.x)?;
+        write!(self.out, " cy=\"{}\"", center.y)?;
+        write!(self.out, " r=\"{}\"", point.radius)?;
+        write!(self.out, " class=\"")?;
+        if point.apply_default_style_class {
+            write!(self.out, " {}", PointDefaultStyleClass::NAME)?;
+            if point.fill == PointFill::Open {
+                write!(self.out, " {}", OpenPointDefaultStyleClass::NAME)?;
+            }
+        }
+        if let Some(class) = point.style_class {
+            write!(self.out, " {}", class)?;
+        }
+        write!(self.out, "\"")?;
+        write!(self.out, "/>")?;
+
+        if let Some(label) = &point.label {
+            self.codegen_point_label(center, point.radius, label)?;
         }
+        return Ok(());
+    }
 
-        write!(self.out, "]]>")?;
-        write!(self.out, "</style>")?;
-        
-        return Ok(())
+    /// Emits a TeX label beside the point whose normalized coordinate is `center`. The label is
+    /// separated from the point by `clearance`, which is typically the radius of the dot.
+    fn codegen_annotation(&mut self, extent: &Extent, annotation: &Annotation) 
+    -> std::io::Result<()>
+    {
+        if !extent.brect.includes(&annotation.pos) { return Ok(()); }
+        let transform = extent.transform();
+        let label = PointLabel {
+            tex: &annotation.tex,
+            placement: annotation.placement,
+            typography_height: annotation.typography_height,
+            width: annotation.width
+        };
+        self.codegen_point_label(transform.apply(annotation.pos), 0.0, &label)
     }
-    
-    fn codegen_cplane(&mut self, cplane: &CoordinatePlane) 
+
+    fn codegen_point_label(&mut self, center: Vec2D, clearance: f64, label: &PointLabel) 
     -> std::io::Result<()>
-    { 
-        if cplane.extent.area() == 0.0 { return Ok(()); }
-        let bound = normalize_coordinate(&cplane.extent, cplane.extent.brect.top_right());
-    
+    {
+        let (dx, dy) = label.placement.direction();
+        let gap = clearance + (0.25 * label.typography_height);
+        // Align the label towards the point, so that short labels do not drift away.
+        let (x, preserve_aspect_ratio) = match dx {
+            d if d < 0.0 => (center.x - gap - label.width, "xMaxYMid"),
+            d if d > 0.0 => (center.x + gap, "xMinYMid"),
+            _ => (center.x - (0.5 * label.width), "xMidYMid"),
+        };
+        let y = match dy {
+            d if d < 0.0 => center.y - gap - label.typography_height,
+            d if d > 0.0 => center.y + gap,
+            _ => center.y - (0.5 * label.typography_height),
+        };
         write!(self.out, "<svg")?;
-        write!(self.out, " viewBox=\"0 0 {} {}\"", bound.x, bound.y)?;
-        write!(self.out, " xmlns=\"http://www.w3.org/2000/svg\"")?;
-        write!(self.out, " preserveAspectRatio=\"xMinYMin meet\"")?;
-        write!(self.out, ">")?;    
-         {
-            let mut buf: SegVecRoot<plotfn::Node> = SegVecRoot::default();
-            for function in &cplane.fns {
-                self.codegen_fnplot(&cplane.extent, function, buf.extend())?;
-            }
-        }
-        self.codegen_horizontal_axis(cplane)?;
-        self.codegen_vertical_axis(cplane)?;
-        self.codegen_horizontal_axis_ticks(cplane)?;
-        self.codegen_vertical_axis_ticks(cplane)?;
-        self.codegen_horizontal_axis_tick_labels(cplane)?;
-        self.codegen_vertical_axis_tick_labels(cplane)?;
-
-        // Make sure to generate the stylesheet last. The TeXRenderer may generate
-        // a specially tailored minimal stylesheet based on the requests it received over
-        // the duratino of the compilation.
-        self.codegen_stylesheet(cplane)?;
+        write!(self.out, " x=\"{}\"", x)?;
+        write!(self.out, " y=\"{}\"", y)?;
+        write!(self.out, " width=\"{}\"", label.width)?;
+        write!(self.out, " height=\"{}\"", label.typography_height)?;
+        write!(self.out, ">")?;
+        self.tex_renderer.render_str(label.tex, self.out, Some(preserve_aspect_ratio))?;
         write!(self.out, "</svg>")?;
-        return Ok(())
+        return Ok(());
     }
 
-    
-    fn codegen_fnplot(&mut self, extent: &Extent, function: &Function, mut buf: SegVec<plotfn::Node>) 
+    /// Emits the title of the given horizontal axis above the right end of the axis line.
+    fn codegen_horizontal_axis_title(&mut self, extent: &Extent, axis: &Axis) 
     -> std::io::Result<()>
-    {    
-        let (domain, codomain) = match function.kind {
-            FunctionKind::OfX => (extent.brect.x, extent.brect.y),
-            FunctionKind::OfY => (extent.brect.y, extent.brect.x),
+    {
+        let Some(title) = &axis.title else { return Ok(()); };
+        let transform = extent.transform();
+        let y = axis.pos.resolve(extent.brect.y);
+        let end = transform.apply(Vec2D { x: extent.brect.x.end(), y });
+        let label = PointLabel {
+            tex: title.tex,
+            placement: LabelPlacement::AboveLeft,
+            typography_height: title.typography_height,
+            width: title.width
         };
-    
-        let error_tolerance = codomain.len() / function.error_tolerance_factor;
-        let zero_tolerance = domain.len() / function.zero_tolerance_factor;
-        let undef_tolerance = domain.len() / function.undef_tolerance_factor;
-                
-        let stats = plotfn::plotfn(&function.eval, &mut buf, PlotFnParams { domain, codomain, 
-            min_depth: function.min_depth, error_tolerance, zero_tolerance, undef_tolerance }); 
-        // println!("{:#?}", stats);
-           
-        write!(self.out, "<path")?;
-        write!(self.out, " class=\"")?;
-        if function.apply_default_style_class {
-            write!(self.out, " {}", FunctionDefaultStyleClass::NAME)?;
-        }
-        if let Some(class) = function.style_class { write!(self.out, " {}", class)?; }
-        write!(self.out, "\"")?;
-        write!(self.out, " d=\"")?;
-    
-        let mut broken = true;
-        for node in buf.as_slice().iter() {
-            match node {
-                plotfn::Node::Break => broken = true,
-                plotfn::Node::Anchor(anchor) => {
-                    match broken {
-                        true => write!(self.out, "M")?,
-                        false => write!(self.out, "L")?,
-                    }
-                    broken = false;
-    
-                    let x = match function.kind {
-                        FunctionKind::OfX => anchor.input,
-                        FunctionKind::OfY => (function.eval)(anchor.input),
-                    };
-    
-                    let y = match function.kind {
-                        FunctionKind::OfX => (function.eval)(anchor.input),
-                        FunctionKind::OfY => anchor.input,
-                    };
-    
-                    let coord = normalize_coordinate(extent, Vec2D { x, y });
-                    write!(self.out, " {} {} ", coord.x, coord.y)?;
-                },
-            }
-        }
-        
-        write!(self.out, "\"/>")?;
-        return Ok(())
+        self.codegen_point_label(end, 0.0, &label)
     }
 
-    fn codegen_vertical_axis(&mut self, cplane: &CoordinatePlane) -> std::io::Result<()> 
+    /// Emits the title of the given vertical axis to the right of the top end of the axis line.
+    fn codegen_vertical_axis_title(&mut self, extent: &Extent, axis: &Axis) 
+    -> std::io::Result<()>
     {
-        let Some(axis) = &cplane.vertical_axis else { return Ok(()); };
-        
-        let start = normalize_coordinate(&cplane.extent, Vec2D { 
-            x: axis.pos,
-            y: cplane.extent.brect.y.begin()
-        });
-        let stop = normalize_coordinate(&cplane.extent, Vec2D { 
-            x: axis.pos,
-            y: cplane.extent.brect.y.end() 
-        });
+        let Some(title) = &axis.title else { return Ok(()); };
+        let transform = extent.transform();
+        let x = axis.pos.resolve(extent.brect.x);
+        let end = transform.apply(Vec2D { x, y: extent.brect.y.end() });
+        let label = PointLabel {
+            tex: title.tex,
+            placement: LabelPlacement::BelowRight,
+            typography_height: title.typography_height,
+            width: title.width
+        };
+        self.codegen_point_label(end, 0.0, &label)
+    }
+
+    fn codegen_vertical_axis(&mut self, extent: &Extent, axis: &Axis) -> std::io::Result<()> 
+    {
+        let transform = extent.transform();
+        let x = axis.pos.resolve(extent.brect.x);
+        let start = transform.apply(Vec2D { x, y: extent.brect.y.begin() });
+        let stop = transform.apply(Vec2D { x, y: extent.brect.y.end() });
+        self.codegen_axis_line(axis, start, stop)
+    }
     
+    fn codegen_horizontal_axis(&mut self, extent: &Extent, axis: &Axis) -> std::io::Result<()> 
+    {
+        let transform = extent.transform();
+        let y = axis.pos.resolve(extent.brect.y);
+        let start = transform.apply(Vec2D { x: extent.brect.x.begin(), y });
+        let stop = transform.apply(Vec2D { x: extent.brect.x.end(), y });
+        self.codegen_axis_line(axis, start, stop)
+    }
+
+    /// Emits the line of the given axis beginning at the normalized coordinate `start` (the
+    /// negative end) and ending at the normalized coordinate `stop` (the positive end). 
+    /// If the axis has an arrowhead, the `<marker>` definition is emitted beforehand.
+    fn codegen_axis_line(&mut self, axis: &Axis, start: Vec2D, stop: Vec2D) -> std::io::Result<()>
+    {
+        let marker_id = match &axis.arrowhead {
+            Some(arrowhead) => Some((self.codegen_arrowhead_marker(arrowhead)?, arrowhead.ends)),
+            None => None,
+        };
+
         write_line_prefix(self.out, start, stop)?;
         write!(self.out, " class=\"")?;
         if axis.apply_default_style_class {
@@ -197,53 +1308,60 @@ where T: TeXRenderer,
             write!(self.out, " {}", class)?;
         }
         write!(self.out, "\"")?;
+        if let Some((id, ends)) = marker_id {
+            write_marker_attributes(self.out, &id, ends)?;
+        }
         write!(self.out, "/>")?;
         return Ok(());
     }
-    
-    fn codegen_horizontal_axis(&mut self, cplane: &CoordinatePlane) -> std::io::Result<()> 
-    {
-       let Some(axis) = &cplane.horizontal_axis else { return Ok(()); };
-        
-        let start = normalize_coordinate(&cplane.extent, Vec2D {
-            x: cplane.extent.brect.x.begin(),
-            y: axis.pos
-        });
-        let stop = normalize_coordinate(&cplane.extent, Vec2D {
-            x: cplane.extent.brect.x.end(),
-            y: axis.pos
-        });
-    
-        write_line_prefix(self.out, start, stop)?;
+
+    /// Emits the `<marker>` definition of the given arrowhead and returns its `id`. The tip of
+    /// the arrowhead coincides with the end of the line it caps.
+    fn codegen_arrowhead_marker(&mut self, arrowhead: &Arrowhead) -> std::io::Result<String> {
+        let id = self.gen_id("yapl-arrowhead");
+        write!(self.out, "<defs>")?;
+        write!(self.out, "<marker")?;
+        write!(self.out, " id=\"{}\"", id)?;
+        write!(self.out, " viewBox=\"0 0 {} {}\"", arrowhead.len, arrowhead.width)?;
+        write!(self.out, " refX=\"{}\"", arrowhead.len)?;
+        write!(self.out, " refY=\"{}\"", 0.5 * arrowhead.width)?;
+        write!(self.out, " markerUnits=\"userSpaceOnUse\"")?;
+        write!(self.out, " markerWidth=\"{}\"", arrowhead.len)?;
+        write!(self.out, " markerHeight=\"{}\"", arrowhead.width)?;
+        write!(self.out, " orient=\"auto-start-reverse\"")?;
+        write!(self.out, ">")?;
+        write!(self.out, "<path")?;
         write!(self.out, " class=\"")?;
-        if axis.apply_default_style_class {
-            write!(self.out, " {}", AxisDefaultStyleClass::NAME)?;
+        if arrowhead.apply_default_style_class {
+            write!(self.out, " {}", ArrowheadDefaultStyleClass::NAME)?;
         }
-        if let Some(class) = axis.style_class {
+        if let Some(class) = arrowhead.style_class {
             write!(self.out, " {}", class)?;
         }
         write!(self.out, "\"")?;
+        write!(self.out, " d=\"M 0 0 L {} {} L 0 {} Z\"", arrowhead.len, 
+            0.5 * arrowhead.width, arrowhead.width)?;
         write!(self.out, "/>")?;
-        return Ok(());
+        write!(self.out, "</marker>")?;
+        write!(self.out, "</defs>")?;
+        return Ok(id);
     }
 
 
-    fn codegen_vertical_axis_ticks(&mut self, cplane: &CoordinatePlane) -> std::io::Result<()> 
+    fn codegen_vertical_axis_ticks(&mut self, extent: &Extent, axis: &Axis) -> std::io::Result<()> 
     {
-        let Some(axis) = &cplane.vertical_axis else { return Ok(()); };
         if axis.stride == 0.0 { return Ok(()); }
-        let n = ((cplane.extent.brect.y.begin() - axis.offset) / axis.stride).ceil();
+        let n = ((extent.brect.y.begin() - axis.offset) / axis.stride).ceil();
         let mut k = axis.offset + (n * axis.stride);
-        let half_length = axis.tick.len / 2.0;
-        let normal_x = normalize_x(&cplane.extent, axis.pos);
-        let min_x = normal_x - half_length;
-        let max_x = normal_x + half_length;
-        while k <= cplane.extent.brect.y.end() {
-            // TODO: Optimize
-            let normal_y = normalize_y(&cplane.extent, k);
-            let left = Vec2D { x: min_x, y: normal_y };
-            let right = Vec2D { x: max_x, y: normal_y };
-            write_line_prefix(self.out, left, right)?;
+        let side = vertical_axis_label_side(axis);
+        let (outward, inward) = axis.tick.extents();
+        let transform = extent.transform();
+        let x = axis.pos.resolve(extent.brect.x);
+        while k <= extent.brect.y.end() {
+            let center = transform.apply(Vec2D { x, y: k });
+            let inner = Vec2D { x: center.x - (side * inward), y: center.y };
+            let outer = Vec2D { x: center.x + (side * outward), y: center.y };
+            write_line_prefix(self.out, inner, outer)?;
             write!(self.out, " class=\"")?;
             if axis.tick.apply_default_style_class {
                 write!(self.out, " {}", TickDefaultStyleClass::NAME)?;
@@ -258,23 +1376,20 @@ where T: TeXRenderer,
         return Ok(())
     }
 
-    fn codegen_horizontal_axis_ticks(&mut self, cplane: &CoordinatePlane) -> std::io::Result<()> 
-    where W: std::io::Write
+    fn codegen_horizontal_axis_ticks(&mut self, extent: &Extent, axis: &Axis) -> std::io::Result<()> 
     {
-        let Some(axis) = &cplane.horizontal_axis else { return Ok(()); };
         if axis.stride == 0.0 { return Ok(()); }
-        let n = ((cplane.extent.brect.x.begin() - axis.offset) / axis.stride).ceil();
+        let n = ((extent.brect.x.begin() - axis.offset) / axis.stride).ceil();
         let mut k = axis.offset + (n * axis.stride);
-        let half_length = axis.tick.len / 2.0;
-        let normal_y = normalize_y(&cplane.extent, axis.pos);
-        let max_y = normal_y + half_length;
-        let min_y = normal_y - half_length;
-        while k <= cplane.extent.brect.x.end() {
-            // TODO: Optimize
-            let normal_x = normalize_x(&cplane.extent, k);
-            let top = Vec2D { x: normal_x, y: max_y };
-            let bot = Vec2D { x: normal_x, y: min_y };
-            write_line_prefix(self.out, top, bot)?;
+        let side = horizontal_axis_label_side(axis);
+        let (outward, inward) = axis.tick.extents();
+        let transform = extent.transform();
+        let y = axis.pos.resolve(extent.brect.y);
+        while k <= extent.brect.x.end() {
+            let center = transform.apply(Vec2D { x: k, y });
+            let outer = Vec2D { x: center.x, y: center.y + (side * outward) };
+            let inner = Vec2D { x: center.x, y: center.y - (side * inward) };
+            write_line_prefix(self.out, outer, inner)?;
 
             write!(self.out, " class=\"")?;
             if axis.tick.apply_default_style_class {
@@ -290,25 +1405,29 @@ where T: TeXRenderer,
         return Ok(())
     }
 
-    
-    fn codegen_horizontal_axis_tick_labels(&mut self, cplane: &CoordinatePlane)
+    /// Emits the tick labels of the given horizontal axis. Labels which would overlap 
+    /// `vertical_axis_brect` are skipped.
+    fn codegen_horizontal_axis_tick_labels(&mut self, extent: &Extent, axis: &Axis, 
+        vertical_axis_brect: Option<BoundingRect>)
     -> std::io::Result<()> 
      {
-        let Some(axis) = &cplane.horizontal_axis else { return Ok(()); };
         let Some(label) = &axis.tick_label else { return Ok(()); };
         if axis.stride == 0.0 { return Ok(()); }
         
-        let vertical_axis_brect = calc_vertical_axis_brect(cplane);
-        
         write!(self.out, "<!-- horizontal axis tick labels begin -->")?;
         
-        let n = ((cplane.extent.brect.x.begin() - axis.offset) / axis.stride).ceil();
-        let y = normalize_y(&cplane.extent, axis.pos) + axis.tick.len;    
+        let n = ((extent.brect.x.begin() - axis.offset) / axis.stride).ceil();
+        let side = horizontal_axis_label_side(axis);
+        let gap = axis.tick.extents().0 + (0.5 * axis.tick.len);
+        let transform = extent.transform();
+        let axis_y = axis.pos.resolve(extent.brect.y);
+        let mut y = transform.apply(Vec2D { x: 0.0, y: axis_y }).y + (side * gap);
+        if side < 0.0 { y -= label.typography_height; }
         let mut k = axis.offset + (n * axis.stride);
         let mut multiple: f64 = n;
-        while k <= cplane.extent.brect.x.end() {
-            let min_x = normalize_x(&cplane.extent, k - (0.5 * axis.stride));
-            let max_x = normalize_x(&cplane.extent, k + (0.5 * axis.stride));
+        while k <= extent.brect.x.end() {
+            let min_x = transform.apply(Vec2D { x: k - (0.5 * axis.stride), y: axis_y }).x;
+            let max_x = transform.apply(Vec2D { x: k + (0.5 * axis.stride), y: axis_y }).x;
             let width = max_x - min_x;
     
             if let Some(brect) = vertical_axis_brect {
@@ -328,24 +1447,7 @@ where T: TeXRenderer,
             match label.kind {
                 TickLabelKind::Decimal => self.tex_renderer.render_num(k, self.out, None)?,
                 TickLabelKind::Symbolic(symbolic) => {
-                    let mut s = String::new();
-                    if let Some(offset_symbol_tex) = &symbolic.offset_symbol_tex {
-                        s.push_str(&offset_symbol_tex);
-                        s.push_str(" ");
-                        if multiple >= 0.0 {
-                            s.push_str("\\plus ");
-                        }
-                    }
-                    if multiple == -1.0 {
-                        s.push_str("-");
-                    }
-                    else if multiple != 1.0 {
-                        s.push_str(&multiple.to_string());
-                    }
-                    s.push_str(" ");
-                    if multiple != 0.0 {  
-                        s.push_str(&symbolic.stride_symbol_tex);
-                    }
+                    let s = symbolic_tick_label_tex(&symbolic, multiple);
                     self.tex_renderer.render_str(&s, self.out, None)?;
                 },
             }
@@ -356,29 +1458,37 @@ where T: TeXRenderer,
         return Ok(())
     }
 
-    
-    fn codegen_vertical_axis_tick_labels(&mut self, cplane: &CoordinatePlane)
+    /// Emits the tick labels of the given vertical axis. Labels which would overlap 
+    /// `horizontal_axis_brect` are skipped.
+    fn codegen_vertical_axis_tick_labels(&mut self, extent: &Extent, axis: &Axis,
+        horizontal_axis_brect: Option<BoundingRect>)
     -> std::io::Result<()> 
     {
-        let Some(axis) = &cplane.vertical_axis else { return Ok(()); };
         let Some(label) = &axis.tick_label else { return Ok(()); };
         if axis.stride == 0.0 { return Ok(()); }
     
-        let horizontal_axis_brect = calc_horizontal_axis_brect(cplane);
-    
         write!(self.out, "<!-- vertical axis tick labels begin -->")?;
         
-        let n = ((cplane.extent.brect.y.begin() - axis.offset) / axis.stride).ceil();
-        let half_length = axis.tick.len / 2.0;
-        let min_x = normalize_x(&cplane.extent, axis.pos) + half_length;
+        let n = ((extent.brect.y.begin() - axis.offset) / axis.stride).ceil();
+        let side = vertical_axis_label_side(axis);
+        let gap = axis.tick.extents().0;
+        let transform = extent.transform();
+        let axis_x = axis.pos.resolve(extent.brect.x);
+        let near_x = transform.apply(Vec2D { x: axis_x, y: 0.0 }).x + (side * gap);
+        // Labels to the left of the axis are right-aligned against the axis, therefore the 
+        // reserved width must be given explicitly.
+        let (min_x, width, preserve_aspect_ratio) = match side < 0.0 {
+            true => (near_x - label.frame_width, Some(label.frame_width), "xMaxYMin"),
+            false => (near_x, None, "xMinYMin"),
+        };
     
         let mut k = axis.offset + (n * axis.stride);
         let mut multiple = n;
-        while k <= cplane.extent.brect.y.end() {
-            let y = normalize_y(&cplane.extent, k);
+        while k <= extent.brect.y.end() {
+            let y = transform.apply(Vec2D { x: axis_x, y: k }).y;
             
             if let Some(brect) = horizontal_axis_brect {
-                if brect.includes(&Vec2D { x: min_x , y }) {
+                if brect.includes(&Vec2D { x: near_x , y }) {
                     multiple += 1.0;
                     k += axis.stride;
                     continue;
@@ -388,31 +1498,18 @@ where T: TeXRenderer,
             write!(self.out, "<svg")?;
             write!(self.out, " x=\"{}\"", min_x)?;
             write!(self.out, " y=\"{}\"", y - (0.5 * label.typography_height))?;
+            if let Some(width) = width {
+                write!(self.out, " width=\"{}\"", width)?;
+            }
             write!(self.out, " height=\"{}\"", label.typography_height)?;
             write!(self.out, ">")?;
     
             match label.kind {
-                TickLabelKind::Decimal => self.tex_renderer.render_num(k, self.out, Some("xMinYMin"))?,
+                TickLabelKind::Decimal => 
+                    self.tex_renderer.render_num(k, self.out, Some(preserve_aspect_ratio))?,
                 TickLabelKind::Symbolic(symbolic) => {
-                    let mut s = String::new();
-                    if let Some(offset_symbol_tex) = &symbolic.offset_symbol_tex {
-                        s.push_str(&offset_symbol_tex);
-                        s.push_str(" ");
-                        if multiple >= 0.0 {
-                            s.push_str("\\plus ");
-                        }
-                    }
-                    if multiple == -1.0 {
-                        s.push_str("-");
-                    }
-                    else if multiple != 1.0 {
-                        s.push_str(&multiple.to_string());
-                    }
-                    s.push_str(" ");
-                    if multiple != 0.0 {  
-                        s.push_str(&symbolic.stride_symbol_tex);
-                    }
-                    self.tex_renderer.render_str(&s, self.out, Some("xMinYMin"))?;
+                    let s = symbolic_tick_label_tex(&symbolic, multiple);
+                    self.tex_renderer.render_str(&s, self.out, Some(preserve_aspect_ratio))?;
                 },
             }
             write!(self.out, "</svg>")?;
@@ -421,6 +1518,205 @@ where T: TeXRenderer,
         }
         return Ok(())
     }
+
+    fn codegen_legend(&mut self, extent: &Extent, legend: &Legend) -> std::io::Result<()> {
+        if legend.entries.is_empty() { return Ok(()); }
+        let transform = extent.transform();
+        
+        let width = legend.width();
+        let height = legend.height();
+        let bound = transform.apply(extent.brect.top_right());
+        let origin = match legend.position {
+            LegendPosition::Corner(Corner::TopLeft) => 
+                Vec2D { x: legend.padding, y: legend.padding },
+            LegendPosition::Corner(Corner::TopRight) => 
+                Vec2D { x: bound.x - legend.padding - width, y: legend.padding },
+            LegendPosition::Corner(Corner::BottomLeft) => 
+                Vec2D { x: legend.padding, y: bound.y - legend.padding - height },
+            LegendPosition::Corner(Corner::BottomRight) => Vec2D { 
+                x: bound.x - legend.padding - width, 
+                y: bound.y - legend.padding - height 
+            },
+            LegendPosition::At(coordinate) => transform.apply(coordinate),
+        };
+
+        write!(self.out, "<!-- legend begin -->")?;
+        write!(self.out, "<rect")?;
+        write!(self.out, " x=\"{}\"", origin.x)?;
+        write!(self.out, " y=\"{}\"", origin.y)?;
+        write!(self.out, " width=\"{}\"", width)?;
+        write!(self.out, " height=\"{}\"", height)?;
+        write!(self.out, " class=\"")?;
+        if legend.apply_default_style_class {
+            write!(self.out, " {}", LegendDefaultStyleClass::NAME)?;
+        }
+        if let Some(class) = legend.style_class {
+            write!(self.out, " {}", class)?;
+        }
+        write!(self.out, "\"")?;
+        write!(self.out, "/>")?;
+
+        let row_height = legend.row_height();
+        let swatch_x = origin.x + legend.padding;
+        let label_x = swatch_x + legend.swatch_len + legend.padding;
+        for (i, entry) in legend.entries.iter().enumerate() {
+            let center_y = origin.y + legend.padding + ((i as f64 + 0.5) * row_height);
+            
+            let swatch = &entry.swatch;
+            match swatch.kind {
+                SwatchKind::Function | SwatchKind::Line => {
+                    let left = Vec2D { x: swatch_x, y: center_y };
+                    let right = Vec2D { x: swatch_x + legend.swatch_len, y: center_y };
+                    write_line_prefix(self.out, left, right)?;
+                    write!(self.out, " class=\"")?;
+                    if swatch.apply_default_style_class {
+                        let name = match swatch.kind {
+                            SwatchKind::Function => FunctionDefaultStyleClass::NAME,
+                            SwatchKind::Line => LineDefaultStyleClass::NAME,
+                        };
+                        write!(self.out, " {}", name)?;
+                    }
+                    if let Some(class) = swatch.style_class {
+                        write!(self.out, " {}", class)?;
+                    }
+                    write!(self.out, "\"")?;
+                    write!(self.out, "/>")?;
+                },
+            }
+
+            write!(self.out, "<svg")?;
+            write!(self.out, " x=\"{}\"", label_x)?;
+            write!(self.out, " y=\"{}\"", center_y - (0.5 * legend.typography_height))?;
+            write!(self.out, " width=\"{}\"", legend.label_width)?;
+            write!(self.out, " height=\"{}\"", legend.typography_height)?;
+            write!(self.out, ">")?;
+            self.tex_renderer.render_str(entry.label_tex, self.out, Some("xMinYMid"))?;
+            write!(self.out, "</svg>")?;
+        }
+        return Ok(());
+    }
+
+    /// Emits the given inset of a plane whose extent is `extent` and whose functions are `fns`,
+    /// together with the outline of the magnified region and the connector lines.
+    fn codegen_inset(&mut self, extent: &Extent, inset: &Inset, fns: &[Function])
+    -> std::io::Result<()>
+    {
+        if inset.region().area() == 0.0 || inset.magnification <= 0.0 { return Ok(()); }
+        let transform = extent.transform();
+        if inset.highlight {
+            self.codegen_inset_rect(extent, inset, inset.region(), InsetDefaultStyleClass::NAME)?;
+            for (a, b) in inset.connectors() {
+                write_line_prefix(self.out, transform.apply(a), transform.apply(b))?;
+                write!(self.out, " class=\"")?;
+                if inset.apply_default_style_class {
+                    write!(self.out, " {}", InsetDefaultStyleClass::NAME)?;
+                }
+                if let Some(class) = inset.style_class {
+                    write!(self.out, " {}", class)?;
+                }
+                write!(self.out, "\"")?;
+                write!(self.out, "/>")?;
+            }
+        }
+        let rect = inset.rect();
+        self.codegen_inset_rect(extent, inset, rect, InsetFrameDefaultStyleClass::NAME)?;
+
+        // The nested viewport clips the elements of the inset to its frame. The normalized
+        // coordinate system of the inset has the same unit length as that of the enclosing
+        // plane, so the viewport needs no viewBox.
+        let top_left = transform.apply(Vec2D { x: rect.x.begin(), y: rect.y.end() });
+        let bottom_right = transform.apply(Vec2D { x: rect.x.end(), y: rect.y.begin() });
+        write!(self.out, "<svg")?;
+        write!(self.out, " x=\"{}\"", top_left.x)?;
+        write!(self.out, " y=\"{}\"", top_left.y)?;
+        write!(self.out, " width=\"{}\"", bottom_right.x - top_left.x)?;
+        write!(self.out, " height=\"{}\"", bottom_right.y - top_left.y)?;
+        write!(self.out, ">")?;
+        let inset_extent = inset.extent(extent);
+        let inset_viewbox = ViewBox {
+            x: 0.0,
+            y: 0.0,
+            width: bottom_right.x - top_left.x,
+            height: bottom_right.y - top_left.y
+        };
+        if inset.replot_fns {
+            // Each function is sampled anew over the magnified region, rather than scaled up.
+            let mut buf: SegVecRoot<plotfn::Node> = SegVecRoot::default();
+            for function in fns {
+                self.codegen_fnplot(&inset_extent, &inset_viewbox, function, buf.extend())?;
+            }
+        }
+        self.codegen_cplane_elements(&inset.cplane, &inset_extent, &inset_viewbox)?;
+        write!(self.out, "</svg>")?;
+        return Ok(());
+    }
+
+    /// Emits the rectangle `brect` of a plane whose extent is `extent`, assigned the default
+    /// style class `default_class_name` and the custom style classes of the given inset.
+    fn codegen_inset_rect(&mut self, extent: &Extent, inset: &Inset, brect: BoundingRect,
+        default_class_name: &str)
+    -> std::io::Result<()>
+    {
+        let transform = extent.transform();
+        let top_left = transform.apply(Vec2D { x: brect.x.begin(), y: brect.y.end() });
+        let bottom_right = transform.apply(Vec2D { x: brect.x.end(), y: brect.y.begin() });
+        write!(self.out, "<rect")?;
+        write!(self.out, " x=\"{}\"", top_left.x)?;
+        write!(self.out, " y=\"{}\"", top_left.y)?;
+        write!(self.out, " width=\"{}\"", bottom_right.x - top_left.x)?;
+        write!(self.out, " height=\"{}\"", bottom_right.y - top_left.y)?;
+        write!(self.out, " class=\"")?;
+        if inset.apply_default_style_class {
+            write!(self.out, " {}", default_class_name)?;
+        }
+        if let Some(class) = inset.style_class {
+            write!(self.out, " {}", class)?;
+        }
+        write!(self.out, "\"")?;
+        write!(self.out, "/>")?;
+        return Ok(());
+    }
+}
+
+fn symbolic_tick_label_tex(symbolic: &SymbolicTickLabel, multiple: f64) -> String {
+    let mut s = String::new();
+    if let Some(offset_symbol_tex) = &symbolic.offset_symbol_tex {
+        s.push_str(&offset_symbol_tex);
+        s.push_str(" ");
+        if multiple >= 0.0 {
+            s.push_str("\\plus ");
+        }
+    }
+    if multiple == -1.0 {
+        s.push_str("-");
+    }
+    else if multiple != 1.0 {
+        s.push_str(&multiple.to_string());
+    }
+    s.push_str(" ");
+    if multiple != 0.0 {  
+        s.push_str(&symbolic.stride_symbol_tex);
+    }
+    return s;
+}
+
+/// Returns `1.0` if the tick labels of the given horizontal axis are placed below the axis line,
+/// and `-1.0` if they are placed above it. This is the sign of the outward direction in the
+/// normalized coordinate system, whose y-axis points downward.
+fn horizontal_axis_label_side(axis: &Axis) -> f64 {
+    match axis.pos {
+        AxisPosition::AtMax => -1.0,
+        AxisPosition::AtMin | AxisPosition::AtValue(_) => 1.0,
+    }
+}
+
+/// Returns `1.0` if the tick labels of the given vertical axis are placed to the right of the
+/// axis line, and `-1.0` if they are placed to the left of it.
+fn vertical_axis_label_side(axis: &Axis) -> f64 {
+    match axis.pos {
+        AxisPosition::AtMin => -1.0,
+        AxisPosition::AtMax | AxisPosition::AtValue(_) => 1.0,
+    }
 }
 
 
@@ -435,58 +1731,333 @@ where W: std::io::Write
     return Ok(())
 }
 
-fn normalize_coordinate(extent: &Extent, rel_coordinate: Vec2D) -> Vec2D {
-    let x = normalize_x(extent, rel_coordinate.x);
-    let y = normalize_y(extent, rel_coordinate.y);
-    return Vec2D { x, y };
-}
-
-fn normalize_x(extent: &Extent, abs_x: f64) -> f64 {
-    assert_ne!(extent.area(), 0.0, "container's interior is undefined and therefore so \
-        is the interior coordinate system.");
-    let delta_x = abs_x - extent.brect.x.begin();
-    let maximum_dimension = f64::max(extent.brect.x.len(), extent.brect.y.len());
-    return (delta_x / maximum_dimension) * extent.x_scale;
-}
-
-fn normalize_y(extent: &Extent, mut abs_y: f64) -> f64 {
-    abs_y *= -1.0;
-    let delta_y = abs_y - (-1.0 * extent.brect.y.end());
-    let maximum_dimension = f64::max(extent.brect.x.len(), extent.brect.y.len());
-    return (delta_y / maximum_dimension) * extent.y_scale;
+/// Writes the `marker-start` and `marker-end` attributes referencing the arrowhead marker
+/// `id`, according to which `ends` of the line are capped.
+fn write_marker_attributes<W>(out: &mut W, id: &str, ends: ArrowheadEnds) -> std::io::Result<()>
+where W: std::io::Write
+{
+    if ends == ArrowheadEnds::Negative || ends == ArrowheadEnds::Both {
+        write!(out, " marker-start=\"url(#{})\"", id)?;
+    }
+    if ends == ArrowheadEnds::Positive || ends == ArrowheadEnds::Both {
+        write!(out, " marker-end=\"url(#{})\"", id)?;
+    }
+    return Ok(());
 }
 
-fn calc_horizontal_axis_brect(cplane: &CoordinatePlane) 
+fn calc_horizontal_axis_brect(cplane: &CoordinatePlane, extent: &Extent) 
 -> Option<BoundingRect> 
 {
     let Some(horizontal_axis) = &cplane.horizontal_axis else { return None; };
-    let min_x = normalize_x(&cplane.extent, cplane.extent.brect.x.begin());
-    let max_x = normalize_x(&cplane.extent, cplane.extent.brect.x.end());
-    let y = normalize_y(&cplane.extent, horizontal_axis.pos);
-    let min_y = y - (0.5 * horizontal_axis.tick.len);
-    let mut max_y = y + (0.5 * horizontal_axis.tick.len);
+    let brect = &extent.brect;
+    let transform = extent.transform();
+    let y = horizontal_axis.pos.resolve(brect.y);
+    let Vec2D { x: min_x, y } = transform.apply(Vec2D { x: brect.x.begin(), y });
+    let max_x = transform.apply(Vec2D { x: brect.x.end(), y: 0.0 }).x;
+    let side = horizontal_axis_label_side(horizontal_axis);
+    let (mut outward, inward) = horizontal_axis.tick.extents();
     if let Some(label) = horizontal_axis.tick_label {
-        max_y += label.typography_height;
+        outward += label.typography_height;
     }
     return Some(BoundingRect {
         x: ClosedInterval::new(NonDecreasing::new(min_x, max_x)),
-        y: ClosedInterval::new(NonDecreasing::new(min_y, max_y))
+        y: ClosedInterval::new(NonDecreasing::minmax(y - (side * inward), y + (side * outward)))
     });
 }
 
-fn calc_vertical_axis_brect(cplane: &CoordinatePlane)
+fn calc_vertical_axis_brect(cplane: &CoordinatePlane, extent: &Extent)
 -> Option<BoundingRect>
 {
     let Some(vertical_axis) = &cplane.vertical_axis else { return None; };
-    let x = normalize_x(&cplane.extent, vertical_axis.pos);
-    let half_tick_length = vertical_axis.tick.len * 0.5;
-    let min_x = x - half_tick_length;
-    let max_x = x + half_tick_length;
-    let min_y = normalize_y(&cplane.extent, cplane.extent.brect.y.end());
-    let max_y = normalize_y(&cplane.extent, cplane.extent.brect.y.begin());
+    let brect = &extent.brect;
+    let transform = extent.transform();
+    let x = vertical_axis.pos.resolve(brect.x);
+    let Vec2D { x, y: min_y } = transform.apply(Vec2D { x, y: brect.y.end() });
+    let max_y = transform.apply(Vec2D { x: 0.0, y: brect.y.begin() }).y;
+    let side = vertical_axis_label_side(vertical_axis);
+    let (outward, inward) = vertical_axis.tick.extents();
     return Some(BoundingRect {
-        x: ClosedInterval::new(NonDecreasing::new(min_x, max_x)),
+        x: ClosedInterval::new(NonDecreasing::minmax(x - (side * inward), x + (side * outward))),
         y: ClosedInterval::new(NonDecreasing::new(min_y, max_y))
     });
 }
 
+/// The space surrounding the extent in the normalized coordinate system which must be included
+/// in the viewport so that the ticks and tick labels of axes along the edges are visible.
+#[derive(Default)]
+struct Margins { left: f64, top: f64, right: f64, bottom: f64 }
+
+/// The `viewBox` of a plane, which spans the normalized extent of the plane and its margins.
+struct ViewBox { x: f64, y: f64, width: f64, height: f64 }
+
+fn calc_viewbox(cplane: &CoordinatePlane) -> ViewBox {
+    let bound = cplane.extent.transform().apply(cplane.extent.brect.top_right());
+    let margins = calc_frame_margins(cplane);
+    return ViewBox {
+        x: 0.0 - margins.left,
+        y: 0.0 - margins.top,
+        width: bound.x + margins.left + margins.right,
+        height: bound.y + margins.top + margins.bottom
+    };
+}
+
+/// Computes the rectangle occupied by the plane of each panel of the given figure in the 
+/// coordinate system of the figure, or `None` for panels whose plane has no area. See 
+/// [`Figure`] for a description of the grid.
+fn calc_panel_rects(figure: &Figure) -> Vec<Option<BoundingRect>> {
+    let mut rects: Vec<Option<BoundingRect>> = vec![None; figure.panels.len()];
+    let rect = |x: f64, y: f64, width: f64, height: f64| BoundingRect {
+        x: ClosedInterval::new(NonDecreasing::new(x, x + width)),
+        y: ClosedInterval::new(NonDecreasing::new(y, y + height))
+    };
+    let grid: Vec<usize> = (0..figure.panels.len())
+        .filter(|&i| figure.panels[i].cplane.extent.area() != 0.0)
+        .filter(|&i| figure.panels[i].rect.is_none())
+        .collect();
+    // Every row reserves the same space for titles, so that the planes of a row line up.
+    let title_height = grid.iter().map(|&i| figure.panels[i].title_height()).fold(0.0, f64::max);
+    let mut top = 0.0;
+    for row in grid.chunks(usize::max(figure.columns, 1)) {
+        let height = row.iter()
+            .map(|&i| calc_viewbox(&figure.panels[i].cplane))
+            .map(|viewbox| viewbox.height / viewbox.width)
+            .fold(0.0, f64::max);
+        for (column, &i) in row.iter().enumerate() {
+            let left = (column as f64) * (1.0 + figure.spacing);
+            rects[i] = Some(rect(left, top + title_height, 1.0, height));
+        }
+        top += title_height + height + figure.spacing;
+    }
+    for (i, panel) in figure.panels.iter().enumerate() {
+        if panel.cplane.extent.area() == 0.0 { continue; }
+        if let Some(explicit) = panel.rect { rects[i] = Some(explicit); }
+    }
+    return rects;
+}
+
+fn calc_frame_margins(cplane: &CoordinatePlane) -> Margins {
+    let mut margins = Margins::default();
+    for axis in cplane.horizontal_axes() {
+        if !axis.pos.is_frame() { continue; }
+        let mut extra = axis.tick.extents().0;
+        if let Some(label) = axis.tick_label {
+            extra += (0.5 * axis.tick.len) + label.typography_height;
+        }
+        match axis.pos {
+            AxisPosition::AtMax => margins.top = f64::max(margins.top, extra),
+            _ => margins.bottom = f64::max(margins.bottom, extra)
+        }
+    }
+    for axis in cplane.vertical_axes() {
+        if !axis.pos.is_frame() { continue; }
+        let mut extra = axis.tick.extents().0;
+        if let Some(label) = axis.tick_label {
+            extra += label.frame_width;
+            // The labels at the ends of the axis are vertically centered on the edges of
+            // the extent, so half of each overhangs.
+            margins.top = f64::max(margins.top, 0.5 * label.typography_height);
+            margins.bottom = f64::max(margins.bottom, 0.5 * label.typography_height);
+        }
+        match axis.pos {
+            AxisPosition::AtMin => margins.left = f64::max(margins.left, extra),
+            _ => margins.right = f64::max(margins.right, extra)
+        }
+    }
+    // Category labels are drawn beneath the bottom edge of the extent.
+    for chart in &cplane.bar_charts {
+        if !chart.has_labels() { continue; }
+        margins.bottom = f64::max(margins.bottom, 1.25 * chart.typography_height);
+    }
+    return margins;
+}
+
+/// The number of elements assigned each of the default style classes. A default style class
+/// is only emitted if at least one element is assigned to it.
+#[derive(Default)]
+struct DefaultStyleClassUsage {
+    function: usize,
+    axis: usize,
+    tick: usize,
+    arrowhead: usize,
+    legend: usize,
+    line: usize,
+    point: usize,
+    open_point: usize,
+    region: usize,
+    region_boundary: usize,
+    strict_region_boundary: usize,
+    series: usize,
+    marker: usize,
+    error_bar: usize,
+    error_bar_cap: usize,
+    band: usize,
+    bar: usize,
+    stem: usize,
+    sequence: usize,
+    cobweb: usize,
+    epsilon_delta_band: usize,
+    epsilon_delta_guide: usize,
+    geometry_mark: usize,
+    domain_coloring: usize,
+    contour: usize,
+    contour_band: usize,
+    number_line_interval: usize,
+    inset: usize,
+    inset_frame: usize
+}
+
+impl DefaultStyleClassUsage {
+    fn total(&self) -> usize {
+        self.function + self.axis + self.tick + self.arrowhead + self.legend + self.line
+            + self.point + self.open_point + self.region + self.region_boundary
+            + self.strict_region_boundary + self.series + self.marker + self.error_bar
+            + self.error_bar_cap + self.band + self.bar + self.stem + self.sequence + self.cobweb
+            + self.epsilon_delta_band + self.epsilon_delta_guide + self.geometry_mark
+            + self.domain_coloring + self.contour + self.contour_band + self.number_line_interval
+            + self.inset + self.inset_frame
+    }
+
+    /// Counts the elements of the given plane which are assigned default style classes.
+    fn tally(&mut self, cplane: &CoordinatePlane) {
+        for region in &cplane.regions {
+            if !region.apply_default_style_class { continue; }
+            self.region += 1;
+            if region.draw_boundary {
+                self.region_boundary += 1;
+                if region.is_strict() {
+                    self.strict_region_boundary += 1;
+                }
+            }
+        }
+        self.function += cplane.fns.iter().filter(|f| f.apply_default_style_class).count();
+        for axis in cplane.horizontal_axes().chain(cplane.vertical_axes()) {
+            if axis.apply_default_style_class {
+                self.axis += 1;
+            }
+            if axis.tick.apply_default_style_class {
+                self.tick += 1;
+            }
+            if let Some(arrowhead) = &axis.arrowhead {
+                if arrowhead.apply_default_style_class {
+                    self.arrowhead += 1;
+                }
+            }
+        }
+        self.line += cplane.lines.iter().filter(|l| l.apply_default_style_class).count();
+        for piece in cplane.piecewise.iter().flat_map(|p| p.pieces.iter()) {
+            if piece.function.apply_default_style_class {
+                self.function += 1;
+            }
+        }
+        for series in &cplane.series {
+            if series.mode.has_polyline() && series.apply_default_style_class {
+                self.series += 1;
+            }
+            if series.mode.has_markers() && series.marker.apply_default_style_class {
+                self.marker += 1;
+            }
+            if let Some(error_bars) = &series.error_bars {
+                if error_bars.apply_default_style_class {
+                    self.error_bar += 1;
+                    self.error_bar_cap += 1;
+                }
+            }
+        }
+        self.band += cplane.bands.iter().filter(|b| b.apply_default_style_class).count();
+        self.bar += cplane.histograms.iter().filter(|h| h.apply_default_style_class).count();
+        self.bar += cplane.bar_charts.iter().filter(|c| c.apply_default_style_class).count();
+        self.stem += cplane.stems.iter().filter(|s| s.apply_default_style_class).count();
+        for sequence in cplane.sequences.iter().filter(|s| s.apply_default_style_class) {
+            if sequence.stems {
+                self.stem += 1;
+            }
+            if sequence.connect {
+                self.sequence += 1;
+            }
+        }
+        self.cobweb += cplane.cobwebs.iter().filter(|c| c.apply_default_style_class).count();
+        for figure in &cplane.epsilon_deltas {
+            if figure.function.apply_default_style_class {
+                self.function += 1;
+            }
+            if figure.apply_default_style_class {
+                self.epsilon_delta_band += 1;
+                self.epsilon_delta_guide += 1;
+            }
+        }
+        self.geometry_mark += cplane.angle_marks.iter()
+            .filter(|m| m.apply_default_style_class).count();
+        self.geometry_mark += cplane.congruence_marks.iter()
+            .filter(|m| m.apply_default_style_class).count();
+        self.geometry_mark += cplane.parallel_marks.iter()
+            .filter(|m| m.apply_default_style_class).count();
+        self.domain_coloring += cplane.domain_colorings.iter()
+            .filter(|c| c.apply_default_style_class).count();
+        for contour in cplane.contours.iter().filter(|c| c.apply_default_style_class) {
+            self.contour += 1;
+            if contour.filled {
+                self.contour_band += 1;
+            }
+        }
+        let dots: Vec<Point> = cplane.piecewise.iter().flat_map(|p| p.endpoints())
+            .chain(cplane.stems.iter().flat_map(|s| s.heads()))
+            .chain(cplane.sequences.iter().flat_map(|s| s.dots()))
+            .chain(cplane.epsilon_deltas.iter().map(|f| f.point()))
+            .collect();
+        for point in cplane.points.iter().chain(dots.iter()) {
+            if !point.apply_default_style_class { continue; }
+            self.point += 1;
+            if point.fill == PointFill::Open {
+                self.open_point += 1;
+            }
+        }
+        for inset in &cplane.insets {
+            if inset.apply_default_style_class {
+                self.inset_frame += 1;
+                if inset.highlight {
+                    self.inset += 1;
+                }
+            }
+            self.tally(&inset.cplane);
+        }
+        if let Some(legend) = &cplane.legend {
+            if legend.apply_default_style_class {
+                self.legend += 1;
+            }
+            for entry in &legend.entries {
+                if !entry.swatch.apply_default_style_class { continue; }
+                match entry.swatch.kind {
+                    SwatchKind::Function => self.function += 1,
+                    SwatchKind::Line => self.line += 1,
+                }
+            }
+        }
+    }
+
+    /// Counts the elements of the given number line which are assigned default style classes.
+    fn tally_number_line(&mut self, line: &NumberLine) {
+        if line.axis.apply_default_style_class {
+            self.axis += 1;
+        }
+        if line.axis.tick.apply_default_style_class {
+            self.tick += 1;
+        }
+        if let Some(arrowhead) = &line.axis.arrowhead {
+            if arrowhead.apply_default_style_class {
+                self.arrowhead += 1;
+            }
+        }
+        for interval in line.intervals.iter().filter(|i| i.apply_default_style_class) {
+            self.number_line_interval += 1;
+            if interval.arrowhead_ends(line.range).is_some() {
+                self.arrowhead += 1;
+            }
+            for point in interval.endpoints(line.range) {
+                self.point += 1;
+                if point.fill == PointFill::Open {
+                    self.open_point += 1;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::io::{BufRead, BufReader, Error, ErrorKind};
+use crate::elements::series::{Series, SeriesMode};
+
+// # Delimited Data
+
+/// A table of numeric data read from a delimited text file, such as CSV or TSV.
+///
+/// The table is stored by column. Missing cells, whether empty, one of [`MISSING_CELLS`], or
+/// absent because the row is shorter than the others, are `NaN`. When the table is converted into
+/// a [`Series`], these cells therefore break the polyline, just as undefined values break the
+/// graph of a [`crate::elements::Function`].
+pub struct Table {
+    /// The names of the columns, or `None` if the first row of the file contains data.
+    pub header: Option<Vec<String>>,
+    pub columns: Vec<Vec<f64>>
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Delimiter {
+    /// Cells are separated by commas, as in CSV. Cells may be enclosed in double quotes.
+    Comma,
+
+    /// Cells are separated by tabs, as in TSV. Cells may be enclosed in double quotes.
+    Tab,
+
+    /// Cells are separated by any amount of whitespace. Since consecutive separators are merged,
+    /// missing cells can only be denoted by one of [`MISSING_CELLS`].
+    Whitespace
+}
+
+/// Identifies a column of a [`Table`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Column<'a> {
+    /// The zero-based position of the column.
+    Index(usize),
+
+    /// The name of the column as given in the header.
+    Name(&'a str)
+}
+
+/// The contents of a cell, ignoring surrounding whitespace, which denote a missing value.
+pub const MISSING_CELLS: [&str; 3] = ["", "NA", "N/A"];
+
+/// Lines beginning with this character are ignored, as are blank lines.
+pub const COMMENT_PREFIX: char = '#';
+
+impl Table {
+    /// Reads a table from the given source.
+    ///
+    /// The first row is considered a header if any of its cells is neither a number nor missing.
+    /// Every cell of every subsequent row must be a number or missing. Otherwise this procedure
+    /// fails with [`ErrorKind::InvalidData`].
+    pub fn read(source: impl std::io::Read, delimiter: Delimiter) -> std::io::Result<Self> {
+        let mut table = Table { header: None, columns: Vec::new() };
+        let mut rows: usize = 0;
+        for (index, line) in BufReader::new(source).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() || line.trim_start().starts_with(COMMENT_PREFIX) { continue; }
+            let cells = split(&line, delimiter);
+            let values: Vec<Option<f64>> = cells.iter().map(|cell| parse(cell)).collect();
+
+            if rows == 0 && table.header.is_none() && values.iter().any(Option::is_none) {
+                table.header = Some(cells);
+                continue;
+            }
+            if let Some(position) = values.iter().position(Option::is_none) {
+                return Err(Error::new(ErrorKind::InvalidData, format!("Cannot read table because \
+                    the cell \"{}\" on line {} is not a number.", cells[position], index + 1)));
+            }
+
+            while table.columns.len() < values.len() {
+                table.columns.push(vec![f64::NAN; rows]);
+            }
+            for (position, column) in table.columns.iter_mut().enumerate() {
+                column.push(values.get(position).copied().flatten().unwrap_or(f64::NAN));
+            }
+            rows += 1;
+        }
+        return Ok(table);
+    }
+
+    /// Returns the number of data rows in this table, excluding the header.
+    pub fn len(&self) -> usize {
+        self.columns.first().map(|column| column.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the values of the given column. Fails with [`ErrorKind::InvalidInput`] if there
+    /// is no such column. Columns which are named in the header but contain no data are
+    /// entirely `NaN`.
+    pub fn column(&self, column: Column) -> std::io::Result<Vec<f64>> {
+        let position = match column {
+            Column::Index(position) => position,
+            Column::Name(name) => {
+                let header = self.header.as_ref().and_then(|header| {
+                    header.iter().position(|cell| cell == name)
+                });
+                header.ok_or_else(|| Error::new(ErrorKind::InvalidInput, format!("Cannot select \
+                    column \"{}\" because the table has no column by that name.", name)))?
+            },
+        };
+        let width = usize::max(self.columns.len(), self.header.as_ref().map_or(0, Vec::len));
+        if position >= width {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("Cannot select column {} \
+                because the table has only {} columns.", position, width)));
+        }
+        return Ok(match self.columns.get(position) {
+            Some(values) => values.clone(),
+            None => vec![f64::NAN; self.len()],
+        });
+    }
+
+    /// Pairs the values of the column `x` with the values of the column `y`, row by row.
+    pub fn samples(&self, x: Column, y: Column) -> std::io::Result<Vec<(f64, f64)>> {
+        let xs = self.column(x)?;
+        let ys = self.column(y)?;
+        return Ok(xs.into_iter().zip(ys).collect());
+    }
+
+    /// Constructs a [`Series`] from the column `x` and the column `y`.
+    pub fn series<'a>(&self, x: Column, y: Column, mode: SeriesMode)
+    -> std::io::Result<Series<'a>>
+    {
+        Ok(Series::new_default(self.samples(x, y)?, mode))
+    }
+}
+
+/// Reads a table from the given source, see [`Table::read`], and constructs a [`Series`] from
+/// the column `x` and the column `y`.
+pub fn read_series<'a>(source: impl std::io::Read, delimiter: Delimiter, x: Column, y: Column,
+    mode: SeriesMode)
+-> std::io::Result<Series<'a>>
+{
+    Table::read(source, delimiter)?.series(x, y, mode)
+}
+
+/// Parses the contents of a cell. Returns `Some(NaN)` for a missing cell and `None` if the cell
+/// is not a number.
+fn parse(cell: &str) -> Option<f64> {
+    let cell = cell.trim();
+    if MISSING_CELLS.contains(&cell) { return Some(f64::NAN); }
+    return cell.parse::<f64>().ok();
+}
+
+/// Splits a line into its cells. Cells delimited by commas or tabs may be enclosed in double
+/// quotes, in which case they may contain the delimiter, and a pair of double quotes denotes
+/// a literal double quote.
+fn split(line: &str, delimiter: Delimiter) -> Vec<String> {
+    let separator = match delimiter {
+        Delimiter::Comma => ',',
+        Delimiter::Tab => '\t',
+        Delimiter::Whitespace => {
+            return line.split_whitespace().map(String::from).collect();
+        }
+    };
+    let mut cells: Vec<String> = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        let cell = cells.last_mut().unwrap();
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            },
+            ('"', true) => quoted = false,
+            ('"', false) if cell.trim().is_empty() => {
+                cell.clear();
+                quoted = true;
+            },
+            (c, false) if c == separator => cells.push(String::new()),
+            (c, _) => cell.push(c),
+        }
+    }
+    return cells.into_iter().map(|cell| cell.trim().to_string()).collect();
+}
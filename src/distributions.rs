@@ -0,0 +1,356 @@
+use crate::elements::annotation::Annotation;
+use crate::elements::band::Band;
+use crate::elements::function::Function;
+use crate::elements::piecewise::Piecewise;
+use crate::elements::point::LabelPlacement;
+use crate::elements::stems::Stems;
+use crate::math::{self, Interval, NonDecreasing, Vec2D};
+
+// # Probability Distributions
+
+/// A common probability distribution of a random variable `X`.
+///
+/// Continuous distributions are plotted by their density, see [`Self::pdf_function`], and
+/// discrete distributions by a stem plot of their mass, see [`Self::stems`]. The parameters are
+/// assumed to be valid, use the constructors such as [`Self::normal`] to have them checked.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Distribution {
+    Normal { mean: f64, standard_deviation: f64 },
+
+    /// Student's t-distribution with the given (not necessarily integral) degrees of freedom.
+    StudentT { degrees_of_freedom: f64 },
+    ChiSquared { degrees_of_freedom: f64 },
+
+    /// The continuous uniform distribution on the interval from `begin` to `end`.
+    Uniform { begin: f64, end: f64 },
+    Exponential { rate: f64 },
+
+    /// The number of successes in `trials` independent trials each succeeding with
+    /// probability `probability`.
+    Binomial { trials: u32, probability: f64 },
+    Poisson { rate: f64 }
+}
+
+/// The probability mass beyond the last stem of a discrete distribution with infinite support,
+/// see [`Distribution::support`].
+pub const DISCRETE_TAIL_PROBABILITY: f64 = 1e-6;
+
+impl Distribution {
+    /// This procedure will panic if `standard_deviation` is not positive.
+    pub fn normal(mean: f64, standard_deviation: f64) -> Self {
+        assert!(standard_deviation > 0.0, "Cannot construct normal distribution with standard \
+            deviation {}.", standard_deviation);
+        Self::Normal { mean, standard_deviation }
+    }
+
+    /// The normal distribution with mean zero and standard deviation one.
+    pub fn standard_normal() -> Self { Self::normal(0.0, 1.0) }
+
+    /// This procedure will panic if `degrees_of_freedom` is not positive.
+    pub fn student_t(degrees_of_freedom: f64) -> Self {
+        assert!(degrees_of_freedom > 0.0, "Cannot construct t-distribution with {} degrees of \
+            freedom.", degrees_of_freedom);
+        Self::StudentT { degrees_of_freedom }
+    }
+
+    /// This procedure will panic if `degrees_of_freedom` is not positive.
+    pub fn chi_squared(degrees_of_freedom: f64) -> Self {
+        assert!(degrees_of_freedom > 0.0, "Cannot construct chi-squared distribution with {} \
+            degrees of freedom.", degrees_of_freedom);
+        Self::ChiSquared { degrees_of_freedom }
+    }
+
+    /// This procedure will panic if `begin` is not less than `end`.
+    pub fn uniform(begin: f64, end: f64) -> Self {
+        assert!(begin < end, "Cannot construct uniform distribution on [{}, {}].", begin, end);
+        Self::Uniform { begin, end }
+    }
+
+    /// This procedure will panic if `rate` is not positive.
+    pub fn exponential(rate: f64) -> Self {
+        assert!(rate > 0.0, "Cannot construct exponential distribution with rate {}.", rate);
+        Self::Exponential { rate }
+    }
+
+    /// This procedure will panic if `probability` is not within `[0, 1]`.
+    pub fn binomial(trials: u32, probability: f64) -> Self {
+        assert!((0.0..=1.0).contains(&probability), "Cannot construct binomial distribution \
+            with probability {}.", probability);
+        Self::Binomial { trials, probability }
+    }
+
+    /// This procedure will panic if `rate` is not positive.
+    pub fn poisson(rate: f64) -> Self {
+        assert!(rate > 0.0, "Cannot construct Poisson distribution with rate {}.", rate);
+        Self::Poisson { rate }
+    }
+
+    /// Returns true if the random variable takes only integer values.
+    pub fn is_discrete(&self) -> bool {
+        matches!(self, Self::Binomial { .. } | Self::Poisson { .. })
+    }
+
+    /// Returns the expected value of the random variable, or `NaN` if it is undefined.
+    pub fn mean(&self) -> f64 {
+        match *self {
+            Self::Normal { mean, .. } => mean,
+            Self::StudentT { degrees_of_freedom: v } => if v > 1.0 { 0.0 } else { f64::NAN },
+            Self::ChiSquared { degrees_of_freedom: k } => k,
+            Self::Uniform { begin, end } => 0.5 * (begin + end),
+            Self::Exponential { rate } => 1.0 / rate,
+            Self::Binomial { trials, probability } => (trials as f64) * probability,
+            Self::Poisson { rate } => rate,
+        }
+    }
+
+    /// Returns the variance of the random variable, which is infinite or `NaN` if the
+    /// distribution is too heavy-tailed for it to be finite.
+    pub fn variance(&self) -> f64 {
+        match *self {
+            Self::Normal { standard_deviation, .. } => standard_deviation * standard_deviation,
+            Self::StudentT { degrees_of_freedom: v } => match v {
+                v if v > 2.0 => v / (v - 2.0),
+                v if v > 1.0 => f64::INFINITY,
+                _ => f64::NAN,
+            },
+            Self::ChiSquared { degrees_of_freedom: k } => 2.0 * k,
+            Self::Uniform { begin, end } => (end - begin).powi(2) / 12.0,
+            Self::Exponential { rate } => 1.0 / (rate * rate),
+            Self::Binomial { trials, probability } =>
+                (trials as f64) * probability * (1.0 - probability),
+            Self::Poisson { rate } => rate,
+        }
+    }
+
+    /// Evaluates the probability density function of a continuous distribution, or the
+    /// probability mass function of a discrete distribution, which is zero at every input
+    /// which is not an integer.
+    pub fn pdf(&self, x: f64) -> f64 {
+        if x.is_nan() { return f64::NAN; }
+        match *self {
+            Self::Normal { mean, standard_deviation: sd } => {
+                let z = (x - mean) / sd;
+                (-0.5 * z * z).exp() / (sd * (2.0 * std::f64::consts::PI).sqrt())
+            },
+            Self::StudentT { degrees_of_freedom: v } => {
+                let ln_c = math::ln_gamma(0.5 * (v + 1.0)) - math::ln_gamma(0.5 * v)
+                    - (0.5 * (v * std::f64::consts::PI).ln());
+                (ln_c - (0.5 * (v + 1.0) * (1.0 + (x * x / v)).ln())).exp()
+            },
+            Self::ChiSquared { degrees_of_freedom: k } => {
+                if x < 0.0 { return 0.0; }
+                if x == 0.0 {
+                    return match k.total_cmp(&2.0) {
+                        std::cmp::Ordering::Less => f64::INFINITY,
+                        std::cmp::Ordering::Equal => 0.5,
+                        std::cmp::Ordering::Greater => 0.0,
+                    };
+                }
+                let h = 0.5 * k;
+                (((h - 1.0) * x.ln()) - (0.5 * x) - (h * 2f64.ln()) - math::ln_gamma(h)).exp()
+            },
+            Self::Uniform { begin, end } => match begin <= x && x <= end {
+                true => 1.0 / (end - begin),
+                false => 0.0,
+            },
+            Self::Exponential { rate } => match x < 0.0 {
+                true => 0.0,
+                false => rate * (-rate * x).exp(),
+            },
+            Self::Binomial { trials, probability } => {
+                if x != x.floor() || x < 0.0 || x > (trials as f64) { return 0.0; }
+                let n = trials as f64;
+                let ln_choose = math::ln_gamma(n + 1.0) - math::ln_gamma(x + 1.0)
+                    - math::ln_gamma(n - x + 1.0);
+                // The powers are exact where the probability is zero or one.
+                if probability == 0.0 || probability == 1.0 {
+                    let k = x as i32;
+                    return probability.powi(k) * (1.0 - probability).powi(trials as i32 - k);
+                }
+                (ln_choose + (x * probability.ln()) + ((n - x) * (1.0 - probability).ln())).exp()
+            },
+            Self::Poisson { rate } => {
+                if x != x.floor() || x < 0.0 { return 0.0; }
+                ((x * rate.ln()) - rate - math::ln_gamma(x + 1.0)).exp()
+            },
+        }
+    }
+
+    /// Evaluates the cumulative distribution function `P(X <= x)`.
+    pub fn cdf(&self, x: f64) -> f64 {
+        if x.is_nan() { return f64::NAN; }
+        // Not every closed form below is defined at the infinite bounds of a tail.
+        if x == f64::INFINITY { return 1.0; }
+        if x == f64::NEG_INFINITY { return 0.0; }
+        match *self {
+            Self::Normal { mean, standard_deviation: sd } => {
+                0.5 * (1.0 + math::erf((x - mean) / (sd * std::f64::consts::SQRT_2)))
+            },
+            Self::StudentT { degrees_of_freedom: v } => {
+                let tail = 0.5 * math::regularized_beta(0.5 * v, 0.5, v / (v + (x * x)));
+                if x < 0.0 { tail } else { 1.0 - tail }
+            },
+            Self::ChiSquared { degrees_of_freedom: k } => math::regularized_gamma(0.5 * k, 0.5 * x),
+            Self::Uniform { begin, end } => ((x - begin) / (end - begin)).clamp(0.0, 1.0),
+            Self::Exponential { rate } => match x < 0.0 {
+                true => 0.0,
+                false => 1.0 - (-rate * x).exp(),
+            },
+            Self::Binomial { trials, probability } => {
+                let k = x.floor();
+                if k < 0.0 { return 0.0; }
+                if k >= (trials as f64) { return 1.0; }
+                math::regularized_beta((trials as f64) - k, k + 1.0, 1.0 - probability)
+            },
+            Self::Poisson { rate } => {
+                let k = x.floor();
+                if k < 0.0 { return 0.0; }
+                1.0 - math::regularized_gamma(k + 1.0, rate)
+            },
+        }
+    }
+
+    /// Computes `P(a < X < b)`. Either bound may be infinite, for instance to compute the
+    /// probability of a tail. For a continuous distribution this is also `P(a <= X <= b)`.
+    pub fn probability(&self, a: f64, b: f64) -> f64 {
+        if a >= b { return 0.0; }
+        if !self.is_discrete() { return self.cdf(b) - self.cdf(a); }
+        // P(X < b) = P(X <= b') where b' is the greatest integer less than b.
+        let below_b = self.cdf(b.ceil() - 1.0);
+        return f64::max(below_b - self.cdf(a), 0.0);
+    }
+
+    /// Returns the least and greatest integer values of a discrete random variable which are
+    /// plotted. If the support is infinite, the last value is the least integer `n` such that
+    /// `P(X > n)` is within [`DISCRETE_TAIL_PROBABILITY`].
+    ///
+    /// This procedure will panic if the distribution is continuous.
+    pub fn support(&self) -> (u32, u32) {
+        match *self {
+            Self::Binomial { trials, .. } => (0, trials),
+            Self::Poisson { .. } => {
+                let mut n: u32 = 0;
+                while 1.0 - self.cdf(n as f64) > DISCRETE_TAIL_PROBABILITY { n += 1; }
+                (0, n)
+            },
+            _ => panic!("Cannot compute the support of a continuous distribution."),
+        }
+    }
+
+    /// Constructs the graph of the probability density function of a continuous distribution.
+    ///
+    /// This procedure will panic if the distribution is discrete, use [`Self::stems`] instead.
+    pub fn pdf_function<'a>(&self) -> Function<'a> {
+        assert!(!self.is_discrete(), "Cannot plot the mass function of a discrete distribution \
+            as a function, use stems instead.");
+        let distribution = *self;
+        Function::new_elementary(move |x| distribution.pdf(x))
+    }
+
+    /// Constructs the graph of the cumulative distribution function of a continuous
+    /// distribution.
+    ///
+    /// This procedure will panic if the distribution is discrete, use [`Self::cdf_steps`]
+    /// instead.
+    pub fn cdf_function<'a>(&self) -> Function<'a> {
+        assert!(!self.is_discrete(), "Cannot plot the distribution function of a discrete \
+            distribution as a function, use steps instead.");
+        let distribution = *self;
+        Function::new_elementary(move |x| distribution.cdf(x))
+    }
+
+    /// Constructs the stem plot of the probability mass function of a discrete distribution,
+    /// with one stem for each value of [`Self::support`].
+    ///
+    /// This procedure will panic if the distribution is continuous.
+    pub fn stems<'a>(&self) -> Stems<'a> {
+        let (first, last) = self.support();
+        let samples: Vec<(f64, f64)> = (first..=last)
+            .map(|k| (k as f64, self.pdf(k as f64)))
+            .collect();
+        Stems::new_default(&samples)
+    }
+
+    /// Constructs the step function which is the cumulative distribution function of a discrete
+    /// distribution. Each step includes its left end and excludes its right end, as marked by
+    /// the endpoint dots. The last step extends indefinitely to the right.
+    ///
+    /// This procedure will panic if the distribution is continuous.
+    pub fn cdf_steps<'a>(&self) -> Piecewise<'a> {
+        let (first, last) = self.support();
+        let mut steps = Piecewise::new_default();
+        steps.push(Interval::open(NonDecreasing::new(f64::NEG_INFINITY, first as f64)), |_| 0.0);
+        for k in first..=last {
+            let value = self.cdf(k as f64);
+            let end = if k == last { f64::INFINITY } else { (k + 1) as f64 };
+            steps.push(Interval::closed_open(NonDecreasing::new(k as f64, end)), move |_| value);
+        }
+        return steps;
+    }
+
+    /// Constructs the shaded area beneath the density function of a continuous distribution
+    /// between `a` and `b`, whose area is `P(a < X < b)`. Either bound may be infinite.
+    ///
+    /// This procedure will panic if the distribution is discrete, use [`Stems::highlight`]
+    /// instead.
+    pub fn shade<'a>(&self, a: f64, b: f64) -> Band<'a> {
+        let mut band = Band::new_under(self.pdf_function());
+        band.domain = Some(NonDecreasing::new(a, b));
+        band.breakpoints = vec![a, b];
+        if let Self::Uniform { begin, end } = *self {
+            band.breakpoints.extend([begin, end]);
+        }
+        band.breakpoints.retain(|x| x.is_finite());
+        return band;
+    }
+
+    /// Formats `P(a < X < b)` as TeX, for instance `P(-1 < X < 1) = 0.6827`, omitting any
+    /// infinite bound. The probability is rounded to the given number of decimal places.
+    pub fn probability_tex(&self, a: f64, b: f64, decimals: usize) -> String {
+        let event = match (a.is_infinite(), b.is_infinite()) {
+            (true, true) => String::from("P(X \\in \\mathbb{R})"),
+            (true, false) => format!("P(X < {})", b),
+            (false, true) => format!("P(X > {})", a),
+            (false, false) => format!("P({} < X < {})", a, b),
+        };
+        format!("{} = {:.*}", event, decimals, self.probability(a, b))
+    }
+
+    /// Constructs an annotation of `P(a < X < b)`, see [`Self::probability_tex`], placed above
+    /// the graph over the interval from `a` to `b`.
+    ///
+    /// The annotation is placed over the midpoint of the interval, or if either bound is
+    /// infinite, one standard deviation inward of the finite bound. For a discrete distribution
+    /// it is placed above the tallest stem within the interval.
+    pub fn annotation<'a>(&self, a: f64, b: f64) -> Annotation<'a> {
+        let spread = match self.variance().sqrt() {
+            sd if sd.is_finite() && sd > 0.0 => sd,
+            _ => 1.0,
+        };
+        let x = match (a.is_infinite(), b.is_infinite()) {
+            (true, true) => self.mean(),
+            (true, false) => b - spread,
+            (false, true) => a + spread,
+            (false, false) => 0.5 * (a + b),
+        };
+        let x = if x.is_nan() { 0.0 } else { x };
+        let y = match self.is_discrete() {
+            true => {
+                let (first, last) = self.support();
+                (first..=last)
+                    .map(|k| k as f64)
+                    .filter(|k| a < *k && *k < b)
+                    .map(|k| self.pdf(k))
+                    .fold(0.0, f64::max)
+            },
+            false => self.pdf(x),
+        };
+        let mut annotation = Annotation::new_default(Vec2D { x, y }, 
+            self.probability_tex(a, b, ANNOTATION_DECIMALS));
+        annotation.placement = LabelPlacement::Above;
+        return annotation;
+    }
+}
+
+/// The number of decimal places of the probability in [`Distribution::annotation`].
+pub const ANNOTATION_DECIMALS: usize = 4;
@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+use crate::elements::point::LabelPlacement;
+use crate::math::Vec2D;
+
+// # Annotation
+
+/// A TeX label placed at a coordinate of the plane, without a dot. This is useful for
+/// annotating an area, such as the probability of a shaded interval beneath a density function.
+///
+/// The TeX may be borrowed or owned, so that computed annotations need not outlive
+/// their source. Annotations whose coordinate lies outside of the extent are not drawn.
+#[derive(Clone)]
+pub struct Annotation<'a> {
+    pub pos: Vec2D,
+    pub tex: Cow<'a, str>,
+
+    /// The position of the label relative to [`Self::pos`].
+    pub placement: LabelPlacement,
+    pub typography_height: f64,
+
+    /// The horizontal space reserved for the label. The label is aligned within this space
+    /// towards [`Self::pos`].
+    pub width: f64
+}
+
+impl<'a> Annotation<'a> {
+    pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_WIDTH: f64 = 12.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+
+    /// Constructs a new [`Annotation`] centered on the given coordinate.
+    pub fn new_default(pos: Vec2D, tex: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            pos,
+            tex: tex.into(),
+            placement: LabelPlacement::Center,
+            typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            width: Self::DEFAULT_WIDTH
+        }
+    }
+}
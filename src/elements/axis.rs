@@ -1,12 +1,24 @@
+use crate::math::ClosedInterval;
+
 // # Axis
 
 #[derive(Clone, Copy)]
 pub struct Axis<'a> {
     pub offset: f64,
     pub stride: f64,
-    pub pos: f64,
+
+    /// The position of the axis line on the perpendicular dimension of the plane. 
+    /// For instance, the position of a horizontal axis is a y-coordinate.
+    pub pos: AxisPosition,
     pub tick_label: Option<TickLabel<'a>>,
     pub tick: Tick<'a>,
+
+    /// The TeX title of the axis, or `None` if the axis is untitled.
+    pub title: Option<AxisTitle<'a>>,
+
+    /// The arrowhead(s) drawn at the end(s) of the axis line, or `None` if the axis line
+    /// should not be capped.
+    pub arrowhead: Option<Arrowhead<'a>>,
     pub apply_default_style_class: bool,
     
     /// List of names of custom CSS styles classes to include in the `class` attibute
@@ -19,17 +31,61 @@ pub struct Axis<'a> {
 }
 
 impl<'a> Axis<'a> {
+    /// Constructs a new [`Axis`] which crosses the perpendicular dimension of the plane at `pos`.
     pub fn new_default(offset: f64, stride: f64, pos: f64) -> Self {
+        Self::new_positioned(offset, stride, AxisPosition::AtValue(pos))
+    }
+
+    /// Constructs a new [`Axis`] with an arbitrary [`AxisPosition`]. 
+    ///
+    /// Use [`AxisPosition::AtMin`] or [`AxisPosition::AtMax`] to construct an axis which lies
+    /// along an edge of the extent of the plane, as is typical of a boxed frame.
+    pub fn new_positioned(offset: f64, stride: f64, pos: AxisPosition) -> Self {
         Self { 
             offset, stride, pos,
             tick_label: Some(TickLabel::new_default(TickLabelKind::Decimal)),
             tick: Tick::new_default(),
+            title: None,
+            arrowhead: None,
             apply_default_style_class: true,
             style_class: None
         }
     }
 }
 
+/// The position of an axis line along the perpendicular dimension of the plane.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AxisPosition {
+    /// The axis crosses the perpendicular dimension at the given data coordinate. 
+    /// For example, the conventional x-axis is a horizontal axis at `AtValue(0.0)`.
+    AtValue(f64),
+
+    /// The axis lies along the minimum edge of the extent. That is the bottom edge for a
+    /// horizontal axis and the left edge for a vertical axis. The tick labels are placed outside
+    /// the extent.
+    AtMin,
+
+    /// The axis lies along the maximum edge of the extent. That is the top edge for a
+    /// horizontal axis and the right edge for a vertical axis. The tick labels are placed outside
+    /// the extent.
+    AtMax
+}
+
+impl AxisPosition {
+    /// Computes the data coordinate of the axis line given the `range` of the perpendicular
+    /// dimension of the extent.
+    pub fn resolve(&self, range: ClosedInterval) -> f64 {
+        match self {
+            AxisPosition::AtValue(value) => *value,
+            AxisPosition::AtMin => range.begin(),
+            AxisPosition::AtMax => range.end(),
+        }
+    }
+
+    /// Returns true if this axis lies along an edge of the extent.
+    pub fn is_frame(&self) -> bool { !matches!(self, AxisPosition::AtValue(_)) }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct AxisDefaultStyleClass {
     pub apply_stroke_width: bool,
@@ -73,6 +129,7 @@ pub(crate) fn write_axis_default_style_class(out: &mut impl std::io::Write, clas
 #[derive(Clone, Copy)]
 pub struct Tick<'a> {
     pub len: f64,    
+    pub direction: TickDirection,
     pub apply_default_style_class: bool,
     
     /// List of names of custom CSS styles classes to include in the `class` attibute
@@ -91,10 +148,35 @@ impl<'a> Tick<'a> {
     pub fn new_default() -> Self {
         Self {
             len: Self::DEFAULT_LEN,
+            direction: TickDirection::Centered,
             apply_default_style_class: true,
             style_class: None
         }
     }
+
+    /// Computes the distance the tick extends from the axis line in the outward direction
+    /// and in the inward direction, respectively.
+    pub fn extents(&self) -> (f64, f64) {
+        match self.direction {
+            TickDirection::Inward => (0.0, self.len),
+            TickDirection::Outward => (self.len, 0.0),
+            TickDirection::Centered => (0.5 * self.len, 0.5 * self.len),
+        }
+    }
+}
+
+/// The direction in which the ticks extend from the axis line. 
+///
+/// Outward is the side of the axis line on which the tick labels are placed. For an axis along
+/// an edge of the extent, this is the exterior of the plot. For an axis positioned at a value,
+/// this is below a horizontal axis and to the right of a vertical axis.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TickDirection { 
+    Inward, 
+    Outward,
+
+    /// The tick is bisected by the axis line.
+    Centered
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -137,21 +219,144 @@ pub(crate) fn write_tick_default_style_class(out: &mut impl std::io::Write, clas
 }
 
 
+// # AxisTitle
+
+/// A TeX title naming the quantity along an axis, such as `x` or `\operatorname{Re}`.
+///
+/// The title is placed inside the extent at the positive end of the axis line. That is above
+/// the right end of a horizontal axis and to the right of the top end of a vertical axis.
+#[derive(Clone, Copy)]
+pub struct AxisTitle<'a> {
+    pub tex: &'a str,
+    pub typography_height: f64,
+
+    /// The horizontal space reserved for the title. The title is aligned within this space
+    /// towards the axis line.
+    pub width: f64
+}
+
+impl<'a> AxisTitle<'a> {
+    pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_WIDTH: f64 = 6.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+
+    pub fn new_default(tex: &'a str) -> Self {
+        Self {
+            tex,
+            typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            width: Self::DEFAULT_WIDTH
+        }
+    }
+}
+
+// # Arrowhead
+
+/// The ends of an axis line which are capped with an arrowhead. The positive end of a
+/// horizontal axis is the right end, and the positive end of a vertical axis is the top end.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ArrowheadEnds { Positive, Negative, Both }
+
+/// An arrowhead capping one or both ends of an axis line. 
+///
+/// The arrowhead is emitted as an SVG `<marker>` definition within a `<defs>` block preceding
+/// the axis line. The dimensions are expressed in the coordinate system of the SVG viewport
+/// and not the coordinate system of the plane, therefore the arrowhead has the same size
+/// regardless of the extent of the plane.
+#[derive(Clone, Copy)]
+pub struct Arrowhead<'a> {
+    pub ends: ArrowheadEnds,
+
+    /// The distance from the base of the arrowhead to its tip, measured along the axis line.
+    pub len: f64,
+
+    /// The length of the base of the arrowhead, measured perpendicular to the axis line.
+    pub width: f64,
+    pub apply_default_style_class: bool,
+
+    /// List of names of custom CSS styles classes to include in the `class` attibute
+    /// of the `path` element within the `marker` element. 
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>,
+}
+
+impl<'a> Arrowhead<'a> {
+    pub const DEFAULT_LEN: f64 = 10.0 * DEFAULT_AXIS_STROKE_WIDTH;
+    pub const DEFAULT_WIDTH: f64 = 7.0 * DEFAULT_AXIS_STROKE_WIDTH;
+
+    pub fn new_default(ends: ArrowheadEnds) -> Self {
+        Self {
+            ends,
+            len: Self::DEFAULT_LEN,
+            width: Self::DEFAULT_WIDTH,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ArrowheadDefaultStyleClass {
+    pub apply_fill: bool,
+    pub apply_stroke: bool
+}
+
+impl ArrowheadDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_fill:   true,
+        apply_stroke: true,
+    };
+    
+    pub const DISABLED: Self = Self {
+        apply_fill:   false,
+        apply_stroke: false,
+    };
+
+    pub const NAME: &'static str = "yapl-def-arrowhead";
+}
+
+pub const DEFAULT_ARROWHEAD_FILL: &'static str = DEFAULT_AXIS_STROKE;
+pub const DEFAULT_ARROWHEAD_STROKE: &'static str = "none";
+
+pub(crate) fn write_arrowhead_default_style_class(out: &mut impl std::io::Write, 
+    class: &ArrowheadDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &ArrowheadDefaultStyleClass::DISABLED { return Ok(()); };
+    write!(out, ".{} {{", ArrowheadDefaultStyleClass::NAME)?;
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_ARROWHEAD_FILL)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_ARROWHEAD_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
 // # TickLabel
 
 #[derive(Clone, Copy)]
 pub struct TickLabel<'a> {
     pub kind: TickLabelKind<'a>,
-    pub typography_height: f64
+    pub typography_height: f64,
+
+    /// The horizontal space reserved for each label of a vertical axis which lies along an
+    /// edge of the extent. The labels of such an axis are placed outside the extent,
+    /// therefore the viewport must be widened to accomodate them.
+    pub frame_width: f64
 }
 
 impl<'a> TickLabel<'a> {
     pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_FRAME_WIDTH: f64 = 3.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
     
     pub fn new_default(kind: TickLabelKind<'a>) -> Self {
         Self {
             kind,
             typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            frame_width: Self::DEFAULT_FRAME_WIDTH
         }
     }
 }
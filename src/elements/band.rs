@@ -0,0 +1,180 @@
+use crate::analysis;
+use crate::elements::cplane::Extent;
+use crate::elements::function::{Function, FunctionKind};
+use crate::elements::series::Series;
+use crate::math::{NonDecreasing, Vec2D};
+
+// # Band
+
+/// The area between two curves, such as a confidence band about a fit or the area beneath
+/// a density function.
+///
+/// The band is filled wherever both edges are defined. The edges themselves are not stroked,
+/// to stroke them add the edges to the [`crate::elements::CoordinatePlane`] separately.
+/// The portions of the band lying outside of the extent are cut away exactly, so there is
+/// no need to clip the band.
+pub struct Band<'a> {
+    pub lower: Function<'a>,
+    pub upper: Function<'a>,
+
+    /// The set of inputs on which the band is filled, or `None` if the band is filled wherever
+    /// both edges are defined within the extent. This is in addition to the
+    /// [`Function::domain`] of each edge.
+    pub domain: Option<NonDecreasing>,
+
+    /// Inputs at which both edges are evaluated in addition to the sampled inputs, such as the
+    /// inputs at which an edge is not smooth. See [`Self::polygons`].
+    pub breakpoints: Vec<f64>,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Band<'a> {
+    /// Constructs the band between the graphs of `lower` and `upper`.
+    ///
+    /// This procedure will panic if `lower` and `upper` are not of the same [`FunctionKind`].
+    pub fn new_default(lower: Function<'a>, upper: Function<'a>) -> Self {
+        assert_eq!(lower.kind, upper.kind, "Cannot fill between a function of x and a function \
+            of y.");
+        Self {
+            lower,
+            upper,
+            domain: None,
+            breakpoints: Vec::new(),
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Constructs the band between two data series. Each edge is the interpolant of its series,
+    /// see [`Series::interpolant`], so the band is broken wherever either series is broken.
+    /// The samples of both series are used as [`Self::breakpoints`].
+    pub fn new_series(lower: &Series, upper: &Series) -> Self {
+        let mut band = Self::new_default(Function::new_elementary(lower.interpolant()),
+            Function::new_elementary(upper.interpolant()));
+        band.breakpoints = lower.samples.iter().chain(upper.samples.iter())
+            .map(|(x, _)| *x)
+            .filter(|x| !x.is_nan())
+            .collect();
+        return band;
+    }
+
+    /// Constructs the band between the graph of `function` and the axis on which its output
+    /// is zero, as in the area beneath a curve.
+    pub fn new_under(function: Function<'a>) -> Self {
+        let zero = Function { kind: function.kind, ..Function::new_elementary(|_| 0.0) };
+        Self::new_default(zero, function)
+    }
+
+    /// Computes the outline of the filled area within the given extent. The coordinates are in
+    /// the coordinate system of the plane.
+    ///
+    /// Both edges are evaluated at a fixed number of evenly spaced inputs, see [`BAND_SAMPLES`],
+    /// at the [`Self::breakpoints`], and at the inputs at which each edge would be sampled if it
+    /// were plotted. Each edge is then clamped to the extent, which yields the intersection of
+    /// the band with the extent.
+    pub fn polygons(&self, extent: &Extent) -> Vec<Vec<Vec2D>> {
+        let (domain, codomain) = self.lower.extent_intervals(extent);
+        let mut domain = Some(domain);
+        let restrictions = [self.domain, self.lower.domain.map(NonDecreasing::from),
+            self.upper.domain.map(NonDecreasing::from)];
+        for restriction in restrictions.into_iter().flatten() {
+            domain = domain.and_then(|domain| domain.intersection(restriction));
+        }
+        let Some(domain) = domain.filter(|domain| domain.len() > 0.0) else { return Vec::new() };
+
+        let mut inputs: Vec<f64> = (0..=BAND_SAMPLES)
+            .map(|i| domain.begin() + (domain.len() * (i as f64) / (BAND_SAMPLES as f64)))
+            .collect();
+        for edge in [&self.lower, &self.upper] {
+            let runs = analysis::sample_runs(edge, extent);
+            inputs.extend(runs.into_iter().flatten().filter(|input| domain.includes(*input)));
+        }
+        inputs.extend(self.breakpoints.iter().copied().filter(|input| domain.includes(*input)));
+        inputs.sort_by(f64::total_cmp);
+        inputs.dedup();
+
+        let clamp = |output: f64| output.clamp(codomain.begin(), codomain.end());
+        let at = |input: f64, output: f64| match self.lower.kind {
+            FunctionKind::OfX => Vec2D { x: input, y: output },
+            FunctionKind::OfY => Vec2D { x: output, y: input },
+        };
+        let mut polygons: Vec<Vec<Vec2D>> = Vec::new();
+        let mut lower: Vec<Vec2D> = Vec::new();
+        let mut upper: Vec<Vec2D> = Vec::new();
+        for input in inputs.into_iter().chain([f64::NAN]) {
+            let l = (self.lower.eval)(input);
+            let u = (self.upper.eval)(input);
+            if !l.is_nan() && !u.is_nan() {
+                lower.push(at(input, clamp(l)));
+                upper.push(at(input, clamp(u)));
+                continue;
+            }
+            // Either edge is undefined, which closes the stretch of the band thus far.
+            if lower.len() >= 2 {
+                upper.extend(lower.drain(..).rev());
+                polygons.push(std::mem::take(&mut upper));
+            }
+            lower.clear();
+            upper.clear();
+        }
+        return polygons;
+    }
+}
+
+/// The number of evenly spaced inputs at which the edges of a [`Band`] are evaluated, in
+/// addition to the inputs at which the edges are sampled when plotted.
+pub const BAND_SAMPLES: usize = 256;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BandDefaultStyleClass {
+    pub apply_fill: bool,
+    pub apply_fill_opacity: bool,
+    pub apply_stroke: bool
+}
+
+impl BandDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_fill:         true,
+        apply_fill_opacity: true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_fill:         false,
+        apply_fill_opacity: false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-band";
+}
+
+pub const DEFAULT_BAND_FILL: &str = "black";
+pub const DEFAULT_BAND_FILL_OPACITY: f64 = 0.15;
+pub const DEFAULT_BAND_STROKE: &str = "none";
+
+pub(crate) fn write_band_default_style_class(out: &mut impl std::io::Write,
+    class: &BandDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &BandDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", BandDefaultStyleClass::NAME)?;
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_BAND_FILL)?;
+    }
+    if class.apply_fill_opacity {
+        write!(out, "fill-opacity: {};", DEFAULT_BAND_FILL_OPACITY)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_BAND_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
@@ -0,0 +1,188 @@
+use crate::math::{BoundingRect, NonDecreasing};
+
+// # Bar Chart
+
+/// A chart of bars rising from a common baseline, with each bar placed either at a numeric
+/// position along the horizontal axis or at one of a sequence of categories.
+///
+/// Categories are identified by TeX labels drawn beneath the bottom edge of the extent, that is
+/// in the space where the tick labels of a horizontal axis along the bottom edge would be drawn.
+/// Such an axis should therefore be constructed without tick labels, see
+/// [`crate::elements::Axis::tick_label`].
+pub struct BarChart<'a> {
+    pub bars: Vec<Bar<'a>>,
+
+    /// The value from which every bar rises, or descends if the value of the bar is smaller.
+    pub baseline: f64,
+
+    /// The height of the category labels in the normalized coordinate system.
+    pub typography_height: f64,
+
+    /// The horizontal space reserved for each category label in the normalized coordinate
+    /// system. The label is centered beneath its bar.
+    pub label_width: f64,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of every `rect` element.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> BarChart<'a> {
+    pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_LABEL_WIDTH: f64 = 6.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+
+    /// The width of each bar as a fraction of the distance between neighboring positions.
+    pub const DEFAULT_BAR_WIDTH_FRACTION: f64 = 0.8;
+
+    pub fn new_default(bars: Vec<Bar<'a>>) -> Self {
+        Self {
+            bars,
+            baseline: 0.0,
+            typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            label_width: Self::DEFAULT_LABEL_WIDTH,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Constructs a chart with one bar centered at each of the given `(position, value)` pairs.
+    /// The bars share a common width, see [`Self::DEFAULT_BAR_WIDTH_FRACTION`], which is
+    /// relative to the smallest distance between two positions.
+    pub fn new_numeric(values: &[(f64, f64)]) -> Self {
+        let mut positions: Vec<f64> = values.iter().map(|(pos, _)| *pos).collect();
+        positions.sort_by(f64::total_cmp);
+        let spacing = positions.windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|gap| *gap > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        let spacing = if spacing.is_finite() { spacing } else { 1.0 };
+        let width = Self::DEFAULT_BAR_WIDTH_FRACTION * spacing;
+        Self::new_default(values.iter().map(|(pos, value)| Bar::new(*pos, width, *value)).collect())
+    }
+
+    /// Constructs a chart with one bar for each of the given `(label, value)` pairs, where the
+    /// label is the TeX typeset beneath the bar. The `i`th category is centered at `i + 1`,
+    /// so the chart is framed by a horizontal extent of `0.5` to `categories.len() + 0.5`.
+    pub fn new_categorical(categories: &[(&'a str, f64)]) -> Self {
+        let bars = categories.iter().enumerate().map(|(i, (tex, value))| {
+            let bar = Bar::new((i + 1) as f64, Self::DEFAULT_BAR_WIDTH_FRACTION, *value);
+            Bar { label: Some(tex), ..bar }
+        });
+        Self::new_default(bars.collect())
+    }
+
+    /// Returns true if any bar of this chart has a category label.
+    pub fn has_labels(&self) -> bool { self.bars.iter().any(|bar| bar.label.is_some()) }
+
+    /// Computes the rectangle covered by each bar within the given bounding rectangle, in the
+    /// coordinate system of the plane. The rectangles are paired with their bars and bars which
+    /// lie entirely outside of the bounding rectangle are omitted.
+    pub fn rects(&self, brect: &BoundingRect) -> Vec<(Bar<'a>, BoundingRect)> {
+        self.bars.iter()
+            .filter_map(|bar| Some((*bar, clip_bar(brect, bar.span, self.baseline, bar.value)?)))
+            .collect()
+    }
+}
+
+/// A single bar of a [`BarChart`] or [`crate::elements::Histogram`].
+#[derive(Clone, Copy, Debug)]
+pub struct Bar<'a> {
+    /// The horizontal extent of the bar.
+    pub span: NonDecreasing,
+
+    /// The value to which the bar extends from the baseline.
+    pub value: f64,
+
+    /// The TeX drawn beneath the bar, or `None` if the bar is unlabeled.
+    pub label: Option<&'a str>,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `rect` element of this bar only, in addition to the classes of the
+    /// chart. This allows for highlighting individual bars.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Bar<'a> {
+    /// Constructs an unlabeled bar of the given width centered at `pos`.
+    pub fn new(pos: f64, width: f64, value: f64) -> Self {
+        Self {
+            span: NonDecreasing::new(pos - (0.5 * width), pos + (0.5 * width)),
+            value,
+            label: None,
+            style_class: None
+        }
+    }
+
+    pub fn center(&self) -> f64 { 0.5 * (self.span.begin() + self.span.end()) }
+}
+
+/// Intersects the rectangle spanning `span` horizontally and `baseline` to `value` vertically
+/// with the given bounding rectangle. Returns `None` if the intersection is empty or if the
+/// value is `NaN`.
+pub(crate) fn clip_bar(brect: &BoundingRect, span: NonDecreasing, baseline: f64, value: f64)
+-> Option<BoundingRect>
+{
+    if value.is_nan() || value == baseline { return None; }
+    let x = brect.x.intersection(span)?;
+    let y = brect.y.intersection(NonDecreasing::minmax(baseline, value))?;
+    if x.len() == 0.0 || y.len() == 0.0 { return None; }
+    return Some(BoundingRect { x, y });
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BarDefaultStyleClass {
+    pub apply_fill: bool,
+    pub apply_fill_opacity: bool,
+    pub apply_stroke: bool,
+    pub apply_stroke_width: bool
+}
+
+impl BarDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_fill:         true,
+        apply_fill_opacity: true,
+        apply_stroke:       true,
+        apply_stroke_width: true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_fill:         false,
+        apply_fill_opacity: false,
+        apply_stroke:       false,
+        apply_stroke_width: false
+    };
+
+    pub const NAME: &'static str = "yapl-def-bar";
+}
+
+pub const DEFAULT_BAR_FILL: &str = "black";
+pub const DEFAULT_BAR_FILL_OPACITY: f64 = 0.15;
+pub const DEFAULT_BAR_STROKE: &str = "black";
+pub const DEFAULT_BAR_STROKE_WIDTH: f64 = crate::elements::axis::DEFAULT_AXIS_STROKE_WIDTH;
+
+pub(crate) fn write_bar_default_style_class(out: &mut impl std::io::Write,
+    class: &BarDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &BarDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", BarDefaultStyleClass::NAME)?;
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_BAR_FILL)?;
+    }
+    if class.apply_fill_opacity {
+        write!(out, "fill-opacity: {};", DEFAULT_BAR_FILL_OPACITY)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_BAR_STROKE)?;
+    }
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_BAR_STROKE_WIDTH)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
@@ -0,0 +1,464 @@
+use crate::elements::annotation::Annotation;
+use crate::elements::cplane::Extent;
+use crate::elements::region::chain;
+use crate::math::{self, Transform2D, Vec2D};
+
+// # Contour
+
+/// The contour map of a scalar field `z = f(x, y)`, consisting of its level curves and
+/// optionally of the filled bands between consecutive levels.
+///
+/// The field is sampled on a grid covering the extent, each cell of which is divided into two
+/// triangles. Within each triangle the field is interpolated linearly, so the level curves are
+/// polylines whose vertices lie on the edges of the triangles. Cells at which the field is not
+/// finite are left blank.
+///
+/// The path of the `i`th level, counting upward from the lowest, is given the class
+/// [`Self::LEVEL_CLASS_PREFIX`] followed by `i`, and the path of the band beneath it the class
+/// [`Self::BAND_CLASS_PREFIX`] followed by `i`. The band above the highest level has index
+/// equal to the number of levels. These classes allow the levels to be styled as a gradient by
+/// a custom stylesheet.
+pub struct Contour<'a> {
+    pub field: Box<dyn Fn(f64, f64) -> f64>,
+    pub levels: ContourLevels,
+
+    /// The number of grid cells along the longer side of the extent, as drawn. The number of
+    /// cells along the shorter side is chosen so that the cells are square.
+    pub resolution: usize,
+
+    /// Whether the bands between consecutive levels are filled.
+    pub filled: bool,
+
+    /// Whether each level curve is labeled with its level. The label is placed at the middle
+    /// of the longest curve of the level, which is broken around the label.
+    pub labeled: bool,
+    pub typography_height: f64,
+
+    /// The horizontal space reserved for each label. The level curve is broken within half of
+    /// this distance of the center of the label.
+    pub label_width: f64,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element of every level curve.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element of every filled band.
+    pub band_style_class: Option<&'a str>
+}
+
+/// The levels at which a [`Contour`] draws level curves.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ContourLevels {
+    /// Round levels, see [`math::nice_step`], spaced so that approximately the given number
+    /// of levels lie within the range of the field over the extent.
+    Auto(usize),
+
+    /// The given levels, which need not be sorted.
+    Explicit(Vec<f64>)
+}
+
+impl<'a> Contour<'a> {
+    pub const DEFAULT_LEVEL_COUNT: usize = 10;
+    pub const DEFAULT_RESOLUTION: usize = 100;
+    pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_LABEL_WIDTH: f64 = 3.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const LEVEL_CLASS_PREFIX: &'static str = "yapl-contour-level-";
+    pub const BAND_CLASS_PREFIX: &'static str = "yapl-contour-band-";
+
+    /// Constructs the contour map of `field` at automatically chosen levels.
+    pub fn new_default<F>(field: F) -> Self
+    where F: Fn(f64, f64) -> f64 + 'static
+    {
+        Self::new_levels(field, ContourLevels::Auto(Self::DEFAULT_LEVEL_COUNT))
+    }
+
+    pub fn new_levels<F>(field: F, levels: ContourLevels) -> Self
+    where F: Fn(f64, f64) -> f64 + 'static
+    {
+        Self {
+            field: Box::new(field),
+            levels,
+            resolution: Self::DEFAULT_RESOLUTION,
+            filled: false,
+            labeled: false,
+            typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            label_width: Self::DEFAULT_LABEL_WIDTH,
+            apply_default_style_class: true,
+            style_class: None,
+            band_style_class: None
+        }
+    }
+
+    /// Computes the levels in increasing order. Automatic levels depend on the range of the
+    /// field over the extent, which requires sampling the field.
+    pub fn levels(&self, extent: &Extent) -> Vec<f64> {
+        match &self.levels {
+            ContourLevels::Auto(_) => self.levels_of(&self.sample(extent)),
+            ContourLevels::Explicit(levels) => sorted(levels.clone()),
+        }
+    }
+
+    /// Computes the level curves, the filled bands if [`Self::filled`] is set, and the labels if
+    /// [`Self::labeled`] is set, within the given extent. All coordinates are in the coordinate
+    /// system of the plane.
+    pub fn trace(&self, extent: &Extent) -> ContourTrace {
+        let grid = self.sample(extent);
+        let levels = self.levels_of(&grid);
+        let mut lines: Vec<Vec<Vec<Vec2D>>> = levels.iter()
+            .map(|level| chain(grid.level_segments(*level)))
+            .collect();
+        let bands = match self.filled {
+            true => grid.bands(&levels),
+            false => Vec::new(),
+        };
+        let mut labels: Vec<(usize, Annotation<'static>)> = Vec::new();
+        if self.labeled {
+            let transform = extent.transform();
+            for (i, polylines) in lines.iter_mut().enumerate() {
+                let Some(pos) = label_position(&transform, polylines, 2.0 * self.label_width)
+                else { continue };
+                let center = transform.apply(pos);
+                *polylines = polylines.iter()
+                    .flat_map(|p| break_around(&transform, p, center, 0.5 * self.label_width))
+                    .collect();
+                let mut label = Annotation::new_default(pos, levels[i].to_string());
+                label.typography_height = self.typography_height;
+                label.width = self.label_width;
+                labels.push((i, label));
+            }
+        }
+        return ContourTrace { levels, lines, bands, labels };
+    }
+
+    fn sample(&self, extent: &Extent) -> Grid {
+        let (width, height) = (extent.width(), extent.height());
+        let longer = usize::max(self.resolution, 1);
+        let shorter = |ratio: f64| usize::max((ratio * (longer as f64)).round() as usize, 1);
+        let (columns, rows) = match width >= height {
+            true => (longer, shorter(height / width)),
+            false => (shorter(width / height), longer),
+        };
+        let (x, y) = (extent.brect.x, extent.brect.y);
+        let mut points: Vec<Vec2D> = Vec::with_capacity((columns + 1) * (rows + 1));
+        for j in 0..=rows {
+            for i in 0..=columns {
+                points.push(Vec2D {
+                    x: x.begin() + (x.len() * ((i as f64) / (columns as f64))),
+                    y: y.begin() + (y.len() * ((j as f64) / (rows as f64)))
+                });
+            }
+        }
+        let values = points.iter().map(|p| (self.field)(p.x, p.y)).collect();
+        return Grid { columns, rows, points, values };
+    }
+
+    fn levels_of(&self, grid: &Grid) -> Vec<f64> {
+        match &self.levels {
+            ContourLevels::Explicit(levels) => sorted(levels.clone()),
+            ContourLevels::Auto(count) => {
+                let finite = grid.values.iter().copied().filter(|v| v.is_finite());
+                let (min, max) = finite.fold((f64::INFINITY, f64::NEG_INFINITY),
+                    |(min, max), v| (f64::min(min, v), f64::max(max, v)));
+                if min >= max { return Vec::new(); }
+                let step = math::nice_step(max - min, usize::max(*count, 1));
+                // Levels at the extremes of the range would enclose nothing.
+                math::multiples_within(step, min, max).into_iter()
+                    .filter(|level| min < *level && *level < max)
+                    .collect()
+            },
+        }
+    }
+}
+
+/// The geometry of a [`Contour`] as computed by [`Contour::trace`].
+pub struct ContourTrace {
+    /// The levels in increasing order.
+    pub levels: Vec<f64>,
+
+    /// The polylines of the level curve of each level, in the order of [`Self::levels`].
+    pub lines: Vec<Vec<Vec<Vec2D>>>,
+
+    /// The polygons whose union is each band, beginning with the band beneath the lowest
+    /// level and ending with the band above the highest level. The polygons of a band do not
+    /// overlap. This is empty unless the contour is filled.
+    pub bands: Vec<Vec<Vec<Vec2D>>>,
+
+    /// The label of each labeled level, paired with the index of the level.
+    pub labels: Vec<(usize, Annotation<'static>)>
+}
+
+fn sorted(mut levels: Vec<f64>) -> Vec<f64> {
+    levels.retain(|level| level.is_finite());
+    levels.sort_by(f64::total_cmp);
+    levels.dedup();
+    return levels;
+}
+
+/// The samples of the field at the vertices of the grid, row by row beginning with the
+/// bottom row.
+struct Grid {
+    columns: usize,
+    rows: usize,
+    points: Vec<Vec2D>,
+    values: Vec<f64>
+}
+
+impl Grid {
+    fn index(&self, i: usize, j: usize) -> usize { (j * (self.columns + 1)) + i }
+
+    /// Returns the vertices of the two triangles of the cell whose bottom left corner is the
+    /// vertex `(i, j)`, or `None` if the field is not finite at a corner of the cell.
+    fn triangles(&self, i: usize, j: usize) -> Option<[[usize; 3]; 2]> {
+        let corners = [self.index(i, j), self.index(i + 1, j), self.index(i + 1, j + 1),
+            self.index(i, j + 1)];
+        if corners.iter().any(|k| !self.values[*k].is_finite()) { return None; }
+        Some([[corners[0], corners[1], corners[2]], [corners[0], corners[2], corners[3]]])
+    }
+
+    /// Locates the point at which the field crosses `level` along the edge between the vertices
+    /// `a` and `b`. The edge is always interpolated in the same direction, regardless of the
+    /// order of the vertices, so that both triangles adjacent to the edge agree on the crossing.
+    fn crossing(&self, a: usize, b: usize, level: f64) -> Vec2D {
+        let (a, b) = (usize::min(a, b), usize::max(a, b));
+        let (p, q) = (self.points[a], self.points[b]);
+        let t = (level - self.values[a]) / (self.values[b] - self.values[a]);
+        return Vec2D { x: p.x + (t * (q.x - p.x)), y: p.y + (t * (q.y - p.y)) };
+    }
+
+    /// Computes the segments of the level curve within each triangle by marching triangles.
+    fn level_segments(&self, level: f64) -> Vec<(Vec2D, Vec2D)> {
+        let mut segments: Vec<(Vec2D, Vec2D)> = Vec::new();
+        for j in 0..self.rows {
+            for i in 0..self.columns {
+                let Some(triangles) = self.triangles(i, j) else { continue };
+                for triangle in triangles {
+                    let above = triangle.map(|k| self.values[k] >= level);
+                    let mut ends: Vec<Vec2D> = Vec::with_capacity(2);
+                    for e in 0..3 {
+                        let (a, b) = (triangle[e], triangle[(e + 1) % 3]);
+                        if above[e] != above[(e + 1) % 3] { ends.push(self.crossing(a, b, level)); }
+                    }
+                    if let [a, b] = ends[..] {
+                        if a != b { segments.push((a, b)); }
+                    }
+                }
+            }
+        }
+        return segments;
+    }
+
+    /// Computes the polygons of each band between consecutive levels. Cells lying entirely
+    /// within a band contribute a single square, and the triangles of the other cells are
+    /// clipped to each band they meet.
+    fn bands(&self, levels: &[f64]) -> Vec<Vec<Vec<Vec2D>>> {
+        let band_of = |value: f64| levels.partition_point(|level| *level <= value);
+        let mut bands: Vec<Vec<Vec<Vec2D>>> = vec![Vec::new(); levels.len() + 1];
+        for j in 0..self.rows {
+            for i in 0..self.columns {
+                let Some(triangles) = self.triangles(i, j) else { continue };
+                let corners = [triangles[0][0], triangles[0][1], triangles[0][2], triangles[1][2]];
+                let band = band_of(self.values[corners[0]]);
+                if corners.iter().all(|k| band_of(self.values[*k]) == band) {
+                    bands[band].push(corners.map(|k| self.points[k]).to_vec());
+                    continue;
+                }
+                for triangle in triangles {
+                    let polygon: Vec<(Vec2D, f64)> = triangle.iter()
+                        .map(|k| (self.points[*k], self.values[*k]))
+                        .collect();
+                    let lowest = triangle.iter().map(|k| band_of(self.values[*k])).min().unwrap();
+                    let highest = triangle.iter().map(|k| band_of(self.values[*k])).max().unwrap();
+                    for (band, polygons) in bands.iter_mut().enumerate()
+                        .take(highest + 1).skip(lowest)
+                    {
+                        let mut clipped = polygon.clone();
+                        if band > 0 { clipped = clip(&clipped, levels[band - 1], true); }
+                        if band < levels.len() { clipped = clip(&clipped, levels[band], false); }
+                        if clipped.len() >= 3 {
+                            polygons.push(clipped.into_iter().map(|(p, _)| p).collect());
+                        }
+                    }
+                }
+            }
+        }
+        return bands;
+    }
+}
+
+/// Clips a polygon, whose vertices are paired with the value of the field, to the half where
+/// the linearly interpolated field is at least `level` if `keep_above` is set, and less than
+/// `level` otherwise.
+fn clip(polygon: &[(Vec2D, f64)], level: f64, keep_above: bool) -> Vec<(Vec2D, f64)> {
+    let inside = |value: f64| match keep_above {
+        true => value >= level,
+        false => value < level,
+    };
+    let mut clipped: Vec<(Vec2D, f64)> = Vec::new();
+    for (k, (p, u)) in polygon.iter().enumerate() {
+        let (q, v) = polygon[(k + 1) % polygon.len()];
+        if inside(*u) { clipped.push((*p, *u)); }
+        if inside(*u) != inside(v) {
+            let t = (level - u) / (v - u);
+            clipped.push((Vec2D { x: p.x + (t * (q.x - p.x)), y: p.y + (t * (q.y - p.y)) }, level));
+        }
+    }
+    return clipped;
+}
+
+/// Chooses the position of the label of a level as the middle of its longest polyline, as
+/// drawn, or `None` if no polyline is at least `min_len` long.
+fn label_position(transform: &Transform2D, polylines: &[Vec<Vec2D>], min_len: f64)
+-> Option<Vec2D>
+{
+    let step_of = |pair: &[Vec2D]| (transform.apply(pair[1]) - transform.apply(pair[0])).len();
+    let len_of = |polyline: &Vec<Vec2D>| polyline.windows(2).map(step_of).sum::<f64>();
+    let (longest, len) = polylines.iter()
+        .map(|polyline| (polyline, len_of(polyline)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+    if len < min_len { return None; }
+    let mut remaining = 0.5 * len;
+    for pair in longest.windows(2) {
+        let step = step_of(pair);
+        if step >= remaining {
+            let t = match step { 0.0 => 0.0, _ => remaining / step };
+            let (p, q) = (pair[0], pair[1]);
+            return Some(p + (t * (q - p)));
+        }
+        remaining -= step;
+    }
+    return longest.last().copied();
+}
+
+/// Splits the polyline where its vertices come within `radius` of the normalized point
+/// `center`, as drawn, dropping those vertices.
+fn break_around(transform: &Transform2D, polyline: &[Vec2D], center: Vec2D, radius: f64)
+-> Vec<Vec<Vec2D>>
+{
+    let mut pieces: Vec<Vec<Vec2D>> = vec![Vec::new()];
+    for p in polyline {
+        match (transform.apply(*p) - center).len() < radius {
+            true => if !pieces[pieces.len() - 1].is_empty() { pieces.push(Vec::new()); },
+            false => pieces.last_mut().unwrap().push(*p),
+        }
+    }
+    pieces.retain(|piece| piece.len() >= 2);
+    return pieces;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ContourDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_linejoin: bool,
+    pub apply_fill: bool,
+    pub apply_stroke: bool
+}
+
+impl ContourDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_linejoin:     true,
+        apply_fill:         true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_linejoin:     false,
+        apply_fill:         false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-contour";
+}
+
+pub const DEFAULT_CONTOUR_STROKE_WIDTH: f64 = crate::elements::line::DEFAULT_LINE_STROKE_WIDTH;
+pub const DEFAULT_CONTOUR_LINEJOIN: &str = "round";
+pub const DEFAULT_CONTOUR_FILL: &str = "none";
+pub const DEFAULT_CONTOUR_STROKE: &str = "black";
+
+pub(crate) fn write_contour_default_style_class(out: &mut impl std::io::Write,
+    class: &ContourDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &ContourDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", ContourDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_CONTOUR_STROKE_WIDTH)?;
+    }
+    if class.apply_linejoin {
+        write!(out, "stroke-linejoin: {};", DEFAULT_CONTOUR_LINEJOIN)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_CONTOUR_FILL)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_CONTOUR_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
+/// The style class of the filled bands of a [`Contour`]. By default the bands are shaded in
+/// increasing opacity from the lowest band, which is transparent, to the highest, through
+/// additional rules targeting the per-band classes, see [`Contour::BAND_CLASS_PREFIX`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ContourBandDefaultStyleClass {
+    pub apply_fill: bool,
+    pub apply_fill_opacity: bool,
+    pub apply_stroke: bool
+}
+
+impl ContourBandDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_fill:         true,
+        apply_fill_opacity: true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_fill:         false,
+        apply_fill_opacity: false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-contour-band";
+}
+
+pub const DEFAULT_CONTOUR_BAND_FILL: &str = "black";
+pub const DEFAULT_CONTOUR_BAND_MAX_FILL_OPACITY: f64 = 0.5;
+pub const DEFAULT_CONTOUR_BAND_STROKE: &str = "none";
+
+/// Writes the band style class, followed by the opacity of each of the first `bands` bands.
+pub(crate) fn write_contour_band_default_style_class(out: &mut impl std::io::Write,
+    class: &ContourBandDefaultStyleClass, bands: usize)
+-> std::io::Result<()>
+{
+    if class == &ContourBandDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", ContourBandDefaultStyleClass::NAME)?;
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_CONTOUR_BAND_FILL)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_CONTOUR_BAND_STROKE)?;
+    }
+    write!(out, "}}")?;
+    if class.apply_fill_opacity {
+        for band in 0..bands {
+            let opacity = match bands {
+                1 => 0.0,
+                _ => DEFAULT_CONTOUR_BAND_MAX_FILL_OPACITY * (band as f64) / ((bands - 1) as f64),
+            };
+            write!(out, ".{}.{}{} {{", ContourBandDefaultStyleClass::NAME,
+                Contour::BAND_CLASS_PREFIX, band)?;
+            write!(out, "fill-opacity: {};", opacity)?;
+            write!(out, "}}")?;
+        }
+    }
+    return Ok(());
+}
@@ -1,12 +1,101 @@
-use crate::elements::axis::Axis;
+use crate::elements::axis::{Axis, AxisPosition, AxisTitle, TickDirection};
+use crate::elements::axis::{SymbolicTickLabel, TickLabel, TickLabelKind};
 use crate::elements::function::Function;
-use crate::math::{BoundingRect, ClosedInterval, NonDecreasing};
+use crate::elements::legend::Legend;
+use crate::elements::line::Line;
+use crate::elements::piecewise::Piecewise;
+use crate::elements::region::Region;
+use crate::elements::series::Series;
+use crate::elements::band::Band;
+use crate::elements::histogram::Histogram;
+use crate::elements::bar_chart::BarChart;
+use crate::elements::stems::Stems;
+use crate::elements::sequence::{Sequence, Cobweb};
+use crate::elements::epsilon_delta::EpsilonDelta;
+use crate::elements::domain_coloring::DomainColoring;
+use crate::elements::contour::Contour;
+use crate::elements::geometry::{AngleMark, CongruenceMark, ParallelMark};
+use crate::elements::annotation::Annotation;
+use crate::elements::point::Point;
+use crate::elements::inset::Inset;
+use crate::math::{BoundingRect, ClosedInterval, NonDecreasing, Transform2D, Vec2D};
 
 pub struct CoordinatePlane<'a> {
     pub extent: Extent,
     pub horizontal_axis: Option<Axis<'a>>,
     pub vertical_axis: Option<Axis<'a>>,
-    pub fns: Vec<Function<'a>>
+
+    /// An additional horizontal axis, typically positioned at [`AxisPosition::AtMax`] so as to
+    /// close the top edge of a boxed frame. The tick labels of this axis are not considered when
+    /// laying out the tick labels of the primary axes.
+    pub secondary_horizontal_axis: Option<Axis<'a>>,
+
+    /// An additional vertical axis, typically positioned at [`AxisPosition::AtMax`] so as to
+    /// close the right edge of a boxed frame. The tick labels of this axis are not considered
+    /// when laying out the tick labels of the primary axes.
+    pub secondary_vertical_axis: Option<Axis<'a>>,
+
+    /// The domain colorings of complex functions over the plane. These are drawn beneath all
+    /// other elements, including the regions.
+    pub domain_colorings: Vec<DomainColoring<'a>>,
+
+    /// The shaded regions of the plane. Regions are drawn beneath all other elements.
+    pub regions: Vec<Region<'a>>,
+
+    /// The contour maps of scalar fields over the plane. The filled bands and level curves are
+    /// drawn above the regions, and the labels together with the annotations.
+    pub contours: Vec<Contour<'a>>,
+
+    /// The filled areas between pairs of curves. Bands are drawn above the regions and beneath
+    /// all other elements.
+    pub bands: Vec<Band<'a>>,
+
+    /// The histograms and bar charts of the plane. Bars are drawn above the bands and beneath
+    /// the functions, so that a density function may be overlaid on a histogram.
+    pub histograms: Vec<Histogram<'a>>,
+    pub bar_charts: Vec<BarChart<'a>>,
+    pub fns: Vec<Function<'a>>,
+
+    /// The piecewise functions plotted on the plane. The pieces are drawn after the functions in
+    /// [`Self::fns`] and the endpoint dots are drawn after the points in [`Self::points`].
+    pub piecewise: Vec<Piecewise<'a>>,
+
+    /// The numeric data series plotted on the plane. Series are drawn after the functions,
+    /// so that measured data overlays the theoretical curves.
+    pub series: Vec<Series<'a>>,
+
+    /// The stem plots of the plane. The stems are drawn after the series and the dots atop
+    /// the stems are drawn after the points in [`Self::points`].
+    pub stems: Vec<Stems<'a>>,
+
+    /// The sequences and cobweb diagrams of the plane. These are drawn after the stem plots
+    /// and the dots of the sequences are drawn after the points in [`Self::points`].
+    pub sequences: Vec<Sequence<'a>>,
+    pub cobwebs: Vec<Cobweb<'a>>,
+
+    /// The epsilon-delta figures of the plane. The bands are drawn above the bars, the graphs
+    /// together with the functions in [`Self::fns`], the guides after the cobwebs, and the dots
+    /// and labels together with the points and annotations respectively.
+    pub epsilon_deltas: Vec<EpsilonDelta<'a>>,
+    pub lines: Vec<Line<'a>>,
+
+    /// The marks of geometry figures, which are drawn after the lines in [`Self::lines`]. The
+    /// labels of the angle marks are drawn together with the annotations.
+    pub angle_marks: Vec<AngleMark<'a>>,
+    pub congruence_marks: Vec<CongruenceMark<'a>>,
+    pub parallel_marks: Vec<ParallelMark<'a>>,
+
+    /// The points of interest marked on the plane. Points are drawn above all other elements
+    /// except for the legend.
+    pub points: Vec<Point<'a>>,
+
+    /// The TeX annotations of the plane. Annotations are drawn above the points.
+    pub annotations: Vec<Annotation<'a>>,
+
+    /// The magnified views of small regions of the plane. Insets are drawn above all other
+    /// elements except for the legend.
+    pub insets: Vec<Inset<'a>>,
+    pub legend: Option<Legend<'a>>
 }
 
 impl<'a> CoordinatePlane<'a> {
@@ -22,7 +111,65 @@ impl<'a> CoordinatePlane<'a> {
             },
             horizontal_axis: Some(Axis::new_default(0.0, 1.0, 0.0)),
             vertical_axis: Some(Axis::new_default(0.0, 1.0, 0.0)),
-            fns: Vec::new()
+            secondary_horizontal_axis: None,
+            secondary_vertical_axis: None,
+            domain_colorings: Vec::new(),
+            regions: Vec::new(),
+            contours: Vec::new(),
+            bands: Vec::new(),
+            histograms: Vec::new(),
+            bar_charts: Vec::new(),
+            fns: Vec::new(),
+            piecewise: Vec::new(),
+            series: Vec::new(),
+            stems: Vec::new(),
+            sequences: Vec::new(),
+            cobwebs: Vec::new(),
+            epsilon_deltas: Vec::new(),
+            lines: Vec::new(),
+            angle_marks: Vec::new(),
+            congruence_marks: Vec::new(),
+            parallel_marks: Vec::new(),
+            points: Vec::new(),
+            annotations: Vec::new(),
+            insets: Vec::new(),
+            legend: None
+        }
+    }
+
+    /// Constructs a new [`CoordinatePlane`] enclosed by a boxed frame, as is typical of
+    /// scientific figures. The labeled primary axes lie along the bottom and left edges of the
+    /// extent and the unlabeled secondary axes lie along the top and right edges. All ticks
+    /// point inward.
+    pub fn new_boxed() -> Self {
+        let mut primary = Axis::new_positioned(0.0, 1.0, AxisPosition::AtMin);
+        primary.tick.direction = TickDirection::Inward;
+        let mut secondary = Axis::new_positioned(0.0, 1.0, AxisPosition::AtMax);
+        secondary.tick.direction = TickDirection::Inward;
+        secondary.tick_label = None;
+        CoordinatePlane {
+            horizontal_axis: Some(primary),
+            vertical_axis: Some(primary),
+            secondary_horizontal_axis: Some(secondary),
+            secondary_vertical_axis: Some(secondary),
+            ..Self::new_elementary()
+        }
+    }
+
+    /// Constructs a new [`CoordinatePlane`] representing the complex plane. The horizontal axis
+    /// is titled as the real axis and the vertical axis is titled as the imaginary axis, whose
+    /// tick labels are multiples of `i`.
+    pub fn new_complex() -> Self {
+        let mut real = Axis::new_default(0.0, 1.0, 0.0);
+        real.title = Some(AxisTitle::new_default("\\operatorname{Re}"));
+        let mut imaginary = Axis::new_default(0.0, 1.0, 0.0);
+        imaginary.title = Some(AxisTitle::new_default("\\operatorname{Im}"));
+        imaginary.tick_label = Some(TickLabel::new_default(TickLabelKind::Symbolic(
+            SymbolicTickLabel { offset_symbol_tex: None, stride_symbol_tex: "i" })));
+        CoordinatePlane {
+            horizontal_axis: Some(real),
+            vertical_axis: Some(imaginary),
+            ..Self::new_elementary()
         }
     }
 
@@ -33,6 +180,16 @@ impl<'a> CoordinatePlane<'a> {
             ..Self::new_elementary()
         }
     }
+
+    /// Iterates over the primary and secondary horizontal axes, skipping those which are `None`.
+    pub fn horizontal_axes(&self) -> impl Iterator<Item = &Axis<'a>> {
+        self.horizontal_axis.iter().chain(self.secondary_horizontal_axis.iter())
+    }
+
+    /// Iterates over the primary and secondary vertical axes, skipping those which are `None`.
+    pub fn vertical_axes(&self) -> impl Iterator<Item = &Axis<'a>> {
+        self.vertical_axis.iter().chain(self.secondary_vertical_axis.iter())
+    }
 }
 
 pub struct Extent {
@@ -45,4 +202,25 @@ impl Extent {
     pub fn width(&self) -> f64 { self.x_scale * self.brect.x.len() }
     pub fn height(&self) -> f64 { self.y_scale * self.brect.y.len() }
     pub fn area(&self) -> f64 { self.width() * self.height() }
+
+    /// Computes the map from the coordinates of the plane into the normalized coordinate
+    /// system, in which the longest side of the bounding rectangle has length one before
+    /// scaling, the top left corner of the bounding rectangle is the origin, and the vertical
+    /// axis points downward.
+    ///
+    /// This procedure will panic if the area of the extent is zero, since the normalized
+    /// coordinate system is then undefined.
+    pub fn transform(&self) -> Transform2D {
+        assert_ne!(self.area(), 0.0, "container's interior is undefined and therefore so \
+            is the interior coordinate system.");
+        let maximum_dimension = f64::max(self.brect.x.len(), self.brect.y.len());
+        let origin = Vec2D { x: self.brect.x.begin(), y: self.brect.y.end() };
+        Transform2D {
+            xx: self.x_scale,
+            yy: -self.y_scale,
+            origin,
+            divisor: maximum_dimension,
+            ..Transform2D::IDENTITY
+        }
+    }
 }
@@ -0,0 +1,147 @@
+use crate::elements::cplane::Extent;
+use crate::math::Complex;
+use crate::raster::Raster;
+
+// # Domain Coloring
+
+/// The domain coloring of a complex function `w = f(z)` over the extent of the plane, which
+/// is taken to be the complex plane with the real part along the horizontal axis.
+///
+/// Each point `z` is colored by the value `f(z)`: the hue gives the argument of `w`, beginning
+/// with red on the positive real axis and proceeding through yellow, green, cyan, blue and
+/// magenta counterclockwise, and the brightness gives the modulus of `w`, so that zeros appear
+/// black and poles appear white. The coloring is rasterized and embedded as a PNG image
+/// clipped to the extent, and is drawn beneath all other elements.
+///
+/// See [`crate::elements::CoordinatePlane::new_complex`] for a plane with axes labeled
+/// accordingly.
+pub struct DomainColoring<'a> {
+    pub function: Box<dyn Fn(Complex) -> Complex>,
+
+    /// The number of pixels along the longer side of the extent, as drawn. The number of pixels
+    /// along the shorter side is chosen so that the pixels are square.
+    pub resolution: usize,
+
+    /// Whether the brightness also cycles with each doubling of the modulus, so that the level
+    /// curves of the modulus appear as the edges of bands.
+    pub modulus_contours: bool,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `image` element.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> DomainColoring<'a> {
+    pub const DEFAULT_RESOLUTION: usize = 400;
+
+    pub fn new_default<F>(function: F) -> Self
+    where F: Fn(Complex) -> Complex + 'static
+    {
+        Self {
+            function: Box::new(function),
+            resolution: Self::DEFAULT_RESOLUTION,
+            modulus_contours: false,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Computes the number of columns and rows of pixels covering the given extent.
+    pub fn dimensions(&self, extent: &Extent) -> (usize, usize) {
+        let (width, height) = (extent.width(), extent.height());
+        let longer = usize::max(self.resolution, 1);
+        let shorter = |ratio: f64| usize::max((ratio * (longer as f64)).round() as usize, 1);
+        match width >= height {
+            true => (longer, shorter(height / width)),
+            false => (shorter(width / height), longer),
+        }
+    }
+
+    /// Rasterizes the coloring over the given extent. The top left pixel covers the top left
+    /// corner of the extent, and each pixel is colored by the value of the function at the
+    /// center of the pixel.
+    pub fn rasterize(&self, extent: &Extent) -> Raster {
+        let (columns, rows) = self.dimensions(extent);
+        let mut raster = Raster::new(columns, rows);
+        let (x, y) = (extent.brect.x, extent.brect.y);
+        for row in 0..rows {
+            let im = y.end() - ((((row as f64) + 0.5) / (rows as f64)) * y.len());
+            for column in 0..columns {
+                let re = x.begin() + ((((column as f64) + 0.5) / (columns as f64)) * x.len());
+                let w = (self.function)(Complex::new(re, im));
+                raster.set(column, row, domain_color(w, self.modulus_contours));
+            }
+        }
+        return raster;
+    }
+}
+
+/// Computes the color of the value `w`. See [`DomainColoring`] for a description of the
+/// color scheme. Values which are `NaN` are colored grey.
+pub fn domain_color(w: Complex, modulus_contours: bool) -> [u8; 3] {
+    if w.re.is_nan() || w.im.is_nan() { return [128, 128, 128]; }
+    let modulus = w.modulus();
+    if modulus.is_infinite() { return [255, 255, 255]; }
+    let hue = (w.arg() / std::f64::consts::TAU).rem_euclid(1.0);
+    let mut lightness = std::f64::consts::FRAC_2_PI * modulus.atan();
+    if modulus_contours && modulus > 0.0 {
+        lightness *= 0.7 + (0.3 * modulus.log2().rem_euclid(1.0));
+    }
+    return hsl_to_rgb(hue, 1.0, lightness);
+}
+
+/// Converts a color given by hue, saturation and lightness, each in the interval `[0, 1]`,
+/// to RGB.
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> [u8; 3] {
+    let chroma = (1.0 - ((2.0 * lightness) - 1.0).abs()) * saturation;
+    let sector = 6.0 * hue;
+    let x = chroma * (1.0 - ((sector.rem_euclid(2.0)) - 1.0).abs());
+    let (r, g, b) = match sector as usize {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - (0.5 * chroma);
+    let channel = |c: f64| ((c + m).clamp(0.0, 1.0) * 255.0).round() as u8;
+    return [channel(r), channel(g), channel(b)];
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DomainColoringDefaultStyleClass {
+    pub apply_image_rendering: bool
+}
+
+impl DomainColoringDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_image_rendering: true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_image_rendering: false
+    };
+
+    pub const NAME: &'static str = "yapl-def-domain-coloring";
+}
+
+pub const DEFAULT_DOMAIN_COLORING_IMAGE_RENDERING: &str = "optimizeQuality";
+
+pub(crate) fn write_domain_coloring_default_style_class(out: &mut impl std::io::Write,
+    class: &DomainColoringDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &DomainColoringDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", DomainColoringDefaultStyleClass::NAME)?;
+    if class.apply_image_rendering {
+        write!(out, "image-rendering: {};", DEFAULT_DOMAIN_COLORING_IMAGE_RENDERING)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
@@ -0,0 +1,222 @@
+use crate::elements::annotation::Annotation;
+use crate::elements::function::Function;
+use crate::elements::point::{LabelPlacement, Point, PointFill};
+use crate::elements::series::Series;
+use crate::math::{BoundingRect, NonDecreasing, Vec2D};
+
+// # Epsilon-Delta
+
+/// The figure accompanying the epsilon-delta definition of the limit of `f(x)` as `x`
+/// approaches `a`.
+///
+/// The figure consists of the graph of the function, a horizontal band of half-height epsilon
+/// about the limit `L`, a vertical band of half-width delta about `a`, a dashed guide from each
+/// axis to the point `(a, L)`, and a dot at that point. The dot is hollow unless the function
+/// attains the limit at `a`. The bands are labeled with TeX along the edges of the extent.
+pub struct EpsilonDelta<'a> {
+    pub function: Function<'a>,
+    pub a: f64,
+    pub limit: f64,
+    pub epsilon: f64,
+    pub delta: f64,
+
+    /// The TeX labeling the epsilon band, which is placed against the right edge of the extent.
+    pub epsilon_tex: &'a str,
+
+    /// The TeX labeling the delta band, which is placed against the top edge of the extent.
+    pub delta_tex: &'a str,
+    pub typography_height: f64,
+
+    /// The radius of the dot at `(a, L)` in the normalized coordinate system.
+    pub point_radius: f64,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of both of the `rect` elements of the bands.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub band_style_class: Option<&'a str>,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element of the guides.
+    pub guide_style_class: Option<&'a str>
+}
+
+impl<'a> EpsilonDelta<'a> {
+    pub const DEFAULT_EPSILON_TEX: &'static str = "L \\pm \\varepsilon";
+    pub const DEFAULT_DELTA_TEX: &'static str = "a \\pm \\delta";
+    pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_POINT_RADIUS: f64 = Point::DEFAULT_RADIUS;
+
+    /// This procedure will panic if `epsilon` or `delta` is negative.
+    pub fn new_default(function: Function<'a>, a: f64, limit: f64, epsilon: f64, delta: f64)
+    -> Self
+    {
+        assert!(epsilon >= 0.0 && delta >= 0.0, "Cannot construct epsilon-delta figure with \
+            epsilon {} and delta {}.", epsilon, delta);
+        Self {
+            function,
+            a,
+            limit,
+            epsilon,
+            delta,
+            epsilon_tex: Self::DEFAULT_EPSILON_TEX,
+            delta_tex: Self::DEFAULT_DELTA_TEX,
+            typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            point_radius: Self::DEFAULT_POINT_RADIUS,
+            apply_default_style_class: true,
+            band_style_class: None,
+            guide_style_class: None
+        }
+    }
+
+    /// Computes the epsilon band and the delta band, in that order, within the given bounding
+    /// rectangle. Either band is `None` if it lies outside of the bounding rectangle.
+    pub fn bands(&self, brect: &BoundingRect) -> [Option<BoundingRect>; 2] {
+        let epsilon = NonDecreasing::new(self.limit - self.epsilon, self.limit + self.epsilon);
+        let delta = NonDecreasing::new(self.a - self.delta, self.a + self.delta);
+        [
+            brect.y.intersection(epsilon).map(|y| BoundingRect { x: brect.x, y }),
+            brect.x.intersection(delta).map(|x| BoundingRect { x, y: brect.y })
+        ]
+    }
+
+    /// Computes the guides from the horizontal axis up to `(a, L)` and across to the vertical
+    /// axis, within the given bounding rectangle. If an axis lies outside of the bounding
+    /// rectangle, its guide ends at the nearest edge instead.
+    pub fn guides(&self, brect: &BoundingRect) -> Vec<Vec<Vec2D>> {
+        let x0 = 0f64.clamp(brect.x.begin(), brect.x.end());
+        let y0 = 0f64.clamp(brect.y.begin(), brect.y.end());
+        let path = vec![(self.a, y0), (self.a, self.limit), (x0, self.limit)];
+        Series::new_polyline(path).polylines(brect)
+    }
+
+    /// Constructs the dot at `(a, L)`, which is filled only if `f(a) = L`.
+    pub fn point(&self) -> Point<'a> {
+        let mut point = Point::new_default(Vec2D { x: self.a, y: self.limit });
+        point.radius = self.point_radius;
+        point.fill = match (self.function.eval)(self.a) == self.limit {
+            true => PointFill::Closed,
+            false => PointFill::Open,
+        };
+        return point;
+    }
+
+    /// Constructs the labels of the epsilon band and the delta band, in that order, within the
+    /// given bounding rectangle. The epsilon label is centered on `L` against the right edge and
+    /// the delta label is centered on `a` against the top edge.
+    pub fn labels(&self, brect: &BoundingRect) -> [Annotation<'a>; 2] {
+        let mut epsilon = Annotation::new_default(Vec2D { x: brect.x.end(), y: self.limit },
+            self.epsilon_tex);
+        epsilon.placement = LabelPlacement::Left;
+        epsilon.typography_height = self.typography_height;
+        let mut delta = Annotation::new_default(Vec2D { x: self.a, y: brect.y.end() },
+            self.delta_tex);
+        delta.placement = LabelPlacement::Below;
+        delta.typography_height = self.typography_height;
+        [epsilon, delta]
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EpsilonDeltaBandDefaultStyleClass {
+    pub apply_fill: bool,
+    pub apply_fill_opacity: bool,
+    pub apply_stroke: bool
+}
+
+impl EpsilonDeltaBandDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_fill:         true,
+        apply_fill_opacity: true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_fill:         false,
+        apply_fill_opacity: false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-epsilon-delta-band";
+}
+
+pub const DEFAULT_EPSILON_DELTA_BAND_FILL: &str = "black";
+pub const DEFAULT_EPSILON_DELTA_BAND_FILL_OPACITY: f64 = 0.1;
+pub const DEFAULT_EPSILON_DELTA_BAND_STROKE: &str = "none";
+
+pub(crate) fn write_epsilon_delta_band_default_style_class(out: &mut impl std::io::Write,
+    class: &EpsilonDeltaBandDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &EpsilonDeltaBandDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", EpsilonDeltaBandDefaultStyleClass::NAME)?;
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_EPSILON_DELTA_BAND_FILL)?;
+    }
+    if class.apply_fill_opacity {
+        write!(out, "fill-opacity: {};", DEFAULT_EPSILON_DELTA_BAND_FILL_OPACITY)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_EPSILON_DELTA_BAND_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EpsilonDeltaGuideDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_stroke_dasharray: bool,
+    pub apply_fill: bool,
+    pub apply_stroke: bool
+}
+
+impl EpsilonDeltaGuideDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width:     true,
+        apply_stroke_dasharray: true,
+        apply_fill:             true,
+        apply_stroke:           true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width:     false,
+        apply_stroke_dasharray: false,
+        apply_fill:             false,
+        apply_stroke:           false
+    };
+
+    pub const NAME: &'static str = "yapl-def-epsilon-delta-guide";
+}
+
+pub const DEFAULT_EPSILON_DELTA_GUIDE_STROKE_WIDTH: f64 =
+    crate::elements::line::DEFAULT_LINE_STROKE_WIDTH;
+pub const DEFAULT_EPSILON_DELTA_GUIDE_STROKE_DASHARRAY: f64 =
+    4.0 * DEFAULT_EPSILON_DELTA_GUIDE_STROKE_WIDTH;
+pub const DEFAULT_EPSILON_DELTA_GUIDE_FILL: &str = "none";
+pub const DEFAULT_EPSILON_DELTA_GUIDE_STROKE: &str = "black";
+
+pub(crate) fn write_epsilon_delta_guide_default_style_class(out: &mut impl std::io::Write,
+    class: &EpsilonDeltaGuideDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &EpsilonDeltaGuideDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", EpsilonDeltaGuideDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_EPSILON_DELTA_GUIDE_STROKE_WIDTH)?;
+    }
+    if class.apply_stroke_dasharray {
+        write!(out, "stroke-dasharray: {};", DEFAULT_EPSILON_DELTA_GUIDE_STROKE_DASHARRAY)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_EPSILON_DELTA_GUIDE_FILL)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_EPSILON_DELTA_GUIDE_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
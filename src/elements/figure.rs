@@ -0,0 +1,132 @@
+use crate::elements::axis::AxisPosition;
+use crate::elements::cplane::CoordinatePlane;
+use crate::math::{BoundingRect, ClosedInterval, NonDecreasing};
+
+// # Figure
+
+/// Several [`CoordinatePlane`]s arranged side by side within a single SVG, such as a function
+/// alongside its first and second derivatives. The figure is compiled by
+/// [`crate::codegen::codegen_figure`], which emits a single stylesheet covering every panel.
+///
+/// Panels without an explicit [`Panel::rect`] are arranged in a grid, filling each row from
+/// left to right in the order of [`Figure::panels`]. The figure uses a coordinate system in
+/// which each cell of the grid has width one, the top left corner of the grid is the origin,
+/// and the vertical axis points downward. The height of each row is that of its tallest plane.
+pub struct Figure<'a> {
+    pub panels: Vec<Panel<'a>>,
+
+    /// The number of cells in each row of the grid.
+    pub columns: usize,
+
+    /// The gap between adjacent cells of the grid in the coordinate system of the figure.
+    pub spacing: f64
+}
+
+impl<'a> Figure<'a> {
+    pub const DEFAULT_SPACING: f64 = 1.0 / 20.0;
+
+    /// Constructs an empty figure whose grid has the given number of columns.
+    pub fn new_grid(columns: usize) -> Self {
+        Self { panels: Vec::new(), columns, spacing: Self::DEFAULT_SPACING }
+    }
+
+    /// Unifies the ranges of the planes along the given dimensions, so that the panels may be
+    /// compared at a glance.
+    ///
+    /// Among the panels arranged in the grid, the tick labels of a frame axis along the bottom
+    /// edge are removed unless the panel is the lowest of its column, and the tick labels of a
+    /// frame axis along the left edge are removed unless the panel is the first of its row.
+    /// This procedure should therefore be called once every panel has been added.
+    pub fn share_axes(&mut self, axes: SharedAxes) {
+        let horizontal = axes != SharedAxes::Vertical;
+        let vertical = axes != SharedAxes::Horizontal;
+        if horizontal {
+            let Some(x) = hull(self.panels.iter().map(|p| p.cplane.extent.brect.x)) else { return };
+            for panel in &mut self.panels { panel.cplane.extent.brect.x = x; }
+        }
+        if vertical {
+            let Some(y) = hull(self.panels.iter().map(|p| p.cplane.extent.brect.y)) else { return };
+            for panel in &mut self.panels { panel.cplane.extent.brect.y = y; }
+        }
+        let columns = usize::max(self.columns, 1);
+        let grid: Vec<usize> = (0..self.panels.len())
+            .filter(|&i| self.panels[i].rect.is_none())
+            .collect();
+        for (cell, &i) in grid.iter().enumerate() {
+            let cplane = &mut self.panels[i].cplane;
+            let lowest = cell + columns >= grid.len();
+            if horizontal && !lowest {
+                if let Some(axis) = &mut cplane.horizontal_axis {
+                    if axis.pos == AxisPosition::AtMin { axis.tick_label = None; }
+                }
+            }
+            let first = cell % columns == 0;
+            if vertical && !first {
+                if let Some(axis) = &mut cplane.vertical_axis {
+                    if axis.pos == AxisPosition::AtMin { axis.tick_label = None; }
+                }
+            }
+        }
+    }
+}
+
+/// The dimensions along which the panels of a [`Figure`] share their range.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SharedAxes { Horizontal, Vertical, Both }
+
+/// A [`CoordinatePlane`] placed within a [`Figure`].
+pub struct Panel<'a> {
+    pub cplane: CoordinatePlane<'a>,
+
+    /// The TeX title centered above the plane, or `None` if the panel is untitled.
+    pub title: Option<PanelTitle<'a>>,
+
+    /// The rectangle occupied by the plane in the coordinate system of the figure, excluding
+    /// its title, or `None` if the panel is arranged in the grid. The plane is scaled to fit
+    /// the rectangle without distortion and centered within it.
+    pub rect: Option<BoundingRect>
+}
+
+impl<'a> Panel<'a> {
+    pub fn new_default(cplane: CoordinatePlane<'a>) -> Self {
+        Self { cplane, title: None, rect: None }
+    }
+
+    /// Computes the height of the space reserved for the title above the plane.
+    pub fn title_height(&self) -> f64 {
+        match &self.title {
+            Some(title) => 1.5 * title.typography_height,
+            None => 0.0,
+        }
+    }
+}
+
+/// The TeX title of a [`Panel`], such as `f'(x)`.
+#[derive(Clone, Copy)]
+pub struct PanelTitle<'a> {
+    pub tex: &'a str,
+    pub typography_height: f64,
+
+    /// The horizontal space reserved for the title, within which the title is centered.
+    pub width: f64
+}
+
+impl<'a> PanelTitle<'a> {
+    pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_WIDTH: f64 = 12.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+
+    pub fn new_default(tex: &'a str) -> Self {
+        Self {
+            tex,
+            typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            width: Self::DEFAULT_WIDTH
+        }
+    }
+}
+
+/// Computes the smallest interval covering each of the given intervals, or `None` if there are
+/// none.
+fn hull(intervals: impl Iterator<Item = ClosedInterval>) -> Option<ClosedInterval> {
+    intervals.reduce(|a, b| ClosedInterval::new(NonDecreasing::new(
+        f64::min(a.begin(), b.begin()), f64::max(a.end(), b.end()))))
+}
@@ -1,3 +1,10 @@
+use crate::elements::cplane::Extent;
+use crate::elements::line::Line;
+use crate::elements::point::Point;
+use crate::math::{self, ClosedInterval, Interval, Vec2D};
+use crate::plotfn::PlotFnParams;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum FunctionKind { OfX, OfY }
 
 pub struct Function<'a> {
@@ -22,6 +29,20 @@ pub struct Function<'a> {
 
     pub kind: FunctionKind,
 
+    /// The set of inputs on which the function is plotted, or `None` if the function is plotted
+    /// wherever it is defined within the extent. The bounds may be infinite, for instance to
+    /// restrict a function of x to the right half of the plane.
+    ///
+    /// The restriction affects only which inputs are sampled. The tolerances of the plot are
+    /// still relative to the extent, so a restricted graph is drawn with the same fidelity
+    /// as an unrestricted one. The graph is sampled up to the bounds of the domain whether or
+    /// not they are included, only the endpoint dots of a [`crate::elements::Piecewise`]
+    /// distinguish the two.
+    pub domain: Option<Interval>,
+
+    /// A TeX label placed beside the graph of the function, or `None` if the graph is unlabeled.
+    pub label: Option<CurveLabel<'a>>,
+
     pub apply_default_style_class: bool,
 
     /// Space-delimited list of names of custom CSS styles classes to include in the `class`
@@ -59,10 +80,143 @@ impl<'a> Function<'a> {
             zero_tolerance_factor: Self::DEFAULT_ZERO_TOLERANCE_FACTOR,
             undef_tolerance_factor: Self::DEFAULT_UNDEF_TOLERANCE_FACTOR,
             kind: Self::DEFAULT_KIND,
+            domain: None,
+            label: None,
             apply_default_style_class: true,
             style_class: None,
         }
     }
+
+    /// Constructs a new [`Function`] like [`Self::new_elementary`] whose graph is restricted
+    /// to the given domain.
+    pub fn new_restricted<F>(domain: Interval, f: F) -> Self
+    where F: Fn(f64) -> f64 + 'static
+    {
+        Function { domain: Some(domain), ..Self::new_elementary(f) }
+    }
+
+    /// Returns the intervals of the extent spanned by the input and the output of this function
+    /// respectively. For [`FunctionKind::OfX`] this is the horizontal interval followed by the
+    /// vertical interval, and vice versa for [`FunctionKind::OfY`].
+    pub fn extent_intervals(&self, extent: &Extent) -> (ClosedInterval, ClosedInterval) {
+        match self.kind {
+            FunctionKind::OfX => (extent.brect.x, extent.brect.y),
+            FunctionKind::OfY => (extent.brect.y, extent.brect.x),
+        }
+    }
+
+    /// Computes the parameters of [`crate::plotfn::plotfn`] which are used to sample this function
+    /// when it is plotted within the given extent. Returns `None` if the [`Self::domain`] of
+    /// this function does not meet the extent, in which case there is nothing to plot.
+    pub fn plotfn_params(&self, extent: &Extent) -> Option<PlotFnParams> {
+        let (domain, codomain) = self.extent_intervals(extent);
+    
+        let error_tolerance = codomain.len() / self.error_tolerance_factor;
+        let zero_tolerance = domain.len() / self.zero_tolerance_factor;
+        let undef_tolerance = domain.len() / self.undef_tolerance_factor;
+
+        let domain = match self.domain {
+            Some(restriction) => domain.intersection(restriction).filter(|i| i.len() > 0.0)?,
+            None => domain,
+        };
+
+        Some(PlotFnParams { domain, codomain, min_depth: self.min_depth, error_tolerance,
+            zero_tolerance, undef_tolerance })
+    }
+
+    /// Computes the coordinate of the point on the graph of this function whose input is `input`.
+    /// For [`FunctionKind::OfX`] this is `(input, f(input))` and for [`FunctionKind::OfY`]
+    /// this is `(f(input), input)`.
+    pub fn coordinate_at(&self, input: f64) -> Vec2D {
+        let output = (self.eval)(input);
+        match self.kind {
+            FunctionKind::OfX => Vec2D { x: input, y: output },
+            FunctionKind::OfY => Vec2D { x: output, y: input },
+        }
+    }
+
+    /// Constructs a [`Point`] marking the point on the graph of this function whose input is
+    /// `input`. This is useful for marking the point(s) of tangency of [`Self::tangent`] and
+    /// [`Self::secant`].
+    pub fn point_at(&self, input: f64) -> Point<'a> {
+        Point::new_default(self.coordinate_at(input))
+    }
+
+    /// Constructs the line tangent to the graph of this function at `input`. The derivative is
+    /// approximated numerically, see [`crate::math::derivative`]. If the derivative is known in
+    /// closed form, use [`Self::tangent_with`] instead.
+    pub fn tangent(&self, input: f64) -> Line<'a> {
+        self.tangent_with(input, |t| math::derivative(&self.eval, t))
+    }
+
+    /// Constructs the line tangent to the graph of this function at `input`, where `derivative`
+    /// is the derivative of this function with respect to its input.
+    pub fn tangent_with(&self, input: f64, derivative: impl Fn(f64) -> f64) -> Line<'a> {
+        // The derivative is the rate of change of the output with respect to the input,
+        // so for a function of y, it is the reciprocal of the slope of the line.
+        let slope = match self.kind {
+            FunctionKind::OfX => derivative(input),
+            FunctionKind::OfY => 1.0 / derivative(input),
+        };
+        Line::new_point_slope(self.coordinate_at(input), slope)
+    }
+
+    /// Constructs the secant line passing through the points on the graph of this function whose
+    /// inputs are `input0` and `input1`. As `input1` approaches `input0`, the secant line 
+    /// approaches the tangent line at `input0`.
+    pub fn secant(&self, input0: f64, input1: f64) -> Line<'a> {
+        Line::new_infinite(self.coordinate_at(input0), self.coordinate_at(input1))
+    }
+}
+
+// # CurveLabel
+
+/// A TeX label placed directly beside the graph of a [`Function`], as an alternative to
+/// a [`crate::elements::Legend`].
+///
+/// The label is anchored to a point on the plotted path and displaced along the normal of
+/// the path at that point. Since the anchor is taken from the sampled path, and not from
+/// the function itself, the label is only ever placed beside a visible portion of the graph.
+#[derive(Clone, Copy)]
+pub struct CurveLabel<'a> {
+    pub tex: &'a str,
+    pub anchor: CurveLabelAnchor,
+
+    /// The distance between the anchor and the nearest edge of the label, measured along
+    /// the normal of the path in the normalized coordinate system. If the label does not fit
+    /// within the viewport on the preferred side of the path, it is placed on the opposite side.
+    pub offset: f64,
+    pub typography_height: f64,
+
+    /// The horizontal space reserved for the label. The label is centered within this space.
+    pub width: f64
+}
+
+impl<'a> CurveLabel<'a> {
+    pub const DEFAULT_OFFSET: f64 = 0.5 * crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_WIDTH: f64 = 6.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+
+    pub fn new_default(tex: &'a str, anchor: CurveLabelAnchor) -> Self {
+        Self {
+            tex,
+            anchor,
+            offset: Self::DEFAULT_OFFSET,
+            typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            width: Self::DEFAULT_WIDTH
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CurveLabelAnchor {
+    /// The label is anchored to the point of the graph whose input is the given value. This is 
+    /// an x-coordinate for [`FunctionKind::OfX`] and a y-coordinate for [`FunctionKind::OfY`].
+    /// If the graph is not visible at the given input, the label is omitted.
+    At(f64),
+
+    /// The label is anchored to the midpoint of the longest visible stretch of the graph.
+    Auto
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,340 @@
+use crate::elements::cplane::Extent;
+use crate::math::Vec2D;
+
+// # Geometry Marks
+//
+// The marks of elementary geometry figures. Since the horizontal and vertical scales of the
+// extent may differ, the marks are constructed in the normalized coordinate system, in which
+// the segments appear as they are drawn. For instance an angle mark spans the angle between the
+// segments as drawn, rather than the angle between them in the coordinate system of the plane.
+
+/// A stroke of a geometry mark in the normalized coordinate system.
+#[derive(Clone, PartialEq, Debug)]
+pub enum MarkStroke {
+    /// A circular arc of the given radius from `start` to `end`. The arc turns clockwise, as
+    /// drawn, if `clockwise` is true.
+    Arc { start: Vec2D, end: Vec2D, radius: f64, clockwise: bool },
+    Polyline(Vec<Vec2D>)
+}
+
+// # Angle Mark
+
+/// The mark of the angle between two segments sharing a vertex, drawn as one or more circular
+/// arcs about the vertex, or as a square if the angle is marked as a right angle.
+///
+/// The mark spans the smaller of the two angles between the rays from [`Self::vertex`] through
+/// [`Self::a`] and through [`Self::b`]. Marks whose vertex lies outside of the extent are not
+/// drawn.
+pub struct AngleMark<'a> {
+    pub vertex: Vec2D,
+
+    /// A point on the first ray of the angle, other than the vertex.
+    pub a: Vec2D,
+
+    /// A point on the second ray of the angle, other than the vertex.
+    pub b: Vec2D,
+
+    /// The radius of the innermost arc, or the side length of the square, in the normalized
+    /// coordinate system.
+    pub radius: f64,
+
+    /// The number of concentric arcs, which is conventionally used to denote congruent angles.
+    pub arcs: usize,
+
+    /// Whether the angle is marked by a square rather than by arcs.
+    pub right_angle: bool,
+
+    /// The TeX labeling the angle, which is placed on the bisector just beyond the mark.
+    pub label: Option<&'a str>,
+    pub typography_height: f64,
+
+    /// The horizontal space reserved for the label, which is centered within this space.
+    pub label_width: f64,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> AngleMark<'a> {
+    pub const DEFAULT_RADIUS: f64 = 1.0 / 25.0;
+    pub const DEFAULT_ARC_SPACING: f64 = 1.0 / 150.0;
+    pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_LABEL_WIDTH: f64 = 3.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+
+    /// Constructs the mark of the angle at `vertex` between the rays through `a` and `b`.
+    pub fn new_default(vertex: Vec2D, a: Vec2D, b: Vec2D) -> Self {
+        Self {
+            vertex,
+            a,
+            b,
+            radius: Self::DEFAULT_RADIUS,
+            arcs: 1,
+            right_angle: false,
+            label: None,
+            typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            label_width: Self::DEFAULT_LABEL_WIDTH,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Constructs the square mark of the right angle at `vertex` between the rays through
+    /// `a` and `b`.
+    pub fn new_right_angle(vertex: Vec2D, a: Vec2D, b: Vec2D) -> Self {
+        let mut mark = Self::new_default(vertex, a, b);
+        mark.right_angle = true;
+        mark.radius = 0.5 * Self::DEFAULT_RADIUS;
+        return mark;
+    }
+
+    /// Computes the normalized vertex followed by the unit directions of the two rays as
+    /// drawn, or `None` if either ray is degenerate.
+    pub fn rays(&self, extent: &Extent) -> Option<(Vec2D, Vec2D, Vec2D)> {
+        let transform = extent.transform();
+        let vertex = transform.apply(self.vertex);
+        let u = (transform.apply(self.a) - vertex).unit()?;
+        let v = (transform.apply(self.b) - vertex).unit()?;
+        return Some((vertex, u, v));
+    }
+
+    /// Computes the angle between the two rays as drawn, in radians.
+    pub fn apparent_angle(&self, extent: &Extent) -> Option<f64> {
+        let (_, u, v) = self.rays(extent)?;
+        Some(f64::atan2(u.cross(v).abs(), u.dot(v)))
+    }
+
+    /// Computes the strokes of the mark in the normalized coordinate system.
+    pub fn strokes(&self, extent: &Extent) -> Vec<MarkStroke> {
+        let Some((vertex, u, v)) = self.rays(extent) else { return Vec::new() };
+        if self.right_angle {
+            let side = self.radius;
+            return vec![MarkStroke::Polyline(vec![
+                vertex + (side * u),
+                vertex + (side * u) + (side * v),
+                vertex + (side * v)
+            ])];
+        }
+        // The arc turns from `u` towards `v` the short way around. Since the vertical axis of
+        // the normalized coordinate system points downward, a positive cross product means the
+        // arc turns clockwise as drawn.
+        let clockwise = u.cross(v) >= 0.0;
+        (0..self.arcs).map(|i| {
+            let radius = self.radius + ((i as f64) * Self::DEFAULT_ARC_SPACING);
+            MarkStroke::Arc {
+                start: vertex + (radius * u),
+                end: vertex + (radius * v),
+                radius,
+                clockwise
+            }
+        }).collect()
+    }
+
+    /// Computes the normalized center of the label on the bisector of the angle, or `None` if
+    /// the mark is unlabeled or degenerate.
+    pub fn label_center(&self, extent: &Extent) -> Option<Vec2D> {
+        self.label?;
+        let (vertex, u, v) = self.rays(extent)?;
+        // The bisector of a straight angle is perpendicular to both rays, on the side towards
+        // which the arc turns.
+        let bisector = (u + v).unit().unwrap_or(u.perp());
+        let outermost = (self.arcs.saturating_sub(1) as f64) * Self::DEFAULT_ARC_SPACING;
+        let extent_of_mark = match self.right_angle {
+            true => self.radius * std::f64::consts::SQRT_2,
+            false => self.radius + outermost,
+        };
+        let distance = extent_of_mark + (0.75 * self.typography_height);
+        return Some(vertex + (distance * bisector));
+    }
+}
+
+// # Congruence Mark
+
+/// The tick marks across the middle of a segment which denote congruent segments. Segments
+/// bearing the same number of ticks are congruent. Marks whose midpoint lies outside of the
+/// extent are not drawn.
+pub struct CongruenceMark<'a> {
+    pub a: Vec2D,
+    pub b: Vec2D,
+    pub ticks: usize,
+
+    /// The length of each tick in the normalized coordinate system.
+    pub len: f64,
+
+    /// The distance between adjacent ticks in the normalized coordinate system.
+    pub spacing: f64,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> CongruenceMark<'a> {
+    pub const DEFAULT_LEN: f64 = 1.0 / 50.0;
+    pub const DEFAULT_SPACING: f64 = 1.0 / 150.0;
+
+    /// Constructs the mark of the segment from `a` to `b` with the given number of ticks.
+    pub fn new_default(a: Vec2D, b: Vec2D, ticks: usize) -> Self {
+        Self {
+            a,
+            b,
+            ticks,
+            len: Self::DEFAULT_LEN,
+            spacing: Self::DEFAULT_SPACING,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Computes the ticks in the normalized coordinate system. Each tick is perpendicular to
+    /// the segment as drawn.
+    pub fn strokes(&self, extent: &Extent) -> Vec<MarkStroke> {
+        let transform = extent.transform();
+        let (a, b) = (transform.apply(self.a), transform.apply(self.b));
+        let Some(d) = (b - a).unit() else { return Vec::new() };
+        let normal = d.perp();
+        let midpoint = 0.5 * (a + b);
+        (0..self.ticks).map(|i| {
+            let center = midpoint + (centered_offset(i, self.ticks, self.spacing) * d);
+            MarkStroke::Polyline(vec![
+                center - ((0.5 * self.len) * normal),
+                center + ((0.5 * self.len) * normal)
+            ])
+        }).collect()
+    }
+}
+
+// # Parallel Mark
+
+/// The arrowheads in the middle of a segment which denote parallel lines. Segments bearing the
+/// same number of arrowheads are parallel. The arrowheads point from `a` towards `b`. Marks
+/// whose midpoint lies outside of the extent are not drawn.
+pub struct ParallelMark<'a> {
+    pub a: Vec2D,
+    pub b: Vec2D,
+    pub arrows: usize,
+
+    /// The length of each arrowhead along the segment in the normalized coordinate system.
+    /// The arrowheads are as wide as they are long.
+    pub size: f64,
+
+    /// The distance between the tips of adjacent arrowheads in the normalized coordinate system.
+    pub spacing: f64,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> ParallelMark<'a> {
+    pub const DEFAULT_SIZE: f64 = 1.0 / 80.0;
+    pub const DEFAULT_SPACING: f64 = 1.0 / 120.0;
+
+    /// Constructs the mark of the segment from `a` to `b` with the given number of arrowheads.
+    pub fn new_default(a: Vec2D, b: Vec2D, arrows: usize) -> Self {
+        Self {
+            a,
+            b,
+            arrows,
+            size: Self::DEFAULT_SIZE,
+            spacing: Self::DEFAULT_SPACING,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Computes the arrowheads in the normalized coordinate system. Each arrowhead is an open
+    /// chevron whose middle vertex is its tip.
+    pub fn strokes(&self, extent: &Extent) -> Vec<MarkStroke> {
+        let transform = extent.transform();
+        let (a, b) = (transform.apply(self.a), transform.apply(self.b));
+        let Some(d) = (b - a).unit() else { return Vec::new() };
+        let normal = d.perp();
+        let midpoint = 0.5 * (a + b);
+        (0..self.arrows).map(|i| {
+            // Center the group of arrowheads, including the length of the last, on the midpoint.
+            let along = centered_offset(i, self.arrows, self.spacing) + (0.5 * self.size);
+            let tip = midpoint + (along * d);
+            let back = tip - (self.size * d);
+            MarkStroke::Polyline(vec![
+                back + ((0.5 * self.size) * normal),
+                tip,
+                back - ((0.5 * self.size) * normal)
+            ])
+        }).collect()
+    }
+}
+
+/// Computes the offset of the `i`th of `count` evenly spaced marks centered on zero.
+fn centered_offset(i: usize, count: usize, spacing: f64) -> f64 {
+    ((i as f64) - (0.5 * ((count as f64) - 1.0))) * spacing
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct GeometryMarkDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_linejoin: bool,
+    pub apply_fill: bool,
+    pub apply_stroke: bool
+}
+
+impl GeometryMarkDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_linejoin:     true,
+        apply_fill:         true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_linejoin:     false,
+        apply_fill:         false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-geometry-mark";
+}
+
+pub const DEFAULT_GEOMETRY_MARK_STROKE_WIDTH: f64 =
+    crate::elements::line::DEFAULT_LINE_STROKE_WIDTH;
+pub const DEFAULT_GEOMETRY_MARK_LINEJOIN: &str = "miter";
+pub const DEFAULT_GEOMETRY_MARK_FILL: &str = "none";
+pub const DEFAULT_GEOMETRY_MARK_STROKE: &str = "black";
+
+pub(crate) fn write_geometry_mark_default_style_class(out: &mut impl std::io::Write,
+    class: &GeometryMarkDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &GeometryMarkDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", GeometryMarkDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_GEOMETRY_MARK_STROKE_WIDTH)?;
+    }
+    if class.apply_linejoin {
+        write!(out, "stroke-linejoin: {};", DEFAULT_GEOMETRY_MARK_LINEJOIN)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_GEOMETRY_MARK_FILL)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_GEOMETRY_MARK_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
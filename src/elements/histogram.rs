@@ -0,0 +1,190 @@
+use crate::elements::bar_chart::{Bar, clip_bar};
+use crate::math::{BoundingRect, NonDecreasing};
+
+// # Histogram
+
+/// The distribution of a set of raw samples, drawn as one bar per bin rising from zero.
+///
+/// Each bin includes its left edge and excludes its right edge, except for the last bin which
+/// includes both. Samples which are `NaN` or lie outside of every bin are not counted.
+pub struct Histogram<'a> {
+    pub bins: Vec<Bin<'a>>,
+
+    /// Whether the height of each bar is the density of the samples in its bin rather than the
+    /// number of samples in its bin. The density is normalized such that the total area of the
+    /// bars is one, so that the histogram may be compared against a probability density function.
+    pub density: bool,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of every `rect` element.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+/// The strategy by which a [`Histogram`] partitions the range of its samples into bins.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Binning {
+    /// Bins of equal width chosen by the Freedman–Diaconis rule, which is robust to outliers.
+    /// If the interquartile range of the samples is zero, the number of bins is chosen by
+    /// Sturges' rule instead. In either case there are at most [`Histogram::MAX_AUTO_BINS`]
+    /// bins, lest a few distant outliers stretch the range over countless empty bins.
+    Auto,
+
+    /// The given number of bins of equal width spanning the range of the samples.
+    Count(usize),
+
+    /// Bins of the given width whose edges are integer multiples of the width.
+    Width(f64),
+
+    /// Bins delimited by the given strictly increasing edges. Samples outside of the first
+    /// and last edges are not counted.
+    Edges(Vec<f64>)
+}
+
+/// A single bin of a [`Histogram`].
+#[derive(Clone, Copy, Debug)]
+pub struct Bin<'a> {
+    /// The left and right edges of the bin.
+    pub edges: NonDecreasing,
+
+    /// The number of samples in the bin.
+    pub count: usize,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `rect` element of this bin only, in addition to the classes of the
+    /// histogram. This allows for highlighting individual bins.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Histogram<'a> {
+    /// The greatest number of bins chosen by [`Binning::Auto`].
+    pub const MAX_AUTO_BINS: usize = 100;
+
+    /// Sorts the given samples into bins. Samples which are not finite are not counted.
+    ///
+    /// This procedure will panic if the binning is [`Binning::Count`] of zero bins,
+    /// [`Binning::Width`] of a width which is not positive and finite, or [`Binning::Edges`]
+    /// of fewer than two edges or edges which are not strictly increasing.
+    pub fn new_default(samples: &[f64], binning: Binning) -> Self {
+        let samples: Vec<f64> = samples.iter().copied().filter(|s| s.is_finite()).collect();
+        let edges = bin_edges(&samples, binning);
+        let mut bins: Vec<Bin<'a>> = edges.windows(2)
+            .map(|pair| Bin { edges: NonDecreasing::new(pair[0], pair[1]), count: 0,
+                style_class: None })
+            .collect();
+        if let (Some(first), Some(last)) = (edges.first(), edges.last()) {
+            for sample in samples {
+                if sample < *first || sample > *last { continue; }
+                // The last bin includes its right edge.
+                let i = usize::min(edges.partition_point(|edge| *edge <= sample), bins.len());
+                bins[i - 1].count += 1;
+            }
+        }
+        Self { bins, density: false, apply_default_style_class: true, style_class: None }
+    }
+
+    /// Sorts the given samples into bins and normalizes the heights of the bars to densities,
+    /// see [`Self::density`].
+    pub fn new_density(samples: &[f64], binning: Binning) -> Self {
+        Self { density: true, ..Self::new_default(samples, binning) }
+    }
+
+    /// Returns the total number of samples counted in the bins.
+    pub fn total(&self) -> usize { self.bins.iter().map(|bin| bin.count).sum() }
+
+    /// Returns the height of the bar of the given bin, which is either the number of samples in
+    /// the bin or the density of the samples in the bin, see [`Self::density`].
+    pub fn height(&self, bin: &Bin) -> f64 {
+        if !self.density { return bin.count as f64; }
+        let total = self.total();
+        if total == 0 { return 0.0; }
+        let width = bin.edges.end() - bin.edges.begin();
+        return (bin.count as f64) / ((total as f64) * width);
+    }
+
+    /// Returns the bar of each bin, in order.
+    pub fn bars(&self) -> Vec<Bar<'a>> {
+        self.bins.iter().map(|bin| Bar {
+            span: bin.edges,
+            value: self.height(bin),
+            label: None,
+            style_class: bin.style_class
+        }).collect()
+    }
+
+    /// Computes the rectangle covered by each bar within the given bounding rectangle, in the
+    /// coordinate system of the plane. Empty bins and bins which lie entirely outside of the
+    /// bounding rectangle are omitted.
+    pub fn rects(&self, brect: &BoundingRect) -> Vec<(Bar<'a>, BoundingRect)> {
+        self.bars().into_iter()
+            .filter_map(|bar| Some((bar, clip_bar(brect, bar.span, 0.0, bar.value)?)))
+            .collect()
+    }
+}
+
+/// Computes the edges of the bins of the given samples, all of which are finite. Returns no
+/// edges if there are no samples, unless the edges are given explicitly.
+fn bin_edges(samples: &[f64], binning: Binning) -> Vec<f64> {
+    if let Binning::Edges(edges) = binning {
+        assert!(edges.len() >= 2, "Cannot construct histogram with fewer than two bin edges.");
+        assert!(edges.windows(2).all(|pair| pair[0] < pair[1]), "Cannot construct histogram \
+            because the bin edges are not strictly increasing.");
+        return edges;
+    }
+    if samples.is_empty() { return Vec::new(); }
+    let min = samples.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    let count = match binning {
+        Binning::Edges(_) => unreachable!(),
+        Binning::Count(count) => {
+            assert!(count > 0, "Cannot construct histogram with zero bins.");
+            count
+        },
+        Binning::Width(width) => {
+            assert!(width > 0.0 && width.is_finite(), "Cannot construct histogram with bins of \
+                width {}.", width);
+            let mut first = (min / width).floor() as i64;
+            let mut last = i64::max((max / width).ceil() as i64, first + 1);
+            // Guard against rounding, which must not leave the extreme samples outside.
+            if (first as f64) * width > min { first -= 1; }
+            if (last as f64) * width < max { last += 1; }
+            return (first..=last).map(|k| (k as f64) * width).collect();
+        },
+        Binning::Auto => {
+            let n = samples.len() as f64;
+            let sturges = (n.log2().ceil() as usize) + 1;
+            let iqr = quantile(samples, 0.75) - quantile(samples, 0.25);
+            match iqr > 0.0 {
+                true => {
+                    let width = 2.0 * iqr / n.cbrt();
+                    let count = usize::max(((max - min) / width).ceil() as usize, 1);
+                    usize::min(count, Histogram::MAX_AUTO_BINS)
+                },
+                false => sturges,
+            }
+        },
+    };
+    // All of the samples are equal, so center a single bin of unit width on them.
+    if min == max { return vec![min - 0.5, min + 0.5]; }
+    let mut edges: Vec<f64> = (0..count)
+        .map(|i| min + ((max - min) * (i as f64) / (count as f64)))
+        .collect();
+    edges.push(max);
+    return edges;
+}
+
+/// Computes the `p`-quantile of the given samples, all of which are finite, by linear
+/// interpolation between the order statistics.
+fn quantile(samples: &[f64], p: f64) -> f64 {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let position = p * ((sorted.len() - 1) as f64);
+    let i = position.floor() as usize;
+    let j = usize::min(i + 1, sorted.len() - 1);
+    return sorted[i] + ((position - (i as f64)) * (sorted[j] - sorted[i]));
+}
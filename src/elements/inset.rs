@@ -0,0 +1,242 @@
+use crate::elements::cplane::{CoordinatePlane, Extent};
+use crate::math::{BoundingRect, ClosedInterval, NonDecreasing, Vec2D};
+
+// # Inset
+
+/// A magnified view of a small region of the enclosing [`CoordinatePlane`], such as the
+/// oscillations of `sin(1/x)` near zero, drawn inside the enclosing plane at the given position.
+///
+/// The inset is a plane in its own right, whose elements are plotted over the magnified region
+/// rather than scaled up from the enclosing plane. In particular the functions of the enclosing
+/// plane are sampled anew over the region, so that detail too fine to be resolved in the
+/// enclosing plane is resolved in the inset. The region is outlined in the enclosing plane and
+/// joined to the inset by connector lines.
+pub struct Inset<'a> {
+    /// The magnified plane. The bounding rectangle of its extent is the magnified region of the
+    /// enclosing plane. The scales of the extent are ignored, since the region is magnified
+    /// uniformly in the normalized coordinate system of the enclosing plane.
+    pub cplane: CoordinatePlane<'a>,
+
+    /// The top left corner of the inset in the coordinates of the enclosing plane.
+    pub position: Vec2D,
+
+    /// The ratio of the size of the inset to the size of the magnified region.
+    pub magnification: f64,
+
+    /// Whether the functions in [`CoordinatePlane::fns`] of the enclosing plane are plotted
+    /// within the inset. These are drawn beneath the elements of the inset plane.
+    pub replot_fns: bool,
+
+    /// Whether the magnified region is outlined in the enclosing plane and joined to the inset
+    /// by connector lines, see [`Inset::connectors`].
+    pub highlight: bool,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the frame of the inset, the outline of the magnified region and the
+    /// connector lines.
+    ///
+    /// Note that these classes are in addition to the default style class names, unless
+    /// of course the default style class names have been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Inset<'a> {
+    /// Constructs an inset magnifying `region` of the enclosing plane by `magnification`, whose
+    /// top left corner is placed at `position`. The inset plane has no axes.
+    pub fn new_default(region: BoundingRect, position: Vec2D, magnification: f64) -> Self {
+        let mut cplane = CoordinatePlane::new_minimal();
+        cplane.extent.brect = region;
+        Self {
+            cplane,
+            position,
+            magnification,
+            replot_fns: true,
+            highlight: true,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// The magnified region of the enclosing plane.
+    pub fn region(&self) -> BoundingRect { self.cplane.extent.brect }
+
+    /// Computes the rectangle occupied by the inset in the coordinates of the enclosing plane.
+    pub fn rect(&self) -> BoundingRect {
+        let region = self.region();
+        let width = self.magnification * region.x.len();
+        let height = self.magnification * region.y.len();
+        BoundingRect {
+            x: ClosedInterval::new(NonDecreasing::new(self.position.x, self.position.x + width)),
+            y: ClosedInterval::new(NonDecreasing::new(self.position.y - height, self.position.y))
+        }
+    }
+
+    /// Computes the extent over which the inset plane is drawn, given the extent of the
+    /// enclosing plane. The scales are chosen so that the normalized coordinate system of the
+    /// inset has the same unit length as that of the enclosing plane. Hence the inset occupies
+    /// exactly [`Inset::rect`], and strokes are as wide within the inset as they are outside.
+    pub fn extent(&self, enclosing: &Extent) -> Extent {
+        let region = self.region();
+        let enclosing_dimension = f64::max(enclosing.brect.x.len(), enclosing.brect.y.len());
+        let dimension = f64::max(region.x.len(), region.y.len());
+        let factor = self.magnification * dimension / enclosing_dimension;
+        Extent {
+            brect: region,
+            x_scale: factor * enclosing.x_scale,
+            y_scale: factor * enclosing.y_scale
+        }
+    }
+
+    /// Computes the connector lines joining the corners of the magnified region to the corners
+    /// of the inset, in the coordinates of the enclosing plane. The connectors are the two edges
+    /// of the convex hull of both rectangles which join one to the other, and therefore cross
+    /// neither. There are no connectors if the rectangles overlap.
+    pub fn connectors(&self) -> Vec<(Vec2D, Vec2D)> {
+        let region = self.region();
+        let rect = self.rect();
+        let overlaps = region.x.intersection(rect.x).is_some()
+            && region.y.intersection(rect.y).is_some();
+        if overlaps { return Vec::new(); }
+        let mut corners: Vec<(Vec2D, bool)> = Vec::new();
+        for (brect, is_inset) in [(region, false), (rect, true)] {
+            for x in [brect.x.begin(), brect.x.end()] {
+                for y in [brect.y.begin(), brect.y.end()] {
+                    corners.push((Vec2D { x, y }, is_inset));
+                }
+            }
+        }
+        let hull = convex_hull(corners);
+        let mut connectors: Vec<(Vec2D, Vec2D)> = Vec::new();
+        for i in 0..hull.len() {
+            let (a, a_is_inset) = hull[i];
+            let (b, b_is_inset) = hull[(i + 1) % hull.len()];
+            match (a_is_inset, b_is_inset) {
+                (false, true) => connectors.push((a, b)),
+                (true, false) => connectors.push((b, a)),
+                _ => continue,
+            }
+        }
+        return connectors;
+    }
+}
+
+/// Computes the vertices of the convex hull of the given points in counterclockwise order,
+/// excluding those which lie along an edge of the hull. Each point carries a tag identifying
+/// the rectangle to which it belongs.
+fn convex_hull(mut points: Vec<(Vec2D, bool)>) -> Vec<(Vec2D, bool)> {
+    points.sort_by(|(a, _), (b, _)| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    let turns_left = |hull: &Vec<(Vec2D, bool)>, p: Vec2D| {
+        let (a, b) = (hull[hull.len() - 2].0, hull[hull.len() - 1].0);
+        (b - a).cross(p - b) > 0.0
+    };
+    // Andrew's monotone chain, constructing the lower and then the upper half of the hull.
+    let mut hull: Vec<(Vec2D, bool)> = Vec::new();
+    let reversed: Vec<(Vec2D, bool)> = points.iter().rev().copied().collect();
+    for half in [points, reversed] {
+        let begin = hull.len();
+        for point in half {
+            while hull.len() >= begin + 2 && !turns_left(&hull, point.0) { hull.pop(); }
+            hull.push(point);
+        }
+        // The last point of each half is the first point of the other.
+        hull.pop();
+    }
+    return hull;
+}
+
+/// The style class of the outline of the magnified region and of the connector lines.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct InsetDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_fill: bool,
+    pub apply_stroke: bool
+}
+
+impl InsetDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_fill:         true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_fill:         false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-inset";
+}
+
+pub const DEFAULT_INSET_STROKE_WIDTH: f64 = crate::elements::axis::DEFAULT_AXIS_STROKE_WIDTH;
+pub const DEFAULT_INSET_FILL: &str = "none";
+pub const DEFAULT_INSET_STROKE: &str = "gray";
+
+pub(crate) fn write_inset_default_style_class(out: &mut impl std::io::Write,
+    class: &InsetDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &InsetDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", InsetDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_INSET_STROKE_WIDTH)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_INSET_FILL)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_INSET_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
+/// The style class of the frame of an [`Inset`]. The frame is filled so as to hide the elements
+/// of the enclosing plane behind the inset.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct InsetFrameDefaultStyleClass {
+    pub apply_fill: bool,
+    pub apply_stroke_width: bool,
+    pub apply_stroke: bool
+}
+
+impl InsetFrameDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_fill:         true,
+        apply_stroke_width: true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_fill:         false,
+        apply_stroke_width: false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-inset-frame";
+}
+
+pub const DEFAULT_INSET_FRAME_FILL: &str = "white";
+pub const DEFAULT_INSET_FRAME_STROKE_WIDTH: f64 = crate::elements::axis::DEFAULT_AXIS_STROKE_WIDTH;
+pub const DEFAULT_INSET_FRAME_STROKE: &str = "black";
+
+pub(crate) fn write_inset_frame_default_style_class(out: &mut impl std::io::Write,
+    class: &InsetFrameDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &InsetFrameDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", InsetFrameDefaultStyleClass::NAME)?;
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_INSET_FRAME_FILL)?;
+    }
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_INSET_FRAME_STROKE_WIDTH)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_INSET_FRAME_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
@@ -0,0 +1,200 @@
+use crate::elements::function::Function;
+use crate::elements::line::Line;
+use crate::math::Vec2D;
+
+// # Legend
+
+pub struct Legend<'a> {
+    pub entries: Vec<LegendEntry<'a>>,
+    pub position: LegendPosition,
+
+    /// The height of the TeX label of each entry. Each row of the legend is slightly taller
+    /// than this, see [`Legend::ROW_HEIGHT_FACTOR`].
+    pub typography_height: f64,
+
+    /// The horizontal space reserved for the TeX label of each entry. The labels are
+    /// left-aligned within this space.
+    pub label_width: f64,
+
+    /// The length of the line drawn to the left of each label which shows the style of the
+    /// described element.
+    pub swatch_len: f64,
+
+    /// The space between the frame of the legend and its contents, and the space between
+    /// the swatch and the label of each entry. If the legend is positioned at a corner, this
+    /// is also the space between the frame of the legend and the edges of the extent.
+    pub padding: f64,
+    pub apply_default_style_class: bool,
+
+    /// List of names of custom CSS styles classes to include in the `class` attibute
+    /// of the `rect` element framing the legend.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Legend<'a> {
+    pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_LABEL_WIDTH: f64 = 8.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_SWATCH_LEN: f64 = 2.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_PADDING: f64 = 0.5 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const ROW_HEIGHT_FACTOR: f64 = 1.5;
+
+    pub fn new_default(position: LegendPosition) -> Self {
+        Self {
+            entries: Vec::new(),
+            position,
+            typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            label_width: Self::DEFAULT_LABEL_WIDTH,
+            swatch_len: Self::DEFAULT_SWATCH_LEN,
+            padding: Self::DEFAULT_PADDING,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Appends an entry describing the given function to the end of the legend.
+    pub fn push_function(&mut self, function: &Function<'a>, label_tex: &'a str) {
+        self.entries.push(LegendEntry::for_function(function, label_tex));
+    }
+
+    /// Appends an entry describing the given line to the end of the legend.
+    pub fn push_line(&mut self, line: &Line<'a>, label_tex: &'a str) {
+        self.entries.push(LegendEntry { label_tex, swatch: Swatch::of_line(line) });
+    }
+
+    pub fn row_height(&self) -> f64 { Self::ROW_HEIGHT_FACTOR * self.typography_height }
+
+    /// Computes the width of the frame of the legend in the normalized coordinate system.
+    pub fn width(&self) -> f64 {
+        (3.0 * self.padding) + self.swatch_len + self.label_width
+    }
+
+    /// Computes the height of the frame of the legend in the normalized coordinate system.
+    pub fn height(&self) -> f64 {
+        (2.0 * self.padding) + (self.entries.len() as f64 * self.row_height())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LegendPosition {
+    /// The legend is placed inside the given corner of the extent, inset by the padding.
+    Corner(Corner),
+
+    /// The top-left corner of the legend's frame is placed at the given coordinate of the plane.
+    At(Vec2D)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Corner { TopLeft, TopRight, BottomLeft, BottomRight }
+
+// # LegendEntry
+
+#[derive(Clone, Copy)]
+pub struct LegendEntry<'a> {
+    pub label_tex: &'a str,
+    pub swatch: Swatch<'a>
+}
+
+impl<'a> LegendEntry<'a> {
+    pub fn for_function(function: &Function<'a>, label_tex: &'a str) -> Self {
+        Self { label_tex, swatch: Swatch::of_function(function) }
+    }
+}
+
+/// A small sample of the described element drawn beside the label of a [`LegendEntry`].
+///
+/// The swatch is assigned the same CSS classes as the described element, therefore any custom
+/// styling of the element carries over to the legend automatically.
+#[derive(Clone, Copy)]
+pub struct Swatch<'a> {
+    pub kind: SwatchKind,
+
+    /// Whether the default style class associated with `kind` is included in the `class`
+    /// attribute of the swatch. This should mirror the `apply_default_style_class` flag of the
+    /// described element.
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS style classes to include in the `class`
+    /// attribute of the swatch. This should mirror the `style_class` of the described element.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Swatch<'a> {
+    pub fn of_function(function: &Function<'a>) -> Self {
+        Self {
+            kind: SwatchKind::Function,
+            apply_default_style_class: function.apply_default_style_class,
+            style_class: function.style_class
+        }
+    }
+
+    pub fn of_line(line: &Line<'a>) -> Self {
+        Self {
+            kind: SwatchKind::Line,
+            apply_default_style_class: line.apply_default_style_class,
+            style_class: line.style_class
+        }
+    }
+}
+
+/// The kind of element described by a [`LegendEntry`]. This determines the shape of the swatch
+/// and the default style class applied to it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwatchKind {
+    /// A [`Function`] plot, drawn as a short line segment with the
+    /// [`crate::elements::function::FunctionDefaultStyleClass`].
+    Function,
+
+    /// A [`Line`], drawn as a short line segment with the
+    /// [`crate::elements::line::LineDefaultStyleClass`].
+    Line
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LegendDefaultStyleClass {
+    pub apply_fill: bool,
+    pub apply_stroke_width: bool,
+    pub apply_stroke: bool
+}
+
+impl LegendDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_fill:         true,
+        apply_stroke_width: true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_fill:         false,
+        apply_stroke_width: false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-legend";
+}
+
+pub const DEFAULT_LEGEND_FILL: &str = "white";
+pub const DEFAULT_LEGEND_STROKE_WIDTH: f64 = crate::elements::axis::DEFAULT_AXIS_STROKE_WIDTH;
+pub const DEFAULT_LEGEND_STROKE: &str = "black";
+
+pub(crate) fn write_legend_default_style_class(out: &mut impl std::io::Write,
+    class: &LegendDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &LegendDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", LegendDefaultStyleClass::NAME)?;
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_LEGEND_FILL)?;
+    }
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_LEGEND_STROKE_WIDTH)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_LEGEND_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
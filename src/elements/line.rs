@@ -0,0 +1,105 @@
+use crate::math::Vec2D;
+
+// # Line
+
+/// A straight line through two points of the plane. Depending on the [`LineExtension`] the line
+/// is either drawn between the two points or extended to the edges of the extent.
+#[derive(Clone, Copy)]
+pub struct Line<'a> {
+    pub a: Vec2D,
+    pub b: Vec2D,
+    pub extension: LineExtension,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `line` element.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Line<'a> {
+    /// Constructs a new [`Line`] which is drawn between `a` and `b`.
+    pub fn new_segment(a: Vec2D, b: Vec2D) -> Self {
+        Self {
+            a, b,
+            extension: LineExtension::Segment,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Constructs a new [`Line`] passing through `a` and `b` which is extended to the edges
+    /// of the extent.
+    pub fn new_infinite(a: Vec2D, b: Vec2D) -> Self {
+        Self { extension: LineExtension::Infinite, ..Self::new_segment(a, b) }
+    }
+
+    /// Constructs a new infinite [`Line`] passing through `point` with the given `slope`.
+    /// If `slope` is infinite, the line is vertical.
+    pub fn new_point_slope(point: Vec2D, slope: f64) -> Self {
+        let direction = match slope.is_infinite() {
+            true => Vec2D { x: 0.0, y: 1.0 },
+            false => Vec2D { x: 1.0, y: slope },
+        };
+        let other = Vec2D { x: point.x + direction.x, y: point.y + direction.y };
+        Self::new_infinite(point, other)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LineExtension { 
+    /// The line is drawn between its two points only.
+    Segment,
+
+    /// The line is extended in both directions until it meets the edges of the extent.
+    Infinite
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LineDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_linecap: bool,
+    pub apply_stroke: bool
+}
+
+impl LineDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_linecap:      true,
+        apply_stroke:       true,
+    };
+    
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_linecap:      false,
+        apply_stroke:       false,
+    };
+
+    pub const NAME: &'static str = "yapl-def-line";
+}
+
+pub const DEFAULT_LINE_STROKE_WIDTH: f64 = 1.0 / 600.0;
+pub const DEFAULT_LINE_LINECAP: &str = "round";
+pub const DEFAULT_LINE_STROKE: &str = "black";
+
+pub(crate) fn write_line_default_style_class(out: &mut impl std::io::Write, 
+    class: &LineDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &LineDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", LineDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_LINE_STROKE_WIDTH)?;
+    }
+    if class.apply_linecap {
+        write!(out, "stroke-linecap: {};", DEFAULT_LINE_LINECAP)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_LINE_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
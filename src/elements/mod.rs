@@ -1,12 +1,86 @@
 pub mod cplane;
 pub mod function;
 pub mod axis;
+pub mod legend;
+pub mod line;
+pub mod point;
+pub mod piecewise;
+pub mod region;
+pub mod series;
+pub mod band;
+pub mod histogram;
+pub mod bar_chart;
+pub mod stems;
+pub mod annotation;
+pub mod sequence;
+pub mod epsilon_delta;
+pub mod geometry;
+pub mod domain_coloring;
+pub mod contour;
+pub mod number_line;
+pub mod figure;
+pub mod inset;
 
 pub use cplane::CoordinatePlane;
 pub use cplane::Extent;
 pub use axis::Axis;
+pub use axis::AxisPosition;
+pub use axis::TickDirection;
+pub use axis::AxisTitle;
+pub use axis::Arrowhead;
+pub use axis::ArrowheadEnds;
 pub use axis::SymbolicTickLabel;
 pub use axis::TickLabelKind;
 pub use axis::TickLabel;
 pub use function::FunctionKind;
 pub use function::Function;
+pub use function::CurveLabel;
+pub use function::CurveLabelAnchor;
+pub use legend::Legend;
+pub use legend::LegendEntry;
+pub use legend::LegendPosition;
+pub use legend::Corner;
+pub use line::Line;
+pub use line::LineExtension;
+pub use point::Point;
+pub use point::PointFill;
+pub use point::PointLabel;
+pub use point::LabelPlacement;
+pub use piecewise::Piecewise;
+pub use piecewise::Piece;
+pub use region::Region;
+pub use region::RegionKind;
+pub use region::Inequality;
+pub use series::Series;
+pub use series::SeriesMode;
+pub use series::Marker;
+pub use series::MarkerShape;
+pub use series::ErrorBars;
+pub use series::Uncertainty;
+pub use band::Band;
+pub use histogram::Histogram;
+pub use histogram::Binning;
+pub use histogram::Bin;
+pub use bar_chart::BarChart;
+pub use bar_chart::Bar;
+pub use stems::Stems;
+pub use stems::Stem;
+pub use annotation::Annotation;
+pub use sequence::Sequence;
+pub use sequence::Cobweb;
+pub use epsilon_delta::EpsilonDelta;
+pub use geometry::AngleMark;
+pub use geometry::CongruenceMark;
+pub use geometry::ParallelMark;
+pub use geometry::MarkStroke;
+pub use domain_coloring::DomainColoring;
+pub use contour::Contour;
+pub use contour::ContourLevels;
+pub use contour::ContourTrace;
+pub use number_line::NumberLine;
+pub use number_line::NumberLineInterval;
+pub use figure::Figure;
+pub use figure::Panel;
+pub use figure::PanelTitle;
+pub use figure::SharedAxes;
+pub use inset::Inset;
@@ -0,0 +1,213 @@
+use crate::elements::axis::{Arrowhead, ArrowheadEnds, Axis};
+use crate::elements::cplane::Extent;
+use crate::elements::point::{Point, PointFill};
+use crate::math::{BoundingRect, ClosedInterval, Endpoint, NonDecreasing};
+use crate::math::{Interval, IntervalSet, OpenInterval, Vec2D};
+
+// # Number Line
+
+/// A one-dimensional figure consisting of a single horizontal axis, on which sets of real
+/// numbers are drawn in interval notation. This is a simplified
+/// [`crate::elements::CoordinatePlane`], and is compiled by
+/// [`crate::codegen::codegen_number_line`].
+///
+/// Each interval is drawn as a thick segment along the axis. Its finite endpoints are marked by
+/// dots, which are filled if the endpoint belongs to the interval and hollow otherwise, and its
+/// ends which continue beyond the visible range, such as the ends of unbounded intervals, are
+/// capped by arrowheads.
+pub struct NumberLine<'a> {
+    /// The visible range of the line.
+    pub range: ClosedInterval,
+    pub axis: Axis<'a>,
+    pub intervals: Vec<NumberLineInterval<'a>>,
+
+    /// The height of the figure in the normalized coordinate system, in which the range has
+    /// length one. The axis runs through the middle of the figure.
+    pub height: f64
+}
+
+impl<'a> NumberLine<'a> {
+    pub const DEFAULT_HEIGHT: f64 = 1.0 / 10.0;
+
+    /// Constructs a number line spanning `range`, with a tick at each integer.
+    pub fn new_default(range: ClosedInterval) -> Self {
+        let mut axis = Axis::new_default(0.0, 1.0, 0.0);
+        axis.arrowhead = Some(Arrowhead::new_default(ArrowheadEnds::Both));
+        Self { range, axis, intervals: Vec::new(), height: Self::DEFAULT_HEIGHT }
+    }
+
+    /// Draws each of the disjoint intervals comprising `set`, for instance the solution set of
+    /// an inequality.
+    pub fn push_set(&mut self, set: &IntervalSet) {
+        self.intervals.extend(set.intervals().iter().map(|i| NumberLineInterval::from(*i)));
+    }
+
+    /// Computes the extent of the figure. The horizontal dimension is the range of the line,
+    /// and the vertical dimension is chosen, centered on zero, so that the figure has the
+    /// desired height.
+    pub fn extent(&self) -> Extent {
+        let half_height = 0.5 * self.height * self.range.len();
+        Extent {
+            brect: BoundingRect {
+                x: self.range,
+                y: ClosedInterval::new(NonDecreasing::new(-half_height, half_height))
+            },
+            x_scale: 1.0,
+            y_scale: 1.0
+        }
+    }
+}
+
+/// An interval drawn on a [`NumberLine`].
+#[derive(Clone, Copy)]
+pub struct NumberLineInterval<'a> {
+    pub interval: Interval,
+
+    /// The radius of the dots marking the finite endpoints in the normalized coordinate system.
+    pub endpoint_radius: f64,
+
+    /// The length of the arrowheads capping the ends which continue beyond the visible range,
+    /// in the normalized coordinate system. The arrowheads are as wide as they are long.
+    pub arrowhead_len: f64,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of every element of the interval, that is the segment, the dots and the
+    /// arrowheads.
+    ///
+    /// Note that these classes are in addition to the default style class names, unless
+    /// of course the default style class names have been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> NumberLineInterval<'a> {
+    pub const DEFAULT_ENDPOINT_RADIUS: f64 = 1.0 / 110.0;
+    pub const DEFAULT_ARROWHEAD_LEN: f64 = 1.0 / 45.0;
+
+    pub fn new_default(interval: Interval) -> Self {
+        Self {
+            interval,
+            endpoint_radius: Self::DEFAULT_ENDPOINT_RADIUS,
+            arrowhead_len: Self::DEFAULT_ARROWHEAD_LEN,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    pub fn is_empty(&self) -> bool { self.interval.is_empty() }
+
+    /// Computes the visible segment of this interval within `range`, or `None` if the interval
+    /// is empty or lies outside of the range.
+    pub fn segment(&self, range: ClosedInterval) -> Option<(f64, f64)> {
+        if self.is_empty() { return None; }
+        let visible = range.intersection(self.interval)?;
+        return Some((visible.begin(), visible.end()));
+    }
+
+    /// Determines which ends of the visible segment continue beyond `range` and are therefore
+    /// capped by arrowheads, or `None` if neither end is.
+    pub fn arrowhead_ends(&self, range: ClosedInterval) -> Option<ArrowheadEnds> {
+        let negative = self.interval.begin() < range.begin();
+        let positive = self.interval.end() > range.end();
+        match (negative, positive) {
+            (true, true) => Some(ArrowheadEnds::Both),
+            (true, false) => Some(ArrowheadEnds::Negative),
+            (false, true) => Some(ArrowheadEnds::Positive),
+            (false, false) => None,
+        }
+    }
+
+    /// Constructs the arrowhead capping the ends which continue beyond `range`.
+    pub fn arrowhead(&self, range: ClosedInterval) -> Option<Arrowhead<'a>> {
+        let ends = self.arrowhead_ends(range)?;
+        let mut arrowhead = Arrowhead::new_default(ends);
+        arrowhead.len = self.arrowhead_len;
+        arrowhead.width = self.arrowhead_len;
+        arrowhead.apply_default_style_class = self.apply_default_style_class;
+        arrowhead.style_class = self.style_class;
+        return Some(arrowhead);
+    }
+
+    /// Constructs the dots marking the endpoints which lie within `range`.
+    pub fn endpoints(&self, range: ClosedInterval) -> Vec<Point<'a>> {
+        if self.is_empty() { return Vec::new(); }
+        let mut dots: Vec<Point<'a>> = Vec::new();
+        let interval = &self.interval;
+        for (x, endpoint) in [(interval.begin(), interval.begin_endpoint()),
+            (interval.end(), interval.end_endpoint())]
+        {
+            if !range.includes(x) { continue; }
+            // A degenerate closed interval is a single point, marked by a single dot.
+            if !dots.is_empty() && dots[0].pos.x == x { continue; }
+            let mut dot = Point::new_default(Vec2D { x, y: 0.0 });
+            dot.radius = self.endpoint_radius;
+            dot.fill = match endpoint {
+                Endpoint::Closed => PointFill::Closed,
+                Endpoint::Open => PointFill::Open,
+            };
+            dot.apply_default_style_class = self.apply_default_style_class;
+            dot.style_class = self.style_class;
+            dots.push(dot);
+        }
+        return dots;
+    }
+}
+
+impl<'a> From<Interval> for NumberLineInterval<'a> {
+    fn from(value: Interval) -> Self { Self::new_default(value) }
+}
+
+impl<'a> From<ClosedInterval> for NumberLineInterval<'a> {
+    fn from(value: ClosedInterval) -> Self { Self::new_default(value.into()) }
+}
+
+impl<'a> From<OpenInterval> for NumberLineInterval<'a> {
+    fn from(value: OpenInterval) -> Self { Self::new_default(value.into()) }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NumberLineIntervalDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_linecap: bool,
+    pub apply_stroke: bool
+}
+
+impl NumberLineIntervalDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_linecap:      true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_linecap:      false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-number-line-interval";
+}
+
+pub const DEFAULT_NUMBER_LINE_INTERVAL_STROKE_WIDTH: f64 = 1.0 / 250.0;
+pub const DEFAULT_NUMBER_LINE_INTERVAL_LINECAP: &str = "butt";
+pub const DEFAULT_NUMBER_LINE_INTERVAL_STROKE: &str = "black";
+
+pub(crate) fn write_number_line_interval_default_style_class(out: &mut impl std::io::Write,
+    class: &NumberLineIntervalDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &NumberLineIntervalDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", NumberLineIntervalDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_NUMBER_LINE_INTERVAL_STROKE_WIDTH)?;
+    }
+    if class.apply_linecap {
+        write!(out, "stroke-linecap: {};", DEFAULT_NUMBER_LINE_INTERVAL_LINECAP)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_NUMBER_LINE_INTERVAL_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
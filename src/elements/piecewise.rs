@@ -0,0 +1,105 @@
+use crate::elements::function::{Function, FunctionKind};
+use crate::elements::point::{Point, PointFill};
+use crate::math::{Endpoint, Interval, Vec2D};
+
+// # Piecewise
+
+/// A function which is defined by a different formula on each of several intervals.
+///
+/// Each piece is plotted as an ordinary [`Function`] restricted to its interval. Every finite
+/// endpoint of a piece is marked with a dot, which is filled if the endpoint belongs to the
+/// piece and hollow otherwise. The dots are drawn above the axes, together with the points
+/// of the [`crate::elements::CoordinatePlane`].
+pub struct Piecewise<'a> {
+    pub pieces: Vec<Piece<'a>>,
+
+    /// The radius of the endpoint dots in the normalized coordinate system.
+    pub endpoint_radius: f64
+}
+
+impl<'a> Piecewise<'a> {
+    pub const DEFAULT_ENDPOINT_RADIUS: f64 = Point::DEFAULT_RADIUS;
+
+    pub fn new_default() -> Self {
+        Self { pieces: Vec::new(), endpoint_radius: Self::DEFAULT_ENDPOINT_RADIUS }
+    }
+
+    /// Appends a piece which evaluates `f` on the interval `domain`.
+    pub fn push<F>(&mut self, domain: Interval, f: F)
+    where F: Fn(f64) -> f64 + 'static
+    {
+        self.pieces.push(Piece { function: Function::new_restricted(domain, f) });
+    }
+
+    /// Computes the dots marking the finite endpoints of the pieces.
+    ///
+    /// The dot of an open endpoint is placed at the limit of the piece as its input approaches the
+    /// endpoint. If the piece is undefined at the endpoint, the limit is approximated by evaluating
+    /// the piece slightly inside of its interval, see [`ENDPOINT_NUDGE_FACTOR`]. Endpoints at which
+    /// the limit is not finite are not marked.
+    ///
+    /// Where an open endpoint of one piece coincides with a closed endpoint of another, as is
+    /// the case for a continuous joint, only the closed dot is kept.
+    pub fn endpoints(&self) -> Vec<Point<'a>> {
+        let mut dots: Vec<Point<'a>> = Vec::new();
+        for piece in &self.pieces {
+            let domain = piece.function.domain.unwrap_or(Interval::unbounded());
+            for (input, endpoint, inward) in [(domain.begin(), domain.begin_endpoint(), 1.0),
+                (domain.end(), domain.end_endpoint(), -1.0)]
+            {
+                if input.is_infinite() { continue; }
+                let Some(pos) = piece.limit_at(input, inward) else { continue };
+                let mut dot = Point::new_default(pos);
+                dot.radius = self.endpoint_radius;
+                dot.style_class = piece.function.style_class;
+                dot.fill = match endpoint {
+                    Endpoint::Closed => PointFill::Closed,
+                    Endpoint::Open => PointFill::Open,
+                };
+                dots.push(dot);
+            }
+        }
+        let closed: Vec<Vec2D> = dots.iter()
+            .filter(|dot| dot.fill == PointFill::Closed)
+            .map(|dot| dot.pos)
+            .collect();
+        dots.retain(|dot| dot.fill == PointFill::Closed
+            || !closed.iter().any(|pos| coincide(*pos, dot.pos)));
+        return dots;
+    }
+}
+
+/// A single formula of a [`Piecewise`] function. The interval on which the formula applies,
+/// including whether each of its endpoints belongs to the piece, is the [`Function::domain`] of
+/// `function`.
+pub struct Piece<'a> {
+    pub function: Function<'a>
+}
+
+impl<'a> Piece<'a> {
+    /// Computes the coordinate of the graph of this piece as its input approaches `input` from
+    /// the direction of `inward`, which is `1.0` from above and `-1.0` from below.
+    fn limit_at(&self, input: f64, inward: f64) -> Option<Vec2D> {
+        let mut output = (self.function.eval)(input);
+        if !output.is_finite() {
+            let nudge = ENDPOINT_NUDGE_FACTOR * f64::max(input.abs(), 1.0);
+            output = (self.function.eval)(input + (inward * nudge));
+        }
+        if !output.is_finite() { return None; }
+        let pos = match self.function.kind {
+            FunctionKind::OfX => Vec2D { x: input, y: output },
+            FunctionKind::OfY => Vec2D { x: output, y: input },
+        };
+        return Some(pos);
+    }
+}
+
+/// The distance inside of its interval at which a piece is evaluated when it is undefined at
+/// an endpoint, relative to the magnitude of the endpoint.
+pub const ENDPOINT_NUDGE_FACTOR: f64 = 1e-9;
+
+/// Determines whether two endpoint dots are at the same position, up to rounding error.
+fn coincide(a: Vec2D, b: Vec2D) -> bool {
+    let close = |u: f64, v: f64| (u - v).abs() <= ENDPOINT_NUDGE_FACTOR * f64::max(u.abs(), 1.0);
+    close(a.x, b.x) && close(a.y, b.y)
+}
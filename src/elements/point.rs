@@ -0,0 +1,185 @@
+use crate::math::Vec2D;
+
+// # Point
+
+/// A dot marking a point of interest on the plane, optionally accompanied by a TeX label.
+/// 
+/// Points lying outside of the extent are not drawn.
+#[derive(Clone, Copy)]
+pub struct Point<'a> {
+    pub pos: Vec2D,
+    pub fill: PointFill,
+
+    /// The radius of the dot in the normalized coordinate system.
+    pub radius: f64,
+    pub label: Option<PointLabel<'a>>,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `circle` element.
+    ///
+    /// Note that these classes are in addition to the default style class names, unless
+    /// of course the default style class names have been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Point<'a> {
+    pub const DEFAULT_RADIUS: f64 = 1.0 / 160.0;
+
+    pub fn new_default(pos: Vec2D) -> Self {
+        Self {
+            pos,
+            fill: PointFill::Closed,
+            radius: Self::DEFAULT_RADIUS,
+            label: None,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Constructs a new [`Point`] with a TeX label placed beside the dot.
+    pub fn new_labeled(pos: Vec2D, tex: &'a str, placement: LabelPlacement) -> Self {
+        Self { label: Some(PointLabel::new_default(tex, placement)), ..Self::new_default(pos) }
+    }
+}
+
+/// Whether the dot is filled, as is the convention for a point which belongs to a set, or hollow,
+/// as is the convention for a point which is excluded from a set.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PointFill { Closed, Open }
+
+#[derive(Clone, Copy)]
+pub struct PointLabel<'a> {
+    pub tex: &'a str,
+    pub placement: LabelPlacement,
+    pub typography_height: f64,
+
+    /// The horizontal space reserved for the label. The label is aligned within this space
+    /// towards the point.
+    pub width: f64
+}
+
+impl<'a> PointLabel<'a> {
+    pub const DEFAULT_TYPOGRAPHY_HEIGHT: f64 = crate::typography::DEFAULT_TYPOGRAPHY_HEIGHT;
+    pub const DEFAULT_WIDTH: f64 = 6.0 * Self::DEFAULT_TYPOGRAPHY_HEIGHT;
+
+    pub fn new_default(tex: &'a str, placement: LabelPlacement) -> Self {
+        Self {
+            tex,
+            placement,
+            typography_height: Self::DEFAULT_TYPOGRAPHY_HEIGHT,
+            width: Self::DEFAULT_WIDTH
+        }
+    }
+}
+
+/// The position of a label relative to the point it describes, as seen in the viewport.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LabelPlacement { 
+    Above, 
+    Below, 
+    Left, 
+    Right, 
+    AboveLeft, 
+    AboveRight, 
+    BelowLeft, 
+    BelowRight,
+
+    /// The label is centered on the point. This is intended for an
+    /// [`crate::elements::Annotation`], since the label of a dot would cover the dot.
+    Center
+}
+
+impl LabelPlacement {
+    /// Returns the horizontal and vertical direction of this placement in the normalized
+    /// coordinate system, whose y-axis points downward. Each component is `-1.0`, `0.0` or `1.0`.
+    pub fn direction(&self) -> (f64, f64) {
+        match self {
+            LabelPlacement::Above      => ( 0.0, -1.0),
+            LabelPlacement::Below      => ( 0.0,  1.0),
+            LabelPlacement::Left       => (-1.0,  0.0),
+            LabelPlacement::Right      => ( 1.0,  0.0),
+            LabelPlacement::AboveLeft  => (-1.0, -1.0),
+            LabelPlacement::AboveRight => ( 1.0, -1.0),
+            LabelPlacement::BelowLeft  => (-1.0,  1.0),
+            LabelPlacement::BelowRight => ( 1.0,  1.0),
+            LabelPlacement::Center     => ( 0.0,  0.0),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PointDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_stroke: bool,
+    pub apply_fill: bool
+}
+
+impl PointDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_stroke:       true,
+        apply_fill:         true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_stroke:       false,
+        apply_fill:         false
+    };
+
+    pub const NAME: &'static str = "yapl-def-point";
+}
+
+pub const DEFAULT_POINT_STROKE_WIDTH: f64 = crate::elements::function::DEFAULT_FUNCTION_STROKE_WIDTH;
+pub const DEFAULT_POINT_STROKE: &str = "black";
+pub const DEFAULT_POINT_FILL: &str = "black";
+
+pub(crate) fn write_point_default_style_class(out: &mut impl std::io::Write, 
+    class: &PointDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &PointDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", PointDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_POINT_STROKE_WIDTH)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_POINT_STROKE)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_POINT_FILL)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
+/// The style class applied to points whose fill is [`PointFill::Open`], in addition to
+/// [`PointDefaultStyleClass::NAME`]. This class must be emitted after the
+/// [`PointDefaultStyleClass`] so that it takes precedence.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct OpenPointDefaultStyleClass {
+    pub apply_fill: bool
+}
+
+impl OpenPointDefaultStyleClass {
+    pub const ENABLED: Self = Self { apply_fill: true };
+    pub const DISABLED: Self = Self { apply_fill: false };
+    pub const NAME: &'static str = "yapl-def-open-point";
+}
+
+pub const DEFAULT_OPEN_POINT_FILL: &str = "white";
+
+pub(crate) fn write_open_point_default_style_class(out: &mut impl std::io::Write, 
+    class: &OpenPointDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &OpenPointDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", OpenPointDefaultStyleClass::NAME)?;
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_OPEN_POINT_FILL)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
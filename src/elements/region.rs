@@ -0,0 +1,469 @@
+use std::collections::HashMap;
+use crate::analysis;
+use crate::elements::cplane::Extent;
+use crate::elements::function::{Function, FunctionKind};
+use crate::math::Vec2D;
+
+// # Region
+
+/// A shaded set of points of the plane, such as the solution set of an inequality.
+///
+/// The boundary of the region is stroked separately from its interior. By convention the
+/// boundary is drawn solid if it belongs to the region and dashed if it does not, see
+/// [`StrictRegionBoundaryDefaultStyleClass`].
+pub struct Region<'a> {
+    pub kind: RegionKind<'a>,
+
+    /// Whether the boundary of the region is stroked.
+    pub draw_boundary: bool,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element filling the interior of the region.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element stroking the boundary of the region.
+    pub boundary_style_class: Option<&'a str>
+}
+
+pub enum RegionKind<'a> {
+    /// The set of points lying on one side of the graph of a function, for instance `y < f(x)`.
+    /// The boundary is the graph of the function, sampled exactly as it would be plotted.
+    Graph { function: Function<'a>, inequality: Inequality },
+
+    /// The set of points `(x, y)` for which the predicate holds.
+    ///
+    /// The extent is recursively subdivided into quadrants. Beyond `min_depth`, subdivision
+    /// halts once the predicate agrees at the corners and center of the quadrant. The boundary
+    /// is traced within the quadrants at `max_depth` by bisecting their edges. Features of the
+    /// region smaller than a quadrant at `min_depth` may be missed.
+    Predicate { predicate: Box<dyn Fn(f64, f64) -> bool>, strict: bool, min_depth: u32,
+        max_depth: u32 }
+}
+
+impl<'a> Region<'a> {
+    pub const DEFAULT_MIN_DEPTH: u32 = 4;
+    pub const DEFAULT_MAX_DEPTH: u32 = 8;
+
+    /// Constructs a region bounded by the graph of `function`. For [`FunctionKind::OfX`] the
+    /// region is `y ⋚ f(x)` and for [`FunctionKind::OfY`] the region is `x ⋚ f(y)`.
+    pub fn new_graph(function: Function<'a>, inequality: Inequality) -> Self {
+        Self::new(RegionKind::Graph { function, inequality })
+    }
+
+    /// Constructs the region on which `predicate` holds. Since the predicate is opaque, whether
+    /// its boundary belongs to the region must be stated explicitly through `strict`.
+    pub fn new_predicate<P>(predicate: P, strict: bool) -> Self
+    where P: Fn(f64, f64) -> bool + 'static
+    {
+        Self::new(RegionKind::Predicate {
+            predicate: Box::new(predicate),
+            strict,
+            min_depth: Self::DEFAULT_MIN_DEPTH,
+            max_depth: Self::DEFAULT_MAX_DEPTH
+        })
+    }
+
+    fn new(kind: RegionKind<'a>) -> Self {
+        Self {
+            kind,
+            draw_boundary: true,
+            apply_default_style_class: true,
+            style_class: None,
+            boundary_style_class: None
+        }
+    }
+
+    /// Returns true if the boundary of this region does not belong to the region.
+    pub fn is_strict(&self) -> bool {
+        match &self.kind {
+            RegionKind::Graph { inequality, .. } => inequality.is_strict(),
+            RegionKind::Predicate { strict, .. } => *strict,
+        }
+    }
+
+    /// Computes the outline of the interior and the boundary of this region within the given
+    /// extent. All coordinates are in the coordinate system of the plane.
+    pub fn trace(&self, extent: &Extent) -> RegionTrace {
+        match &self.kind {
+            RegionKind::Graph { function, inequality } => trace_graph(function, *inequality, extent),
+            RegionKind::Predicate { predicate, min_depth, max_depth, .. } => {
+                trace_predicate(predicate, *min_depth, *max_depth, extent)
+            },
+        }
+    }
+}
+
+/// The relation between the output coordinate of a point and the value of the function at the
+/// input coordinate of the point, for all points within a [`RegionKind::Graph`] region.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Inequality { Less, LessEqual, Greater, GreaterEqual }
+
+impl Inequality {
+    /// Returns true if the graph of the function does not belong to the region.
+    pub fn is_strict(self) -> bool {
+        matches!(self, Inequality::Less | Inequality::Greater)
+    }
+
+    /// Returns true if the region lies below the graph of a function of x, or to the left of
+    /// the graph of a function of y.
+    pub fn is_below(self) -> bool {
+        matches!(self, Inequality::Less | Inequality::LessEqual)
+    }
+}
+
+/// The geometry of a [`Region`] as computed by [`Region::trace`].
+#[derive(Default)]
+pub struct RegionTrace {
+    /// Closed polygons whose union is the interior of the region. The polygons do not overlap.
+    pub fill: Vec<Vec<Vec2D>>,
+
+    /// Open polylines whose union is the boundary of the region.
+    pub boundary: Vec<Vec<Vec2D>>
+}
+
+impl RegionTrace {
+    /// Computes the total area of the interior of the region.
+    pub fn area(&self) -> f64 {
+        let mut area = 0.0;
+        for polygon in &self.fill {
+            let mut signed = 0.0;
+            for (i, a) in polygon.iter().enumerate() {
+                let b = polygon[(i + 1) % polygon.len()];
+                signed += (a.x * b.y) - (b.x * a.y);
+            }
+            area += (signed / 2.0).abs();
+        }
+        return area;
+    }
+}
+
+/// The number of points at which the function is evaluated within each break in the sampled
+/// graph of a [`RegionKind::Graph`] region, to determine whether the region spans the extent
+/// there.
+pub const GAP_SAMPLES: usize = 8;
+
+/// The number of times an interval is bisected when locating the edge of a region.
+pub const BISECT_ITERATIONS: usize = 24;
+
+fn trace_graph(function: &Function, inequality: Inequality, extent: &Extent) -> RegionTrace {
+    let mut trace = RegionTrace::default();
+    let Some(params) = function.plotfn_params(extent) else { return trace };
+    let (lo, hi) = (params.codomain.begin(), params.codomain.end());
+    let below = inequality.is_below();
+    let edge = match below { true => lo, false => hi };
+    let at = |input: f64, output: f64| match function.kind {
+        FunctionKind::OfX => Vec2D { x: input, y: output },
+        FunctionKind::OfY => Vec2D { x: output, y: input },
+    };
+
+    // Where the graph is not visible the region spans the entire extent if the function
+    // exceeds the extent on the side of the region, and is empty otherwise.
+    let spans = |input: f64| {
+        let output = (function.eval)(input);
+        match below {
+            true => output >= hi,
+            false => output <= lo,
+        }
+    };
+    let column = |begin: f64, end: f64| vec![at(begin, lo), at(end, lo), at(end, hi), at(begin, hi)];
+    let fill_gap = |fill: &mut Vec<Vec<Vec2D>>, begin: f64, end: f64| {
+        if begin >= end { return; }
+        let step = (end - begin) / (GAP_SAMPLES as f64);
+        let inputs: Vec<f64> = (0..GAP_SAMPLES).map(|i| begin + ((i as f64) + 0.5) * step).collect();
+        let mut span_begin = begin;
+        let mut spanned = spans(inputs[0]);
+        for pair in inputs.windows(2) {
+            if spans(pair[1]) == spanned { continue; }
+            let span_end = bisect(spans, pair[0], pair[1]);
+            if spanned { fill.push(column(span_begin, span_end)); }
+            span_begin = span_end;
+            spanned = !spanned;
+        }
+        if spanned { fill.push(column(span_begin, end)); }
+    };
+
+    let mut cursor = params.domain.begin();
+    for run in analysis::sample_runs(function, extent) {
+        let first = run[0];
+        let last = run[run.len() - 1];
+        fill_gap(&mut trace.fill, cursor, first);
+        let graph: Vec<Vec2D> = run.iter().map(|input| function.coordinate_at(*input)).collect();
+        let mut polygon = vec![at(first, edge)];
+        polygon.extend(graph.iter().copied());
+        polygon.push(at(last, edge));
+        trace.fill.push(polygon);
+        trace.boundary.push(graph);
+        cursor = last;
+    }
+    fill_gap(&mut trace.fill, cursor, params.domain.end());
+    return trace;
+}
+
+/// Locates the point between `a` and `b` at which `test` changes value, where `test(a)`
+/// and `test(b)` are known to differ.
+fn bisect(test: impl Fn(f64) -> bool, mut a: f64, mut b: f64) -> f64 {
+    let at_a = test(a);
+    for _ in 0..BISECT_ITERATIONS {
+        let mid = (a + b) / 2.0;
+        match test(mid) == at_a {
+            true => a = mid,
+            false => b = mid,
+        }
+    }
+    return (a + b) / 2.0;
+}
+
+fn trace_predicate(predicate: &dyn Fn(f64, f64) -> bool, min_depth: u32, max_depth: u32,
+    extent: &Extent)
+-> RegionTrace
+{
+    assert!(min_depth <= max_depth, "Cannot trace region because min_depth exceeds max_depth.");
+    let mut trace = RegionTrace::default();
+    let cells: u64 = 1 << max_depth;
+    let brect = &extent.brect;
+    // The corners of the quadrants are computed from their indices on the finest grid so that
+    // adjacent quadrants share bitwise identical corners, and therefore identical boundary points.
+    let grid = |i: u64, j: u64| Vec2D {
+        x: brect.x.begin() + brect.x.len() * ((i as f64) / (cells as f64)),
+        y: brect.y.begin() + brect.y.len() * ((j as f64) / (cells as f64))
+    };
+    let holds = |p: Vec2D| predicate(p.x, p.y);
+
+    let mut segments: Vec<(Vec2D, Vec2D)> = Vec::new();
+    let mut stack: Vec<(u64, u64, u64, u32)> = vec![(0, 0, cells, 0)];
+    while let Some((i, j, size, depth)) = stack.pop() {
+        // Counterclockwise beginning with the bottom-left corner.
+        let corners = [grid(i, j), grid(i + size, j), grid(i + size, j + size), grid(i, j + size)];
+        let inside = corners.map(holds);
+        let uniform = inside.iter().all(|b| *b == inside[0]) && (size == 1
+            || holds(grid(i + (size / 2), j + (size / 2))) == inside[0]);
+
+        if uniform && depth >= min_depth {
+            if inside[0] { trace.fill.push(corners.to_vec()); }
+            continue;
+        }
+
+        if size > 1 {
+            let half = size / 2;
+            stack.push((i, j, half, depth + 1));
+            stack.push((i + half, j, half, depth + 1));
+            stack.push((i + half, j + half, half, depth + 1));
+            stack.push((i, j + half, half, depth + 1));
+            continue;
+        }
+
+        // This is a smallest quadrant through which the boundary passes. The interior is
+        // outlined by walking the corners and the points at which the boundary crosses the edges.
+        let mut polygon: Vec<(Vec2D, bool)> = Vec::new();
+        for k in 0..4 {
+            let (a, b) = (corners[k], corners[(k + 1) % 4]);
+            if inside[k] { polygon.push((a, false)); }
+            if inside[k] != inside[(k + 1) % 4] {
+                polygon.push((crossing(&holds, a, b), true));
+            }
+        }
+        for k in 0..polygon.len() {
+            let (a, a_crossing) = polygon[k];
+            let (b, b_crossing) = polygon[(k + 1) % polygon.len()];
+            if a_crossing && b_crossing { segments.push((a, b)); }
+        }
+        trace.fill.push(polygon.into_iter().map(|(p, _)| p).collect());
+    }
+    trace.boundary = chain(segments);
+    return trace;
+}
+
+/// Locates the point on the edge between the corners `a` and `b` at which the predicate changes
+/// value. The edge is always bisected in the same direction, regardless of the order of the
+/// corners, so that both quadrants adjacent to the edge agree on the crossing.
+fn crossing(holds: &impl Fn(Vec2D) -> bool, a: Vec2D, b: Vec2D) -> Vec2D {
+    let (a, b) = match (a.x, a.y) < (b.x, b.y) {
+        true => (a, b),
+        false => (b, a),
+    };
+    let along = |t: f64| Vec2D { x: a.x + t * (b.x - a.x), y: a.y + t * (b.y - a.y) };
+    return along(bisect(|t| holds(along(t)), 0.0, 1.0));
+}
+
+/// Joins segments which share endpoints into polylines. Endpoints are shared only if they are
+/// bitwise identical.
+pub(crate) fn chain(segments: Vec<(Vec2D, Vec2D)>) -> Vec<Vec<Vec2D>> {
+    let key = |p: Vec2D| (p.x.to_bits(), p.y.to_bits());
+    let mut adjacent: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    for (index, (a, b)) in segments.iter().enumerate() {
+        adjacent.entry(key(*a)).or_default().push(index);
+        adjacent.entry(key(*b)).or_default().push(index);
+    }
+
+    let mut used = vec![false; segments.len()];
+    // Extends the polyline from its last point for as long as an unused segment continues it.
+    let extend = |polyline: &mut Vec<Vec2D>, used: &mut [bool]| {
+        loop {
+            let tip = polyline[polyline.len() - 1];
+            let next = adjacent[&key(tip)].iter().copied().find(|index| !used[*index]);
+            let Some(index) = next else { break };
+            used[index] = true;
+            let (a, b) = segments[index];
+            polyline.push(match key(a) == key(tip) { true => b, false => a });
+        }
+    };
+
+    let mut polylines: Vec<Vec<Vec2D>> = Vec::new();
+    for index in 0..segments.len() {
+        if used[index] { continue; }
+        used[index] = true;
+        let (a, b) = segments[index];
+        let mut polyline = vec![a, b];
+        extend(&mut polyline, &mut used);
+        polyline.reverse();
+        extend(&mut polyline, &mut used);
+        polylines.push(polyline);
+    }
+    return polylines;
+}
+
+// # Default Style Classes
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RegionDefaultStyleClass {
+    pub apply_fill: bool,
+    pub apply_fill_opacity: bool,
+    pub apply_stroke: bool
+}
+
+impl RegionDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_fill:         true,
+        apply_fill_opacity: true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_fill:         false,
+        apply_fill_opacity: false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-region";
+}
+
+pub const DEFAULT_REGION_FILL: &str = "black";
+pub const DEFAULT_REGION_FILL_OPACITY: f64 = 0.15;
+pub const DEFAULT_REGION_STROKE: &str = "none";
+
+pub(crate) fn write_region_default_style_class(out: &mut impl std::io::Write,
+    class: &RegionDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &RegionDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", RegionDefaultStyleClass::NAME)?;
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_REGION_FILL)?;
+    }
+    if class.apply_fill_opacity {
+        write!(out, "fill-opacity: {};", DEFAULT_REGION_FILL_OPACITY)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_REGION_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RegionBoundaryDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_linecap: bool,
+    pub apply_linejoin: bool,
+    pub apply_fill: bool,
+    pub apply_stroke: bool
+}
+
+impl RegionBoundaryDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_linecap:      true,
+        apply_linejoin:     true,
+        apply_fill:         true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_linecap:      false,
+        apply_linejoin:     false,
+        apply_fill:         false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-region-boundary";
+}
+
+pub const DEFAULT_REGION_BOUNDARY_STROKE_WIDTH: f64 =
+    crate::elements::function::DEFAULT_FUNCTION_STROKE_WIDTH;
+pub const DEFAULT_REGION_BOUNDARY_LINECAP: &str = "round";
+pub const DEFAULT_REGION_BOUNDARY_LINEJOIN: &str = "round";
+pub const DEFAULT_REGION_BOUNDARY_FILL: &str = "none";
+pub const DEFAULT_REGION_BOUNDARY_STROKE: &str = "black";
+
+pub(crate) fn write_region_boundary_default_style_class(out: &mut impl std::io::Write,
+    class: &RegionBoundaryDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &RegionBoundaryDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", RegionBoundaryDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_REGION_BOUNDARY_STROKE_WIDTH)?;
+    }
+    if class.apply_linecap {
+        write!(out, "stroke-linecap: {};", DEFAULT_REGION_BOUNDARY_LINECAP)?;
+    }
+    if class.apply_linejoin {
+        write!(out, "stroke-linejoin: {};", DEFAULT_REGION_BOUNDARY_LINEJOIN)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_REGION_BOUNDARY_FILL)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_REGION_BOUNDARY_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
+/// The style class applied to the boundary of a strict region, in addition to
+/// [`RegionBoundaryDefaultStyleClass::NAME`]. This class must be emitted after the
+/// [`RegionBoundaryDefaultStyleClass`] so that it takes precedence.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct StrictRegionBoundaryDefaultStyleClass {
+    pub apply_dasharray: bool
+}
+
+impl StrictRegionBoundaryDefaultStyleClass {
+    pub const ENABLED: Self = Self { apply_dasharray: true };
+    pub const DISABLED: Self = Self { apply_dasharray: false };
+    pub const NAME: &'static str = "yapl-def-strict-region-boundary";
+}
+
+pub const DEFAULT_STRICT_REGION_BOUNDARY_DASH: f64 = 6.0 * DEFAULT_REGION_BOUNDARY_STROKE_WIDTH;
+pub const DEFAULT_STRICT_REGION_BOUNDARY_GAP: f64 = 4.0 * DEFAULT_REGION_BOUNDARY_STROKE_WIDTH;
+
+pub(crate) fn write_strict_region_boundary_default_style_class(out: &mut impl std::io::Write,
+    class: &StrictRegionBoundaryDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &StrictRegionBoundaryDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", StrictRegionBoundaryDefaultStyleClass::NAME)?;
+    if class.apply_dasharray {
+        write!(out, "stroke-dasharray: {} {};", DEFAULT_STRICT_REGION_BOUNDARY_DASH,
+            DEFAULT_STRICT_REGION_BOUNDARY_GAP)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
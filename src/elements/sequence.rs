@@ -0,0 +1,300 @@
+use std::ops::RangeInclusive;
+use crate::elements::point::Point;
+use crate::elements::series::Series;
+use crate::elements::stems::Stems;
+use crate::math::{BoundingRect, Vec2D};
+
+// # Sequence
+
+/// A sequence of real numbers `a_n` indexed by consecutive integers `n`, plotted as the points
+/// `(n, a_n)`.
+///
+/// Each term is marked by a dot, and optionally by a stem rising from the horizontal axis, see
+/// [`Self::stems`]. Consecutive terms may be joined by line segments to guide the eye, see
+/// [`Self::connect`]. Terms which are not finite, as when a recursion diverges, are not drawn
+/// and break the connecting line. The dots are drawn above the axes, together with the points
+/// of the [`crate::elements::CoordinatePlane`].
+pub struct Sequence<'a> {
+    /// The index of the first term.
+    pub first: i64,
+    pub terms: Vec<f64>,
+
+    /// Whether each term is drawn as a stem rising from the horizontal axis, rather than only
+    /// a dot.
+    pub stems: bool,
+
+    /// Whether consecutive terms are joined by line segments.
+    pub connect: bool,
+
+    /// The radius of the dots in the normalized coordinate system, or `None` if the terms are
+    /// drawn without dots.
+    pub dot_radius: Option<f64>,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of every element of the sequence, that is the dots, the stems and the
+    /// connecting line.
+    ///
+    /// Note that these classes are in addition to the default style class names, unless
+    /// of course the default style class names have been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Sequence<'a> {
+    pub const DEFAULT_DOT_RADIUS: f64 = Point::DEFAULT_RADIUS;
+
+    /// Constructs the sequence of the given terms, the first of which has index `first`.
+    pub fn new_default(first: i64, terms: Vec<f64>) -> Self {
+        Self {
+            first,
+            terms,
+            stems: false,
+            connect: false,
+            dot_radius: Some(Self::DEFAULT_DOT_RADIUS),
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Constructs the sequence given by the explicit formula `a_n = f(n)` over the given
+    /// range of indices.
+    pub fn new_explicit<F>(indices: RangeInclusive<i64>, f: F) -> Self
+    where F: Fn(i64) -> f64
+    {
+        Self::new_default(*indices.start(), indices.map(f).collect())
+    }
+
+    /// Constructs the sequence given by the recursion `a_{n+1} = f(a_n)`, beginning with the
+    /// term `initial` at index `first` and continuing for `count` terms in total.
+    pub fn new_recursive<F>(first: i64, initial: f64, count: usize, f: F) -> Self
+    where F: Fn(f64) -> f64
+    {
+        Self::new_default(first, recursion(initial, count, f))
+    }
+
+    /// Returns the index of each term paired with the term, in order. Terms which are not
+    /// finite are replaced by `NaN`.
+    pub fn samples(&self) -> Vec<(f64, f64)> {
+        self.terms.iter().enumerate()
+            .map(|(i, term)| ((self.first + (i as i64)) as f64, *term))
+            .map(|(n, term)| (n, if term.is_finite() { term } else { f64::NAN }))
+            .collect()
+    }
+
+    /// Computes the line joining consecutive terms within the given bounding rectangle, see
+    /// [`Series::polylines`].
+    pub fn polylines(&self, brect: &BoundingRect) -> Vec<Vec<Vec2D>> {
+        Series::new_polyline(self.samples()).polylines(brect)
+    }
+
+    /// Constructs the stems of the terms, without dots since those are given by [`Self::dots`].
+    pub fn to_stems(&self) -> Stems<'a> {
+        let mut stems = Stems::new_default(&self.samples());
+        stems.head_radius = None;
+        stems.apply_default_style_class = self.apply_default_style_class;
+        stems.style_class = self.style_class;
+        return stems;
+    }
+
+    /// Computes the dots marking the finite terms.
+    pub fn dots(&self) -> Vec<Point<'a>> {
+        let Some(radius) = self.dot_radius else { return Vec::new() };
+        let mut dots: Vec<Point<'a>> = Vec::new();
+        for (n, term) in self.samples() {
+            if term.is_nan() { continue; }
+            let mut dot = Point::new_default(Vec2D { x: n, y: term });
+            dot.radius = radius;
+            dot.apply_default_style_class = self.apply_default_style_class;
+            dot.style_class = self.style_class;
+            dots.push(dot);
+        }
+        return dots;
+    }
+
+    /// Constructs the cobweb diagram of the terms of this sequence, which is meaningful if the
+    /// sequence is given by a recursion.
+    pub fn cobweb(&self) -> Cobweb<'a> { Cobweb::new_default(self.terms.clone()) }
+}
+
+/// Computes `count` terms of the recursion `a_{n+1} = f(a_n)` beginning with `initial`.
+fn recursion<F>(initial: f64, count: usize, f: F) -> Vec<f64>
+where F: Fn(f64) -> f64
+{
+    let mut terms: Vec<f64> = Vec::with_capacity(count);
+    let mut term = initial;
+    for _ in 0..count {
+        terms.push(term);
+        term = f(term);
+    }
+    return terms;
+}
+
+// # Cobweb
+
+/// The cobweb diagram of a recursion `a_{n+1} = f(a_n)`, which illustrates the convergence or
+/// divergence of the recursion.
+///
+/// The path begins at `(a_0, 0)`, or at `(a_0, a_0)` if [`Self::start_on_axis`] is false, and
+/// proceeds vertically to the graph of `f` at `(a_0, a_1)` and then horizontally to the
+/// diagonal at `(a_1, a_1)`, and so on. The graph of `f` and the diagonal `y = x` are not part
+/// of the diagram and should be added to the [`crate::elements::CoordinatePlane`] as functions.
+pub struct Cobweb<'a> {
+    /// The terms of the recursion, beginning with the initial term.
+    pub terms: Vec<f64>,
+
+    /// Whether the path begins on the horizontal axis rather than on the diagonal.
+    pub start_on_axis: bool,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Cobweb<'a> {
+    /// Constructs the cobweb diagram of the given terms of a recursion.
+    pub fn new_default(terms: Vec<f64>) -> Self {
+        Self { terms, start_on_axis: true, apply_default_style_class: true, style_class: None }
+    }
+
+    /// Constructs the cobweb diagram of `iterations` applications of `f` beginning with
+    /// `initial`.
+    pub fn new_recursive<F>(initial: f64, iterations: usize, f: F) -> Self
+    where F: Fn(f64) -> f64
+    {
+        Self::new_default(recursion(initial, iterations + 1, f))
+    }
+
+    /// Computes the vertices of the path in the coordinate system of the plane. The path ends
+    /// before the first term which is not finite.
+    pub fn path(&self) -> Vec<Vec2D> {
+        let terms: Vec<f64> = self.terms.iter().copied().take_while(|t| t.is_finite()).collect();
+        let Some(initial) = terms.first() else { return Vec::new() };
+        let mut path: Vec<Vec2D> = vec![Vec2D {
+            x: *initial,
+            y: if self.start_on_axis { 0.0 } else { *initial }
+        }];
+        for pair in terms.windows(2) {
+            path.push(Vec2D { x: pair[0], y: pair[1] });
+            path.push(Vec2D { x: pair[1], y: pair[1] });
+        }
+        return path;
+    }
+
+    /// Computes the path within the given bounding rectangle, see [`Series::polylines`].
+    pub fn polylines(&self, brect: &BoundingRect) -> Vec<Vec<Vec2D>> {
+        let samples = self.path().into_iter().map(|p| (p.x, p.y)).collect();
+        Series::new_polyline(samples).polylines(brect)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SequenceDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_linejoin: bool,
+    pub apply_fill: bool,
+    pub apply_stroke: bool
+}
+
+impl SequenceDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_linejoin:     true,
+        apply_fill:         true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_linejoin:     false,
+        apply_fill:         false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-sequence";
+}
+
+pub const DEFAULT_SEQUENCE_STROKE_WIDTH: f64 = crate::elements::line::DEFAULT_LINE_STROKE_WIDTH;
+pub const DEFAULT_SEQUENCE_LINEJOIN: &str = "round";
+pub const DEFAULT_SEQUENCE_FILL: &str = "none";
+pub const DEFAULT_SEQUENCE_STROKE: &str = "black";
+
+pub(crate) fn write_sequence_default_style_class(out: &mut impl std::io::Write,
+    class: &SequenceDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &SequenceDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", SequenceDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_SEQUENCE_STROKE_WIDTH)?;
+    }
+    if class.apply_linejoin {
+        write!(out, "stroke-linejoin: {};", DEFAULT_SEQUENCE_LINEJOIN)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_SEQUENCE_FILL)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_SEQUENCE_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CobwebDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_linejoin: bool,
+    pub apply_fill: bool,
+    pub apply_stroke: bool
+}
+
+impl CobwebDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_linejoin:     true,
+        apply_fill:         true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_linejoin:     false,
+        apply_fill:         false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-cobweb";
+}
+
+pub const DEFAULT_COBWEB_STROKE_WIDTH: f64 = crate::elements::line::DEFAULT_LINE_STROKE_WIDTH;
+pub const DEFAULT_COBWEB_LINEJOIN: &str = "miter";
+pub const DEFAULT_COBWEB_FILL: &str = "none";
+pub const DEFAULT_COBWEB_STROKE: &str = "black";
+
+pub(crate) fn write_cobweb_default_style_class(out: &mut impl std::io::Write,
+    class: &CobwebDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &CobwebDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", CobwebDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_COBWEB_STROKE_WIDTH)?;
+    }
+    if class.apply_linejoin {
+        write!(out, "stroke-linejoin: {};", DEFAULT_COBWEB_LINEJOIN)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_COBWEB_FILL)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_COBWEB_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
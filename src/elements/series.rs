@@ -0,0 +1,486 @@
+use crate::math::{BoundingRect, Interpolation, Spline, Vec2D};
+
+// # Series
+
+/// A sequence of numeric samples, such as measured data, drawn as markers, as a polyline
+/// connecting consecutive samples, or both.
+///
+/// Samples whose coordinates are `NaN` are never drawn and break the polyline, therefore missing
+/// measurements can be represented by `NaN` without joining the samples on either side of them.
+/// Markers lying outside of the extent are not drawn, and the polyline is clipped to the extent.
+pub struct Series<'a> {
+    pub samples: Vec<(f64, f64)>,
+    pub mode: SeriesMode,
+
+    /// The curve drawn through the samples. Unless this is [`Interpolation::Linear`], the
+    /// samples within each unbroken stretch must be ordered by strictly increasing x-coordinate,
+    /// see [`Spline::new`], and the curve is emitted as a sequence of cubic Bézier curves.
+    pub interpolation: Interpolation,
+    pub marker: Marker<'a>,
+
+    /// The uncertainty of each sample, or `None` if the samples are exact.
+    pub error_bars: Option<ErrorBars<'a>>,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` element of the polyline.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Series<'a> {
+    pub fn new_default(samples: Vec<(f64, f64)>, mode: SeriesMode) -> Self {
+        Self {
+            samples,
+            mode,
+            interpolation: Interpolation::Linear,
+            marker: Marker::new_default(MarkerShape::Circle),
+            error_bars: None,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Constructs a new [`Series`] which draws only a marker at each sample, as in a scatter plot.
+    pub fn new_scatter(samples: Vec<(f64, f64)>) -> Self {
+        Self::new_default(samples, SeriesMode::Markers)
+    }
+
+    /// Constructs a new [`Series`] which draws only the polyline connecting the samples.
+    pub fn new_polyline(samples: Vec<(f64, f64)>) -> Self {
+        Self::new_default(samples, SeriesMode::Polyline)
+    }
+
+    /// Iterates over the samples which lie within the given rectangle. These are the positions
+    /// of the markers.
+    pub fn markers<'b>(&'b self, brect: &'b BoundingRect) -> impl Iterator<Item = Vec2D> + 'b {
+        self.samples.iter()
+            .map(|(x, y)| Vec2D { x: *x, y: *y })
+            .filter(|pos| brect.includes(pos))
+    }
+
+    /// Computes the stretches of the polyline connecting the samples which are visible within
+    /// the given rectangle. The polyline is broken wherever a sample is `NaN` and wherever it
+    /// leaves the rectangle.
+    pub fn polylines(&self, brect: &BoundingRect) -> Vec<Vec<Vec2D>> {
+        let mut polylines: Vec<Vec<Vec2D>> = Vec::new();
+        // The end of the most recently drawn segment, so long as the polyline is unbroken.
+        let mut tip: Option<Vec2D> = None;
+        for pair in self.samples.windows(2) {
+            let a = Vec2D { x: pair[0].0, y: pair[0].1 };
+            let b = Vec2D { x: pair[1].0, y: pair[1].1 };
+            let defined = [a.x, a.y, b.x, b.y].iter().all(|c| !c.is_nan());
+            let clipped = match defined {
+                true => brect.clip_segment(a, b),
+                false => None,
+            };
+            let Some((start, stop)) = clipped else {
+                tip = None;
+                continue;
+            };
+            // The samples lying within the rectangle are used verbatim, rather than the clipped
+            // endpoints, so that consecutive segments join exactly despite rounding error.
+            let start = match brect.includes(&a) { true => a, false => start };
+            let stop = match brect.includes(&b) { true => b, false => stop };
+            if tip != Some(start) {
+                polylines.push(vec![start]);
+            }
+            polylines.last_mut().unwrap().push(stop);
+            // A segment which is cut short by the rectangle cannot be continued.
+            tip = match stop == b {
+                true => Some(stop),
+                false => None,
+            };
+        }
+        return polylines;
+    }
+
+    /// Computes the interpolating spline through each unbroken stretch of samples. Stretches
+    /// are separated by samples which are not finite. Stretches of a single sample are omitted,
+    /// as are stretches which are not ordered by strictly increasing x-coordinate, since these
+    /// are not the graph of a function and cannot be interpolated.
+    pub fn splines(&self) -> Vec<Spline> {
+        return self.samples
+            .split(|(x, y)| !x.is_finite() || !y.is_finite())
+            .filter(|run| run.len() >= 2)
+            .filter(|run| run.windows(2).all(|pair| pair[0].0 < pair[1].0))
+            .map(|run| Spline::new(self.interpolation, run))
+            .collect();
+    }
+
+    /// Constructs the interpolant of the samples as a closure, for instance to construct a
+    /// [`crate::elements::Function`] for analysis. The interpolant is `NaN` outside of the
+    /// unbroken stretches of samples.
+    pub fn interpolant(&self) -> impl Fn(f64) -> f64 + 'static {
+        let splines = self.splines();
+        move |x| {
+            splines.iter()
+                .find(|spline| spline.knots[0] <= x && x <= spline.knots[spline.knots.len() - 1])
+                .map_or(f64::NAN, |spline| spline.eval(x))
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SeriesMode { Markers, Polyline, Both }
+
+// # Error Bars
+
+/// The uncertainties of the samples of a [`Series`], drawn as a bar spanning the range of
+/// plausible values of each sample. Bars are drawn beneath the markers.
+///
+/// Each end of a bar is capped by a short perpendicular segment, unless the end lies outside of
+/// the extent, in which case the bar is clipped and the cap omitted. The caps are assigned a
+/// separate style class from the bars, so that they may be hidden or restyled independently.
+pub struct ErrorBars<'a> {
+    /// The horizontal uncertainty of each sample, in the same order as the samples, or `None`
+    /// if there is no horizontal uncertainty.
+    pub x: Option<Vec<Uncertainty>>,
+
+    /// The vertical uncertainty of each sample, in the same order as the samples, or `None`
+    /// if there is no vertical uncertainty.
+    pub y: Option<Vec<Uncertainty>>,
+
+    /// The length of each cap in the normalized coordinate system.
+    pub cap_len: f64,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `path` elements of the bars and of the caps.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> ErrorBars<'a> {
+    pub const DEFAULT_CAP_LEN: f64 = Marker::DEFAULT_SIZE;
+
+    pub fn new_default(x: Option<Vec<Uncertainty>>, y: Option<Vec<Uncertainty>>) -> Self {
+        Self {
+            x,
+            y,
+            cap_len: Self::DEFAULT_CAP_LEN,
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Constructs [`ErrorBars`] with the given symmetric vertical uncertainties.
+    pub fn new_symmetric_y(errors: &[f64]) -> Self {
+        Self::new_default(None, Some(errors.iter().map(|e| Uncertainty::Symmetric(*e)).collect()))
+    }
+
+    /// Constructs [`ErrorBars`] with the given symmetric horizontal uncertainties.
+    pub fn new_symmetric_x(errors: &[f64]) -> Self {
+        Self::new_default(Some(errors.iter().map(|e| Uncertainty::Symmetric(*e)).collect()), None)
+    }
+
+    /// Computes the bars for the given samples which are visible within the given rectangle.
+    /// Samples which are `NaN`, and uncertainties which are `NaN`, are skipped.
+    pub fn bars(&self, samples: &[(f64, f64)], brect: &BoundingRect) -> Vec<ErrorBar> {
+        let mut bars: Vec<ErrorBar> = Vec::new();
+        for (i, (x, y)) in samples.iter().copied().enumerate() {
+            if x.is_nan() || y.is_nan() { continue; }
+            let horizontal = self.x.as_ref().and_then(|x| x.get(i)).map(|u| (u.bounds(x), true));
+            let vertical = self.y.as_ref().and_then(|y| y.get(i)).map(|u| (u.bounds(y), false));
+            for ((lo, hi), is_horizontal) in horizontal.into_iter().chain(vertical) {
+                if lo.is_nan() || hi.is_nan() { continue; }
+                let (a, b) = match is_horizontal {
+                    true => (Vec2D { x: lo, y }, Vec2D { x: hi, y }),
+                    false => (Vec2D { x, y: lo }, Vec2D { x, y: hi }),
+                };
+                let Some((start, stop)) = brect.clip_segment(a, b) else { continue };
+                bars.push(ErrorBar {
+                    a: start,
+                    b: stop,
+                    caps: [brect.includes(&a), brect.includes(&b)],
+                    is_horizontal
+                });
+            }
+        }
+        return bars;
+    }
+}
+
+/// The range of plausible values of a measurement.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Uncertainty {
+    /// The measurement may be greater or less than the measured value by the given amount.
+    Symmetric(f64),
+
+    /// The measurement may be less than the measured value by the first amount and greater than
+    /// the measured value by the second amount.
+    Asymmetric(f64, f64)
+}
+
+impl Uncertainty {
+    /// Computes the least and greatest plausible values of a measurement of `value`.
+    pub fn bounds(self, value: f64) -> (f64, f64) {
+        match self {
+            Uncertainty::Symmetric(error) => (value - error, value + error),
+            Uncertainty::Asymmetric(minus, plus) => (value - minus, value + plus),
+        }
+    }
+}
+
+/// A single error bar as computed by [`ErrorBars::bars`]. The bar spans from `a` to `b`, which
+/// have been clipped to the extent.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ErrorBar {
+    pub a: Vec2D,
+    pub b: Vec2D,
+
+    /// Whether `a` and `b` respectively are capped. An end is not capped if it was clipped.
+    pub caps: [bool; 2],
+    pub is_horizontal: bool
+}
+
+impl SeriesMode {
+    pub fn has_markers(self) -> bool { self != SeriesMode::Polyline }
+    pub fn has_polyline(self) -> bool { self != SeriesMode::Markers }
+}
+
+// # Marker
+
+/// The symbol drawn at each sample of a [`Series`].
+///
+/// The shape is defined once per series within `<defs>` and instantiated at each sample with
+/// `<use>`. The CSS classes are assigned to the `<use>` elements, from which the shape inherits
+/// its `fill`, `stroke` and `stroke-width`.
+#[derive(Clone, Copy)]
+pub struct Marker<'a> {
+    pub shape: MarkerShape,
+
+    /// The width and height of the shape in the normalized coordinate system.
+    pub size: f64,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of each `use` element.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Marker<'a> {
+    pub const DEFAULT_SIZE: f64 = 2.0 * crate::elements::point::Point::DEFAULT_RADIUS;
+
+    pub fn new_default(shape: MarkerShape) -> Self {
+        Self { shape, size: Self::DEFAULT_SIZE, apply_default_style_class: true, style_class: None }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MarkerShape {
+    Circle,
+    Square,
+    Diamond,
+
+    /// A triangle pointing upward in the viewport.
+    Triangle,
+
+    /// A diagonal cross, drawn only by its stroke.
+    Cross,
+
+    /// An upright cross, drawn only by its stroke.
+    Plus
+}
+
+// # Default Style Classes
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SeriesDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_linecap: bool,
+    pub apply_linejoin: bool,
+    pub apply_fill: bool,
+    pub apply_stroke: bool
+}
+
+impl SeriesDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_linecap:      true,
+        apply_linejoin:     true,
+        apply_fill:         true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_linecap:      false,
+        apply_linejoin:     false,
+        apply_fill:         false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-series";
+}
+
+pub const DEFAULT_SERIES_STROKE_WIDTH: f64 = crate::elements::line::DEFAULT_LINE_STROKE_WIDTH;
+pub const DEFAULT_SERIES_LINECAP: &str = "round";
+pub const DEFAULT_SERIES_LINEJOIN: &str = "round";
+pub const DEFAULT_SERIES_FILL: &str = "none";
+pub const DEFAULT_SERIES_STROKE: &str = "black";
+
+pub(crate) fn write_series_default_style_class(out: &mut impl std::io::Write,
+    class: &SeriesDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &SeriesDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", SeriesDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_SERIES_STROKE_WIDTH)?;
+    }
+    if class.apply_linecap {
+        write!(out, "stroke-linecap: {};", DEFAULT_SERIES_LINECAP)?;
+    }
+    if class.apply_linejoin {
+        write!(out, "stroke-linejoin: {};", DEFAULT_SERIES_LINEJOIN)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_SERIES_FILL)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_SERIES_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MarkerDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_stroke: bool,
+    pub apply_fill: bool
+}
+
+impl MarkerDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_stroke:       true,
+        apply_fill:         true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_stroke:       false,
+        apply_fill:         false
+    };
+
+    pub const NAME: &'static str = "yapl-def-marker";
+}
+
+pub const DEFAULT_MARKER_STROKE_WIDTH: f64 = crate::elements::line::DEFAULT_LINE_STROKE_WIDTH;
+pub const DEFAULT_MARKER_STROKE: &str = "black";
+pub const DEFAULT_MARKER_FILL: &str = "black";
+
+pub(crate) fn write_marker_default_style_class(out: &mut impl std::io::Write,
+    class: &MarkerDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &MarkerDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", MarkerDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_MARKER_STROKE_WIDTH)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_MARKER_STROKE)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_MARKER_FILL)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ErrorBarDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_stroke: bool,
+    pub apply_fill: bool
+}
+
+impl ErrorBarDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_stroke:       true,
+        apply_fill:         true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_stroke:       false,
+        apply_fill:         false
+    };
+
+    pub const NAME: &'static str = "yapl-def-error-bar";
+}
+
+pub const DEFAULT_ERROR_BAR_STROKE_WIDTH: f64 = crate::elements::line::DEFAULT_LINE_STROKE_WIDTH;
+pub const DEFAULT_ERROR_BAR_STROKE: &str = "black";
+pub const DEFAULT_ERROR_BAR_FILL: &str = "none";
+
+pub(crate) fn write_error_bar_default_style_class(out: &mut impl std::io::Write,
+    class: &ErrorBarDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &ErrorBarDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", ErrorBarDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_ERROR_BAR_STROKE_WIDTH)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_ERROR_BAR_STROKE)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_ERROR_BAR_FILL)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ErrorBarCapDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_stroke: bool,
+    pub apply_fill: bool
+}
+
+impl ErrorBarCapDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_stroke:       true,
+        apply_fill:         true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_stroke:       false,
+        apply_fill:         false
+    };
+
+    pub const NAME: &'static str = "yapl-def-error-bar-cap";
+}
+
+pub const DEFAULT_ERROR_BAR_CAP_STROKE_WIDTH: f64 = DEFAULT_ERROR_BAR_STROKE_WIDTH;
+pub const DEFAULT_ERROR_BAR_CAP_STROKE: &str = "black";
+pub const DEFAULT_ERROR_BAR_CAP_FILL: &str = "none";
+
+pub(crate) fn write_error_bar_cap_default_style_class(out: &mut impl std::io::Write,
+    class: &ErrorBarCapDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &ErrorBarCapDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", ErrorBarCapDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_ERROR_BAR_CAP_STROKE_WIDTH)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_ERROR_BAR_CAP_STROKE)?;
+    }
+    if class.apply_fill {
+        write!(out, "fill: {};", DEFAULT_ERROR_BAR_CAP_FILL)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
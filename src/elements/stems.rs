@@ -0,0 +1,136 @@
+use crate::elements::point::Point;
+use crate::math::{BoundingRect, Vec2D};
+
+// # Stems
+
+/// A stem plot of discrete values, such as a probability mass function. Each stem is a vertical
+/// line from the baseline to its value, topped by a dot.
+///
+/// The dots are drawn above the axes, together with the points of the
+/// [`crate::elements::CoordinatePlane`].
+pub struct Stems<'a> {
+    pub stems: Vec<Stem<'a>>,
+
+    /// The value from which every stem rises, or descends if the value of the stem is smaller.
+    pub baseline: f64,
+
+    /// The radius of the dots in the normalized coordinate system, or `None` if the stems are
+    /// drawn without dots.
+    pub head_radius: Option<f64>,
+    pub apply_default_style_class: bool,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of every `line` element. The dot of a stem is assigned the classes of the stem,
+    /// see [`Stem::style_class`], or if it has none, these classes.
+    ///
+    /// Note that these classes are in addition to the default style class name, unless
+    /// of course the default style class name has been explicitly omitted by setting
+    /// `apply_default_style_class` to false.
+    pub style_class: Option<&'a str>
+}
+
+/// A single stem of [`Stems`].
+#[derive(Clone, Copy, Debug)]
+pub struct Stem<'a> {
+    pub x: f64,
+    pub value: f64,
+
+    /// Space-delimited list of names of custom CSS styles classes to include in the `class`
+    /// attribute of the `line` element and the dot of this stem only, in addition to the classes
+    /// of the stem plot. This allows for highlighting individual stems.
+    pub style_class: Option<&'a str>
+}
+
+impl<'a> Stems<'a> {
+    pub const DEFAULT_HEAD_RADIUS: f64 = Point::DEFAULT_RADIUS;
+
+    /// Constructs a stem for each of the given `(x, value)` pairs.
+    pub fn new_default(samples: &[(f64, f64)]) -> Self {
+        Self {
+            stems: samples.iter()
+                .map(|(x, value)| Stem { x: *x, value: *value, style_class: None })
+                .collect(),
+            baseline: 0.0,
+            head_radius: Some(Self::DEFAULT_HEAD_RADIUS),
+            apply_default_style_class: true,
+            style_class: None
+        }
+    }
+
+    /// Assigns the given classes to every stem whose position lies strictly between `a`
+    /// and `b`, such as to highlight the outcomes of the event `a < X < b`.
+    pub fn highlight(&mut self, a: f64, b: f64, style_class: &'a str) {
+        for stem in self.stems.iter_mut().filter(|stem| a < stem.x && stem.x < b) {
+            stem.style_class = Some(style_class);
+        }
+    }
+
+    /// Computes the line of each stem within the given bounding rectangle, in the coordinate
+    /// system of the plane. The lines are paired with their stems and stems which lie entirely
+    /// outside of the bounding rectangle are omitted.
+    pub fn lines(&self, brect: &BoundingRect) -> Vec<(Stem<'a>, Vec2D, Vec2D)> {
+        self.stems.iter()
+            .filter(|stem| !stem.x.is_nan() && !stem.value.is_nan() && stem.value != self.baseline)
+            .filter_map(|stem| {
+                let (a, b) = brect.clip_segment(Vec2D { x: stem.x, y: self.baseline },
+                    Vec2D { x: stem.x, y: stem.value })?;
+                Some((*stem, a, b))
+            })
+            .collect()
+    }
+
+    /// Computes the dots atop the stems. Dots lying outside of the extent are discarded when
+    /// drawn, just like any other [`Point`].
+    pub fn heads(&self) -> Vec<Point<'a>> {
+        let Some(radius) = self.head_radius else { return Vec::new() };
+        let mut heads: Vec<Point<'a>> = Vec::new();
+        for stem in &self.stems {
+            if stem.x.is_nan() || stem.value.is_nan() { continue; }
+            let mut head = Point::new_default(Vec2D { x: stem.x, y: stem.value });
+            head.radius = radius;
+            head.apply_default_style_class = self.apply_default_style_class;
+            head.style_class = stem.style_class.or(self.style_class);
+            heads.push(head);
+        }
+        return heads;
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct StemDefaultStyleClass {
+    pub apply_stroke_width: bool,
+    pub apply_stroke: bool
+}
+
+impl StemDefaultStyleClass {
+    pub const ENABLED: Self = Self {
+        apply_stroke_width: true,
+        apply_stroke:       true
+    };
+
+    pub const DISABLED: Self = Self {
+        apply_stroke_width: false,
+        apply_stroke:       false
+    };
+
+    pub const NAME: &'static str = "yapl-def-stem";
+}
+
+pub const DEFAULT_STEM_STROKE_WIDTH: f64 = crate::elements::function::DEFAULT_FUNCTION_STROKE_WIDTH;
+pub const DEFAULT_STEM_STROKE: &str = "black";
+
+pub(crate) fn write_stem_default_style_class(out: &mut impl std::io::Write,
+    class: &StemDefaultStyleClass)
+-> std::io::Result<()>
+{
+    if class == &StemDefaultStyleClass::DISABLED { return Ok(()) }
+    write!(out, ".{} {{", StemDefaultStyleClass::NAME)?;
+    if class.apply_stroke_width {
+        write!(out, "stroke-width: {};", DEFAULT_STEM_STROKE_WIDTH)?;
+    }
+    if class.apply_stroke {
+        write!(out, "stroke: {};", DEFAULT_STEM_STROKE)?;
+    }
+    write!(out, "}}")?;
+    return Ok(());
+}
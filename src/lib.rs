@@ -1,5 +1,9 @@
 pub mod math;
+pub mod analysis;
+pub mod data;
+pub mod distributions;
 pub mod misc;
+pub mod raster;
 pub mod plotfn;
 pub mod codegen;
 pub mod typography;
@@ -110,6 +110,27 @@ macro_rules! assert_matches {
     }
 }
 
+// # Encoding Utilities
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes `data` in the standard base64 alphabet with padding.
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut s = String::with_capacity(4 * data.len().div_ceil(3));
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | (b[2] as u32);
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => s.push(BASE64_ALPHABET[((n >> (18 - (6 * i))) & 0x3F) as usize] as char),
+                false => s.push('='),
+            }
+        }
+    }
+    return s;
+}
+
 // # IO utilities
 
 pub fn read_u32_le(stream: &mut impl std::io::Read) -> std::io::Result<u32> {
@@ -0,0 +1,140 @@
+// # Raster
+
+/// An RGB image whose pixels are stored row by row, beginning with the top row.
+///
+/// Rasters are embedded into the SVG as PNG data URIs. The PNG encoder is deliberately
+/// minimal: the image data is stored uncompressed within the zlib stream, which is valid
+/// PNG and requires no dependencies, at the expense of size.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Raster {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<[u8; 3]>
+}
+
+impl Raster {
+    /// Constructs a black raster of the given dimensions.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, height, pixels: vec![[0; 3]; width * height] }
+    }
+
+    pub fn get(&self, column: usize, row: usize) -> [u8; 3] {
+        self.pixels[(row * self.width) + column]
+    }
+
+    pub fn set(&mut self, column: usize, row: usize, rgb: [u8; 3]) {
+        self.pixels[(row * self.width) + column] = rgb;
+    }
+
+    /// Encodes this raster as an 8-bit truecolor PNG.
+    pub fn encode_png(&self) -> Vec<u8> {
+        let mut png: Vec<u8> = Vec::new();
+        png.extend_from_slice(&PNG_SIGNATURE);
+
+        let mut header: Vec<u8> = Vec::with_capacity(13);
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        // Bit depth 8, truecolor, deflate compression, adaptive filtering, no interlacing.
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+        write_png_chunk(&mut png, b"IHDR", &header);
+
+        // Each scanline is preceded by its filter type, which is always None.
+        let mut scanlines: Vec<u8> = Vec::with_capacity(self.height * ((3 * self.width) + 1));
+        for row in self.pixels.chunks(usize::max(self.width, 1)).take(self.height) {
+            scanlines.push(0);
+            for pixel in row { scanlines.extend_from_slice(pixel); }
+        }
+        write_png_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        write_png_chunk(&mut png, b"IEND", &[]);
+        return png;
+    }
+
+    /// Encodes this raster as a PNG data URI suitable for the `href` attribute of an SVG
+    /// `<image>` element.
+    pub fn to_data_uri(&self) -> String {
+        let mut uri = String::from("data:image/png;base64,");
+        uri.push_str(&crate::misc::base64_encode(&self.encode_png()));
+        return uri;
+    }
+}
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// The maximum length of a stored (uncompressed) deflate block.
+const MAX_STORED_BLOCK_LEN: usize = 0xFFFF;
+
+fn write_png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let begin = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[begin..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream consisting of stored deflate blocks.
+pub fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let block_count = usize::max(data.len().div_ceil(MAX_STORED_BLOCK_LEN), 1);
+    let mut out: Vec<u8> = Vec::with_capacity(data.len() + (5 * block_count) + 6);
+    // Deflate with a 32K window and no preset dictionary, at the fastest level. The header
+    // is a multiple of 31 when read as a big-endian integer.
+    out.extend_from_slice(&[0x78, 0x01]);
+    for i in 0..block_count {
+        let block = &data[(i * MAX_STORED_BLOCK_LEN)..
+            usize::min((i + 1) * MAX_STORED_BLOCK_LEN, data.len())];
+        let is_final = i + 1 == block_count;
+        out.push(if is_final { 1 } else { 0 });
+        let len = block.len() as u16;
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    return out;
+}
+
+/// Computes the CRC-32 (ISO 3309) checksum of `data`, as used by PNG.
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for byte in data {
+        crc = CRC32_TABLE[((crc ^ (*byte as u32)) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    return crc ^ 0xFFFF_FFFF;
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = match c & 1 {
+                1 => 0xEDB8_8320 ^ (c >> 1),
+                _ => c >> 1,
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    return table;
+}
+
+/// Computes the Adler-32 checksum of `data`, as used by zlib.
+pub fn adler32(data: &[u8]) -> u32 {
+    const MODULUS: u32 = 65521;
+    let (mut a, mut b): (u32, u32) = (1, 0);
+    // The sums cannot overflow within a chunk of this length before being reduced.
+    for chunk in data.chunks(5552) {
+        for byte in chunk {
+            a += *byte as u32;
+            b += a;
+        }
+        a %= MODULUS;
+        b %= MODULUS;
+    }
+    return (b << 16) | a;
+}
@@ -1,6 +1,25 @@
 use crate::elements::function::FunctionDefaultStyleClass;
 use crate::elements::axis::AxisDefaultStyleClass;
 use crate::elements::axis::TickDefaultStyleClass;
+use crate::elements::axis::ArrowheadDefaultStyleClass;
+use crate::elements::legend::LegendDefaultStyleClass;
+use crate::elements::line::LineDefaultStyleClass;
+use crate::elements::point::{PointDefaultStyleClass, OpenPointDefaultStyleClass};
+use crate::elements::region::{RegionDefaultStyleClass, RegionBoundaryDefaultStyleClass};
+use crate::elements::region::StrictRegionBoundaryDefaultStyleClass;
+use crate::elements::series::{SeriesDefaultStyleClass, MarkerDefaultStyleClass};
+use crate::elements::series::{ErrorBarDefaultStyleClass, ErrorBarCapDefaultStyleClass};
+use crate::elements::band::BandDefaultStyleClass;
+use crate::elements::bar_chart::BarDefaultStyleClass;
+use crate::elements::stems::StemDefaultStyleClass;
+use crate::elements::sequence::{SequenceDefaultStyleClass, CobwebDefaultStyleClass};
+use crate::elements::epsilon_delta::EpsilonDeltaBandDefaultStyleClass;
+use crate::elements::epsilon_delta::EpsilonDeltaGuideDefaultStyleClass;
+use crate::elements::geometry::GeometryMarkDefaultStyleClass;
+use crate::elements::domain_coloring::DomainColoringDefaultStyleClass;
+use crate::elements::contour::{ContourDefaultStyleClass, ContourBandDefaultStyleClass};
+use crate::elements::number_line::NumberLineIntervalDefaultStyleClass;
+use crate::elements::inset::{InsetDefaultStyleClass, InsetFrameDefaultStyleClass};
 
 /// By default, Yapl includes a minimal CSS stylesheet with sensible defaults in every SVG.
 /// However, rules in this stylesheet can be made redundant through injection of custom styles.
@@ -56,22 +75,100 @@ use crate::elements::axis::TickDefaultStyleClass;
 /// default style classes is closed.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct DefaultGlobalStyleClasses {
-    pub function: FunctionDefaultStyleClass,
-    pub axis:     AxisDefaultStyleClass,
-    pub tick:     TickDefaultStyleClass
+    pub function:               FunctionDefaultStyleClass,
+    pub axis:                   AxisDefaultStyleClass,
+    pub tick:                   TickDefaultStyleClass,
+    pub arrowhead:              ArrowheadDefaultStyleClass,
+    pub legend:                 LegendDefaultStyleClass,
+    pub line:                   LineDefaultStyleClass,
+    pub point:                  PointDefaultStyleClass,
+    pub open_point:             OpenPointDefaultStyleClass,
+    pub region:                 RegionDefaultStyleClass,
+    pub region_boundary:        RegionBoundaryDefaultStyleClass,
+    pub strict_region_boundary: StrictRegionBoundaryDefaultStyleClass,
+    pub series:                 SeriesDefaultStyleClass,
+    pub marker:                 MarkerDefaultStyleClass,
+    pub error_bar:              ErrorBarDefaultStyleClass,
+    pub error_bar_cap:          ErrorBarCapDefaultStyleClass,
+    pub band:                   BandDefaultStyleClass,
+    pub bar:                    BarDefaultStyleClass,
+    pub stem:                   StemDefaultStyleClass,
+    pub sequence:               SequenceDefaultStyleClass,
+    pub cobweb:                 CobwebDefaultStyleClass,
+    pub epsilon_delta_band:     EpsilonDeltaBandDefaultStyleClass,
+    pub epsilon_delta_guide:    EpsilonDeltaGuideDefaultStyleClass,
+    pub geometry_mark:          GeometryMarkDefaultStyleClass,
+    pub domain_coloring:        DomainColoringDefaultStyleClass,
+    pub contour:                ContourDefaultStyleClass,
+    pub contour_band:           ContourBandDefaultStyleClass,
+    pub number_line_interval:   NumberLineIntervalDefaultStyleClass,
+    pub inset:                  InsetDefaultStyleClass,
+    pub inset_frame:            InsetFrameDefaultStyleClass
 }
 
 impl DefaultGlobalStyleClasses {
     pub const ENABLED: Self = Self {
-        function: FunctionDefaultStyleClass::ENABLED,
-        axis:     AxisDefaultStyleClass::    ENABLED,
-        tick:     TickDefaultStyleClass::    ENABLED,
+        function:               FunctionDefaultStyleClass::            ENABLED,
+        axis:                   AxisDefaultStyleClass::                ENABLED,
+        tick:                   TickDefaultStyleClass::                ENABLED,
+        arrowhead:              ArrowheadDefaultStyleClass::           ENABLED,
+        legend:                 LegendDefaultStyleClass::              ENABLED,
+        line:                   LineDefaultStyleClass::                ENABLED,
+        point:                  PointDefaultStyleClass::               ENABLED,
+        open_point:             OpenPointDefaultStyleClass::           ENABLED,
+        region:                 RegionDefaultStyleClass::              ENABLED,
+        region_boundary:        RegionBoundaryDefaultStyleClass::      ENABLED,
+        strict_region_boundary: StrictRegionBoundaryDefaultStyleClass::ENABLED,
+        series:                 SeriesDefaultStyleClass::              ENABLED,
+        marker:                 MarkerDefaultStyleClass::              ENABLED,
+        error_bar:              ErrorBarDefaultStyleClass::            ENABLED,
+        error_bar_cap:          ErrorBarCapDefaultStyleClass::         ENABLED,
+        band:                   BandDefaultStyleClass::                ENABLED,
+        bar:                    BarDefaultStyleClass::                 ENABLED,
+        stem:                   StemDefaultStyleClass::                ENABLED,
+        sequence:               SequenceDefaultStyleClass::            ENABLED,
+        cobweb:                 CobwebDefaultStyleClass::              ENABLED,
+        epsilon_delta_band:     EpsilonDeltaBandDefaultStyleClass::    ENABLED,
+        epsilon_delta_guide:    EpsilonDeltaGuideDefaultStyleClass::   ENABLED,
+        geometry_mark:          GeometryMarkDefaultStyleClass::        ENABLED,
+        domain_coloring:        DomainColoringDefaultStyleClass::      ENABLED,
+        contour:                ContourDefaultStyleClass::             ENABLED,
+        contour_band:           ContourBandDefaultStyleClass::         ENABLED,
+        number_line_interval:   NumberLineIntervalDefaultStyleClass::  ENABLED,
+        inset:                  InsetDefaultStyleClass::               ENABLED,
+        inset_frame:            InsetFrameDefaultStyleClass::          ENABLED,
     };
 
     pub const DISABLED: Self = Self {
-        function: FunctionDefaultStyleClass::DISABLED,
-        axis:     AxisDefaultStyleClass::    DISABLED,
-        tick:     TickDefaultStyleClass::    DISABLED,
+        function:               FunctionDefaultStyleClass::            DISABLED,
+        axis:                   AxisDefaultStyleClass::                DISABLED,
+        tick:                   TickDefaultStyleClass::                DISABLED,
+        arrowhead:              ArrowheadDefaultStyleClass::           DISABLED,
+        legend:                 LegendDefaultStyleClass::              DISABLED,
+        line:                   LineDefaultStyleClass::                DISABLED,
+        point:                  PointDefaultStyleClass::               DISABLED,
+        open_point:             OpenPointDefaultStyleClass::           DISABLED,
+        region:                 RegionDefaultStyleClass::              DISABLED,
+        region_boundary:        RegionBoundaryDefaultStyleClass::      DISABLED,
+        strict_region_boundary: StrictRegionBoundaryDefaultStyleClass::DISABLED,
+        series:                 SeriesDefaultStyleClass::              DISABLED,
+        marker:                 MarkerDefaultStyleClass::              DISABLED,
+        error_bar:              ErrorBarDefaultStyleClass::            DISABLED,
+        error_bar_cap:          ErrorBarCapDefaultStyleClass::         DISABLED,
+        band:                   BandDefaultStyleClass::                DISABLED,
+        bar:                    BarDefaultStyleClass::                 DISABLED,
+        stem:                   StemDefaultStyleClass::                DISABLED,
+        sequence:               SequenceDefaultStyleClass::            DISABLED,
+        cobweb:                 CobwebDefaultStyleClass::              DISABLED,
+        epsilon_delta_band:     EpsilonDeltaBandDefaultStyleClass::    DISABLED,
+        epsilon_delta_guide:    EpsilonDeltaGuideDefaultStyleClass::   DISABLED,
+        geometry_mark:          GeometryMarkDefaultStyleClass::        DISABLED,
+        domain_coloring:        DomainColoringDefaultStyleClass::      DISABLED,
+        contour:                ContourDefaultStyleClass::             DISABLED,
+        contour_band:           ContourBandDefaultStyleClass::         DISABLED,
+        number_line_interval:   NumberLineIntervalDefaultStyleClass::  DISABLED,
+        inset:                  InsetDefaultStyleClass::               DISABLED,
+        inset_frame:            InsetFrameDefaultStyleClass::          DISABLED,
     };
 }
 
@@ -0,0 +1,97 @@
+mod common;
+
+use std::f64::consts::PI;
+use yapl::analysis::{analyze, intersections, FeatureKind};
+use yapl::elements::{CoordinatePlane, Function};
+use yapl::math::{ClosedInterval, NonDecreasing};
+use common::assert_close;
+
+const TOLERANCE: f64 = 1e-6;
+
+#[test]
+fn test_analyze_sinx() {
+    let mut cplane = CoordinatePlane::new_elementary();
+    cplane.extent.brect.x = ClosedInterval::new(NonDecreasing::new(-2.0 * PI - 1.0, 2.0 * PI + 1.0));
+    cplane.extent.brect.y = ClosedInterval::new(NonDecreasing::new(-1.5, 1.5));
+    let f = Function::new_elementary(|x| x.sin());
+    let analysis = analyze(&f, &cplane.extent);
+
+    let zeros: Vec<f64> = analysis.zeros.iter().map(|z| z.input).collect();
+    assert_eq!(zeros.len(), 5);
+    for (zero, k) in zeros.iter().zip(-2..=2) {
+        assert_close(*zero, (k as f64) * PI, TOLERANCE);
+    }
+
+    assert_eq!(analysis.extrema.len(), 4);
+    for extremum in &analysis.extrema {
+        let expected = match extremum.kind {
+            FeatureKind::Maximum => 1.0,
+            FeatureKind::Minimum => -1.0,
+            _ => unreachable!()
+        };
+        assert_close(extremum.pos.y, expected, TOLERANCE);
+    }
+    
+    assert_eq!(analysis.inflections.len(), 5);
+}
+
+#[test]
+fn test_analyze_discontinuity() {
+    let cplane = CoordinatePlane::new_elementary();
+    let f = Function::new_elementary(|x| 1.0 / x);
+    let analysis = analyze(&f, &cplane.extent);
+    assert!(analysis.zeros.is_empty());
+    assert!(analysis.extrema.is_empty());
+}
+
+#[test]
+fn test_analyze_touching_zero() {
+    let cplane = CoordinatePlane::new_elementary();
+    let f = Function::new_elementary(|x| (x - 1.0) * (x - 1.0));
+    let analysis = analyze(&f, &cplane.extent);
+    assert_eq!(analysis.zeros.len(), 1);
+    assert_close(analysis.zeros[0].input, 1.0, TOLERANCE);
+    assert_eq!(analysis.extrema.len(), 1);
+    assert_eq!(analysis.extrema[0].kind, FeatureKind::Minimum);
+    assert_eq!(analysis.extrema[0].label_tex, "(1, 0)");
+    assert!(analysis.inflections.is_empty());
+}
+
+#[test]
+fn test_analyze_linear() {
+    let cplane = CoordinatePlane::new_elementary();
+    let f = Function::new_elementary(|x| (0.5 * x) + 1.0);
+    let analysis = analyze(&f, &cplane.extent);
+    assert_eq!(analysis.zeros.len(), 1);
+    assert_close(analysis.zeros[0].input, -2.0, TOLERANCE);
+    assert!(analysis.extrema.is_empty());
+    assert!(analysis.inflections.is_empty());
+}
+
+#[test]
+fn test_intersections() {
+    let cplane = CoordinatePlane::new_elementary();
+    let f = Function::new_elementary(|x| x * x - 2.0);
+    let g = Function::new_elementary(|x| x);
+    let points = intersections(&f, &g, &cplane.extent);
+    assert_eq!(points.len(), 2);
+    assert_close(points[0].pos.x, -1.0, TOLERANCE);
+    assert_close(points[1].pos.x, 2.0, TOLERANCE);
+    assert_eq!(points[1].label_tex, "(2, 2)");
+}
+
+#[test]
+fn test_intersections_discontinuity() {
+    let cplane = CoordinatePlane::new_elementary();
+    let f = Function::new_elementary(|x| 1.0 / x);
+    let g = Function::new_elementary(|x| -1.0 / x);
+    assert!(intersections(&f, &g, &cplane.extent).is_empty());
+
+    // The graph of tan(x) crosses the line once on each of the visible branches, but not
+    // across the asymptotes where tan(x) merely changes sign.
+    let f = Function::new_elementary(|x| x.tan());
+    let g = Function::new_elementary(|x| x / 10.0);
+    let points = intersections(&f, &g, &cplane.extent);
+    assert_eq!(points.len(), 3);
+    assert_close(points[1].pos.x, 0.0, TOLERANCE);
+}
@@ -0,0 +1,146 @@
+mod common;
+
+use yapl::elements::{Arrowhead, ArrowheadEnds, Axis, AxisPosition, CoordinatePlane, TickLabel};
+use yapl::elements::axis::{Tick, TickDirection};
+use yapl::math::{ClosedInterval, NonDecreasing};
+use common::{assert_close, attribute, compile, numbers, tags};
+
+#[test]
+fn test_arrowhead_markers() {
+    let mut cplane = CoordinatePlane::new_elementary();
+    cplane.horizontal_axis.as_mut().unwrap().arrowhead =
+        Some(Arrowhead::new_default(ArrowheadEnds::Positive));
+    cplane.vertical_axis.as_mut().unwrap().arrowhead =
+        Some(Arrowhead::new_default(ArrowheadEnds::Both));
+    let svg = compile(&cplane);
+
+    // One marker per axis, each referenced by the line of its own axis.
+    let markers: Vec<&str> = tags(&svg, "marker").iter()
+        .map(|tag| attribute(tag, "id").unwrap())
+        .collect();
+    assert_eq!(markers.len(), 2);
+    assert_ne!(markers[0], markers[1]);
+    let axes: Vec<&str> = tags(&svg, "line").into_iter()
+        .filter(|tag| attribute(tag, "class") == Some(" yapl-def-axis"))
+        .collect();
+    assert_eq!(axes.len(), 2);
+    let url = |id: &str| format!("url(#{})", id);
+    // The horizontal axis is capped at its positive end only, which is where the line stops.
+    assert_eq!(attribute(axes[0], "marker-start"), None);
+    assert_eq!(attribute(axes[0], "marker-end"), Some(url(markers[0]).as_str()));
+    assert_eq!(attribute(axes[1], "marker-start"), Some(url(markers[1]).as_str()));
+    assert_eq!(attribute(axes[1], "marker-end"), Some(url(markers[1]).as_str()));
+    assert_eq!(svg.matches(".yapl-def-arrowhead {").count(), 1);
+}
+
+#[test]
+fn test_negative_arrowhead() {
+    let mut cplane = CoordinatePlane::new_elementary();
+    cplane.vertical_axis = None;
+    cplane.horizontal_axis.as_mut().unwrap().arrowhead =
+        Some(Arrowhead::new_default(ArrowheadEnds::Negative));
+    let svg = compile(&cplane);
+    let axis = tags(&svg, "line")[0];
+    assert!(attribute(axis, "marker-start").is_some());
+    assert!(attribute(axis, "marker-end").is_none());
+}
+
+#[test]
+fn test_arrowhead_default_style_class() {
+    let mut custom = Arrowhead::new_default(ArrowheadEnds::Both);
+    custom.apply_default_style_class = false;
+    custom.style_class = Some("my-arrowhead");
+
+    let mut cplane = CoordinatePlane::new_elementary();
+    cplane.horizontal_axis.as_mut().unwrap().arrowhead = Some(custom);
+    cplane.vertical_axis.as_mut().unwrap().arrowhead = Some(custom);
+    let svg = compile(&cplane);
+    assert_eq!(svg.matches("<marker ").count(), 2);
+    assert_eq!(svg.matches("class=\" my-arrowhead\"").count(), 2);
+    assert!(!svg.contains("yapl-def-arrowhead"));
+
+    // A single arrowhead using the default class is enough for the class to be emitted.
+    cplane.vertical_axis.as_mut().unwrap().arrowhead =
+        Some(Arrowhead::new_default(ArrowheadEnds::Positive));
+    let svg = compile(&cplane);
+    assert_eq!(svg.matches("class=\" yapl-def-arrowhead\"").count(), 1);
+    assert_eq!(svg.matches(".yapl-def-arrowhead {").count(), 1);
+}
+
+#[test]
+fn test_axis_position_resolve() {
+    let range = ClosedInterval::new(NonDecreasing::new(-2.0, 3.0));
+    assert_eq!(AxisPosition::AtValue(1.0).resolve(range), 1.0);
+    // A value outside of the range is not clamped, the axis is simply not visible.
+    assert_eq!(AxisPosition::AtValue(7.0).resolve(range), 7.0);
+    assert_eq!(AxisPosition::AtMin.resolve(range), -2.0);
+    assert_eq!(AxisPosition::AtMax.resolve(range), 3.0);
+    assert!(AxisPosition::AtMin.is_frame() && AxisPosition::AtMax.is_frame());
+    assert!(!AxisPosition::AtValue(0.0).is_frame());
+}
+
+#[test]
+fn test_tick_extents() {
+    let mut tick = Tick::new_default();
+    tick.len = 0.02;
+    tick.direction = TickDirection::Inward;
+    assert_eq!(tick.extents(), (0.0, 0.02));
+    tick.direction = TickDirection::Outward;
+    assert_eq!(tick.extents(), (0.02, 0.0));
+    tick.direction = TickDirection::Centered;
+    assert_eq!(tick.extents(), (0.01, 0.01));
+}
+
+/// Parses the `viewBox` of the root element of the given SVG.
+fn viewbox(svg: &str) -> Vec<f64> {
+    return numbers(attribute(tags(svg, "svg")[0], "viewBox").unwrap());
+}
+
+#[test]
+fn test_frame_margins() {
+    // The axes of the default plane cross the interior, so the viewBox is the bare extent.
+    let svg = compile(&CoordinatePlane::new_elementary());
+    assert!(svg.starts_with("<svg viewBox=\"0 0 1 1\""));
+
+    // The boxed frame reserves room for the labels of its primary axes along the left and
+    // bottom edges. Its ticks point inward, so they need no room of their own.
+    let svg = compile(&CoordinatePlane::new_boxed());
+    let label_height = TickLabel::DEFAULT_TYPOGRAPHY_HEIGHT;
+    let left = TickLabel::DEFAULT_FRAME_WIDTH;
+    let top = 0.5 * label_height;
+    let bottom = (0.5 * Tick::DEFAULT_LEN) + label_height;
+    let expected = [-left, -top, 1.0 + left, 1.0 + top + bottom];
+    for (actual, expected) in viewbox(&svg).into_iter().zip(expected) {
+        assert_close(actual, expected, 1e-12);
+    }
+}
+
+/// Computes the bounds of the tick labels of the given plane along the horizontal and vertical
+/// dimensions of the viewport, respectively.
+fn tick_label_bounds(cplane: &CoordinatePlane) -> ((f64, f64), (f64, f64)) {
+    let svg = compile(cplane);
+    let (mut x, mut y) = ((f64::INFINITY, f64::NEG_INFINITY), (f64::INFINITY, f64::NEG_INFINITY));
+    for tag in tags(&svg, "svg").into_iter().filter(|tag| attribute(tag, "x").is_some()) {
+        // Labels to the right of a vertical axis extend as far as needed and omit their width.
+        let number = |name: &str| attribute(tag, name).map_or(0.0, |s| s.parse::<f64>().unwrap());
+        x = (f64::min(x.0, number("x")), f64::max(x.1, number("x") + number("width")));
+        y = (f64::min(y.0, number("y")), f64::max(y.1, number("y") + number("height")));
+    }
+    return (x, y);
+}
+
+#[test]
+fn test_tick_label_sides() {
+    let mut cplane = CoordinatePlane::new_minimal();
+    cplane.horizontal_axis = Some(Axis::new_positioned(0.0, 1.0, AxisPosition::AtMin));
+    assert!(tick_label_bounds(&cplane).1.0 >= 1.0);
+    // Along the top edge the labels flip above the axis line.
+    cplane.horizontal_axis = Some(Axis::new_positioned(0.0, 1.0, AxisPosition::AtMax));
+    assert!(tick_label_bounds(&cplane).1.1 <= 0.0);
+
+    cplane.horizontal_axis = None;
+    cplane.vertical_axis = Some(Axis::new_positioned(0.0, 1.0, AxisPosition::AtMin));
+    assert!(tick_label_bounds(&cplane).0.1 <= 0.0);
+    cplane.vertical_axis = Some(Axis::new_positioned(0.0, 1.0, AxisPosition::AtMax));
+    assert!(tick_label_bounds(&cplane).0.0 >= 1.0);
+}
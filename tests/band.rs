@@ -0,0 +1,64 @@
+mod common;
+
+use yapl::elements::{Band, CoordinatePlane, ErrorBars, Function, Series, Uncertainty};
+use yapl::math::{NonDecreasing, Vec2D};
+use common::area;
+
+#[test]
+fn test_band_between_functions() {
+    let cplane = CoordinatePlane::new_elementary();
+    let band = Band::new_default(Function::new_elementary(|x| x - 1.0),
+        Function::new_elementary(|x| x + 1.0));
+    // The band is clamped to the extent, which cuts away a triangle at each end.
+    let expected = 20.0 - 0.5 - 0.5;
+    assert!((area(&band.polygons(&cplane.extent)) - expected).abs() < 1e-3);
+}
+
+#[test]
+fn test_band_under_curve_with_domain() {
+    let cplane = CoordinatePlane::new_elementary();
+    let mut band = Band::new_under(Function::new_elementary(|x| x * x));
+    band.domain = Some(NonDecreasing::new(0.0, 2.0));
+    assert!((area(&band.polygons(&cplane.extent)) - (8.0 / 3.0)).abs() < 1e-3);
+}
+
+#[test]
+fn test_band_breaks_where_undefined() {
+    let cplane = CoordinatePlane::new_elementary();
+    let band = Band::new_under(Function::new_elementary(|x: f64| x.sqrt()));
+    let polygons = band.polygons(&cplane.extent);
+    assert_eq!(polygons.len(), 1);
+    assert!(polygons[0].iter().all(|p| p.x >= 0.0));
+}
+
+#[test]
+fn test_band_between_series() {
+    let cplane = CoordinatePlane::new_elementary();
+    let lower = Series::new_polyline(vec![(0.0, 0.0), (1.0, 0.0), (2.0, f64::NAN), (3.0, 0.0),
+        (4.0, 0.0)]);
+    let upper = Series::new_polyline(vec![(0.0, 1.0), (4.0, 1.0)]);
+    let band = Band::new_series(&lower, &upper);
+    let polygons = band.polygons(&cplane.extent);
+    assert_eq!(polygons.len(), 2);
+    assert!((area(&polygons) - 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_error_bars() {
+    let cplane = CoordinatePlane::new_elementary();
+    let samples = [(0.0, 0.0), (1.0, 4.0), (f64::NAN, 1.0)];
+    let mut error_bars = ErrorBars::new_symmetric_y(&[0.5, 2.0, 1.0]);
+    error_bars.x = Some(vec![Uncertainty::Asymmetric(1.0, 2.0); 3]);
+    let bars = error_bars.bars(&samples, &cplane.extent.brect);
+    assert_eq!(bars.len(), 4);
+
+    assert!(bars[0].is_horizontal);
+    assert_eq!((bars[0].a, bars[0].b), (Vec2D { x: -1.0, y: 0.0 }, Vec2D { x: 2.0, y: 0.0 }));
+    assert!(!bars[1].is_horizontal);
+    assert_eq!((bars[1].a, bars[1].b), (Vec2D { x: 0.0, y: -0.5 }, Vec2D { x: 0.0, y: 0.5 }));
+    assert_eq!(bars[1].caps, [true, true]);
+
+    // The upper end of the vertical bar of the second sample is clipped, and therefore uncapped.
+    assert_eq!(bars[3].b, Vec2D { x: 1.0, y: 5.0 });
+    assert_eq!(bars[3].caps, [true, false]);
+}
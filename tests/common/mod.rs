@@ -0,0 +1,88 @@
+#![allow(dead_code)]
+
+use yapl::codegen::{codegen, codegen_figure, codegen_number_line};
+use yapl::elements::{CoordinatePlane, Figure, NumberLine};
+use yapl::math::{Complex, Vec2D};
+use yapl::style::Stylesheet;
+use yapl::typography::NullTeXRenderer;
+
+/// Compiles the given plane with the default stylesheet. TeX is rendered as empty `<svg>`
+/// elements, so that only the geometry of the labels remains.
+pub fn compile(cplane: &CoordinatePlane) -> String {
+    let mut svg: Vec<u8> = Vec::new();
+    codegen(&mut svg, cplane, Stylesheet::new_default(), &mut NullTeXRenderer).unwrap();
+    return String::from_utf8(svg).unwrap();
+}
+
+/// Compiles the given figure with the default stylesheet, rendering TeX as in [`compile`].
+pub fn compile_figure(figure: &Figure) -> String {
+    let mut svg: Vec<u8> = Vec::new();
+    codegen_figure(&mut svg, figure, Stylesheet::new_default(), &mut NullTeXRenderer).unwrap();
+    return String::from_utf8(svg).unwrap();
+}
+
+/// Compiles the given number line with the default stylesheet, rendering TeX as in [`compile`].
+pub fn compile_number_line(line: &NumberLine) -> String {
+    let mut svg: Vec<u8> = Vec::new();
+    codegen_number_line(&mut svg, line, Stylesheet::new_default(), &mut NullTeXRenderer).unwrap();
+    return String::from_utf8(svg).unwrap();
+}
+
+/// Collects the opening tags of the elements with the given name, in document order.
+pub fn tags<'a>(svg: &'a str, name: &str) -> Vec<&'a str> {
+    let prefix = format!("<{} ", name);
+    return svg.match_indices(&prefix)
+        .map(|(i, _)| &svg[i..i + svg[i..].find('>').unwrap()])
+        .collect();
+}
+
+/// Extracts the value of the given attribute of a tag.
+pub fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let prefix = format!(" {}=\"", name);
+    let begin = tag.find(&prefix)? + prefix.len();
+    return Some(&tag[begin..begin + tag[begin..].find('"').unwrap()]);
+}
+
+/// Parses the numbers of a space-delimited attribute value, such as a `viewBox` or the data of
+/// a path, skipping any commands.
+pub fn numbers(value: &str) -> Vec<f64> {
+    return value.split(' ').filter_map(|s| s.parse::<f64>().ok()).collect();
+}
+
+/// Computes the total area of the given simple polygons by the shoelace formula.
+pub fn area(polygons: &[Vec<Vec2D>]) -> f64 {
+    let mut area = 0.0;
+    for polygon in polygons {
+        let mut signed = 0.0;
+        for (i, a) in polygon.iter().enumerate() {
+            let b = polygon[(i + 1) % polygon.len()];
+            signed += (a.x * b.y) - (b.x * a.y);
+        }
+        area += (signed / 2.0).abs();
+    }
+    return area;
+}
+
+/// A value which may be compared with another of its kind up to a tolerance.
+pub trait Approx: std::fmt::Debug + Copy {
+    /// Computes the distance between this value and `other`.
+    fn distance(self, other: Self) -> f64;
+}
+
+impl Approx for f64 {
+    fn distance(self, other: Self) -> f64 { (self - other).abs() }
+}
+
+impl Approx for Vec2D {
+    fn distance(self, other: Self) -> f64 { (self - other).len() }
+}
+
+impl Approx for Complex {
+    fn distance(self, other: Self) -> f64 { (self - other).modulus() }
+}
+
+/// Asserts that `actual` lies within `tolerance` of `expected`.
+pub fn assert_close<T: Approx>(actual: T, expected: T, tolerance: f64) {
+    assert!(actual.distance(expected) < tolerance, "expected {:?} but found {:?}", expected,
+        actual);
+}
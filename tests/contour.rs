@@ -0,0 +1,105 @@
+mod common;
+
+use yapl::elements::{Contour, ContourLevels, CoordinatePlane};
+use yapl::math;
+use common::{area, assert_close, compile};
+
+#[test]
+fn test_nice_levels() {
+    assert_eq!(math::nice_step(20.0, 10), 2.0);
+    assert_eq!(math::nice_step(1.0, 4), 0.2);
+    assert_eq!(math::nice_step(7.0, 1), 5.0);
+    assert_eq!(math::nice_step(0.9, 1), 1.0);
+    assert_eq!(math::multiples_within(0.1, 0.25, 0.6), vec![0.3, 0.4, 0.5, 0.6]);
+    assert_eq!(math::multiples_within(5.0, -7.0, 7.0), vec![-5.0, 0.0, 5.0]);
+
+    let cplane = CoordinatePlane::new_elementary();
+    let contour = Contour::new_default(|x, y| x + y);
+    assert_eq!(contour.levels(&cplane.extent),
+        vec![-8.0, -6.0, -4.0, -2.0, 0.0, 2.0, 4.0, 6.0, 8.0]);
+    let explicit = Contour::new_levels(|x, y| x * y, ContourLevels::Explicit(vec![1.0, -1.0]));
+    assert_eq!(explicit.levels(&cplane.extent), vec![-1.0, 1.0]);
+}
+
+#[test]
+fn test_level_curves() {
+    let cplane = CoordinatePlane::new_elementary();
+    let contour = Contour::new_levels(|x, y| (x * x) + (y * y),
+        ContourLevels::Explicit(vec![4.0, 16.0]));
+    let trace = contour.trace(&cplane.extent);
+    assert_eq!(trace.lines.len(), 2);
+    // Each circle is traced as a single closed polyline.
+    for (polylines, radius) in trace.lines.iter().zip([2.0, 4.0]) {
+        assert_eq!(polylines.len(), 1);
+        let polyline = &polylines[0];
+        assert_eq!(polyline.first(), polyline.last());
+        for p in polyline { assert_close(f64::hypot(p.x, p.y), radius, 0.01); }
+    }
+    assert!(trace.bands.is_empty());
+    assert!(trace.labels.is_empty());
+}
+
+#[test]
+fn test_filled_bands() {
+    let cplane = CoordinatePlane::new_elementary();
+    let mut contour = Contour::new_levels(|x, y| (x * x) + (y * y),
+        ContourLevels::Explicit(vec![4.0]));
+    contour.filled = true;
+    let trace = contour.trace(&cplane.extent);
+    assert_eq!(trace.bands.len(), 2);
+    let (inside, outside) = (area(&trace.bands[0]), area(&trace.bands[1]));
+    // The circle is approximated by an inscribed polygon, which is slightly smaller.
+    assert_close(inside, 4.0 * std::f64::consts::PI, 0.05);
+    assert_close(inside + outside, 100.0, 1e-9);
+}
+
+#[test]
+fn test_undefined_cells_are_blank() {
+    let cplane = CoordinatePlane::new_elementary();
+    let mut contour = Contour::new_levels(|x, _| x.sqrt(), ContourLevels::Explicit(vec![1.0]));
+    contour.filled = true;
+    let trace = contour.trace(&cplane.extent);
+    let total: f64 = trace.bands.iter().map(|band| area(band)).sum();
+    assert_close(total, 50.0, 1e-9);
+    assert!(trace.lines[0].iter().flatten().all(|p| p.x > 0.0));
+}
+
+#[test]
+fn test_labels_break_level_curves() {
+    let cplane = CoordinatePlane::new_elementary();
+    let mut contour = Contour::new_levels(|x, y| (x * x) + (y * y),
+        ContourLevels::Explicit(vec![0.001, 16.0]));
+    contour.labeled = true;
+    let trace = contour.trace(&cplane.extent);
+    // The tiny curve about the origin is too short to be labeled.
+    assert_eq!(trace.labels.len(), 1);
+    let (level, label) = &trace.labels[0];
+    assert_eq!(*level, 1);
+    assert_eq!(label.tex, "16");
+    assert_close(f64::hypot(label.pos.x, label.pos.y), 4.0, 0.01);
+    // The circle is broken around the label, leaving a single open polyline.
+    let polylines = &trace.lines[1];
+    assert_eq!(polylines.len(), 2);
+    let gap = 0.5 * contour.label_width;
+    let center = cplane.extent.transform().apply(label.pos);
+    for p in polylines.iter().flatten() {
+        let q = cplane.extent.transform().apply(*p);
+        assert!(f64::hypot(q.x - center.x, q.y - center.y) >= gap);
+    }
+}
+
+#[test]
+fn test_contour_codegen() {
+    let mut cplane = CoordinatePlane::new_minimal();
+    let mut contour = Contour::new_levels(|x, y| (x * x) + (y * y),
+        ContourLevels::Explicit(vec![1.0, 4.0, 9.0]));
+    contour.filled = true;
+    contour.labeled = true;
+    cplane.contours.push(contour);
+    let svg = compile(&cplane);
+    // One label per level, and one graded opacity per band, including the band above the
+    // highest level.
+    assert_eq!(svg.matches("<svg x=").count(), 3);
+    assert_eq!(svg.matches("fill-opacity").count(), 4);
+    assert!(svg.contains(".yapl-def-contour-band.yapl-contour-band-3 {"));
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use yapl::elements::{Axis, AxisPosition, CoordinatePlane, CurveLabel, CurveLabelAnchor, Function};
+use yapl::elements::axis::TickDirection;
+use yapl::math::{Interval, NonDecreasing, Vec2D};
+use common::{assert_close, attribute, compile, tags};
+
+/// Compiles a plane without axes over [-5, 5]² plotting the given function with a label, and
+/// returns the center of the label, if any.
+fn label_center(function: Function<'static>, anchor: CurveLabelAnchor) -> Option<Vec2D> {
+    return label_center_on(CoordinatePlane::new_minimal(), function, anchor);
+}
+
+/// Like [`label_center`], but plots the function on the given plane, which must not have tick
+/// labels.
+fn label_center_on(mut cplane: CoordinatePlane<'static>, mut function: Function<'static>,
+    anchor: CurveLabelAnchor)
+-> Option<Vec2D>
+{
+    function.label = Some(CurveLabel::new_default("f", anchor));
+    cplane.fns.push(function);
+    let svg = compile(&cplane);
+
+    // The root element has no position, only the label does.
+    let labels: Vec<&str> = tags(&svg, "svg").into_iter()
+        .filter(|tag| attribute(tag, "x").is_some())
+        .collect();
+    assert!(labels.len() <= 1);
+    let tag = labels.first()?;
+    let number = |name: &str| attribute(tag, name).unwrap().parse::<f64>().unwrap();
+    return Some(Vec2D {
+        x: number("x") + (0.5 * number("width")),
+        y: number("y") + (0.5 * number("height"))
+    });
+}
+
+/// Computes the center of a label anchored at `point` of a path heading in `direction`, placed
+/// on the side of the path which faces upward in the viewport, or downward if `flipped`.
+fn expected_center(point: Vec2D, direction: Vec2D, flipped: bool) -> Vec2D {
+    let len = f64::hypot(direction.x, direction.y);
+    let (mut x, mut y) = (direction.y / len, -direction.x / len);
+    if y > 0.0 || flipped { (x, y) = (-x, -y); }
+    let support = 0.5 * ((x.abs() * CurveLabel::DEFAULT_WIDTH)
+        + (y.abs() * CurveLabel::DEFAULT_TYPOGRAPHY_HEIGHT));
+    let distance = CurveLabel::DEFAULT_OFFSET + support;
+    return Vec2D { x: point.x + (x * distance), y: point.y + (y * distance) };
+}
+
+#[test]
+fn test_curve_label_at() {
+    // The graph of f(x) = x passes through (0.7, 0.3) in the normalized coordinate system at
+    // x = 2, and the label sits above and to the left of it.
+    let center = label_center(Function::new_elementary(|x| x), CurveLabelAnchor::At(2.0));
+    let expected = expected_center(Vec2D { x: 0.7, y: 0.3 }, Vec2D { x: 1.0, y: -1.0 }, false);
+    assert_close(center.unwrap(), expected, 1e-9);
+
+    // The anchor is interpolated along the sampled path between adjacent samples, and the
+    // direction of the path there is that of the chord rather than the tangent.
+    let f = Function::new_elementary(|x| (x * x / 10.0) - 2.0);
+    let center = label_center(f, CurveLabelAnchor::At(1.3)).unwrap();
+    let point = Vec2D { x: 0.63, y: 0.5 - ((1.3 * 1.3 / 10.0) - 2.0) / 10.0 };
+    let expected = expected_center(point, Vec2D { x: 1.0, y: -0.26 }, false);
+    assert_close(center, expected, 1e-2);
+
+    // Near the top edge there is no room above the path, so the label flips beneath it.
+    let f = Function::new_elementary(|x| 4.95 + (x / 100.0));
+    let center = label_center(f, CurveLabelAnchor::At(0.0));
+    let expected = expected_center(Vec2D { x: 0.5, y: 0.005 }, Vec2D { x: 1.0, y: -0.01 }, true);
+    assert_close(center.unwrap(), expected, 1e-9);
+
+    // Unless the frame reserves a margin above the extent, into which the label may extend.
+    let mut axis = Axis::new_positioned(0.0, 1.0, AxisPosition::AtMax);
+    axis.tick_label = None;
+    axis.tick.direction = TickDirection::Outward;
+    axis.tick.len = 0.05;
+    let mut cplane = CoordinatePlane::new_minimal();
+    cplane.secondary_horizontal_axis = Some(axis);
+    let f = Function::new_elementary(|x| 4.95 + (x / 100.0));
+    let center = label_center_on(cplane, f, CurveLabelAnchor::At(0.0));
+    let expected = expected_center(Vec2D { x: 0.5, y: 0.005 }, Vec2D { x: 1.0, y: -0.01 }, false);
+    assert_close(center.unwrap(), expected, 1e-9);
+}
+
+#[test]
+fn test_curve_label_auto() {
+    // By symmetry the midpoint of the visible arc of the parabola is its vertex.
+    let center = label_center(Function::new_elementary(|x| x * x), CurveLabelAnchor::Auto);
+    let expected = expected_center(Vec2D { x: 0.5, y: 0.5 }, Vec2D { x: 1.0, y: 0.0 }, false);
+    assert_close(center.unwrap(), expected, 1e-2);
+
+    // The hyperbola has two visible branches, and the label is anchored to one of them.
+    let center = label_center(Function::new_elementary(|x| 1.0 / x), CurveLabelAnchor::Auto);
+    let center = center.unwrap();
+    assert!(center.x > 0.0 && center.x < 1.0 && center.y > 0.0 && center.y < 1.0);
+    assert!((center.x - 0.5).abs() > 0.01);
+}
+
+#[test]
+fn test_curve_label_skipped() {
+    // Outside of the extent.
+    let center = label_center(Function::new_elementary(|x| x), CurveLabelAnchor::At(7.0));
+    assert_eq!(center, None);
+    // Outside of the domain.
+    let domain = Interval::closed(NonDecreasing::new(0.0, 5.0));
+    let sqrt = Function::new_restricted(domain, |x| x.sqrt());
+    assert_eq!(label_center(sqrt, CurveLabelAnchor::At(-2.0)), None);
+    // Where the function is undefined.
+    let sqrt = Function::new_elementary(|x| x.sqrt());
+    assert_eq!(label_center(sqrt, CurveLabelAnchor::At(-2.0)), None);
+    // Where the graph leaves the extent.
+    let square = Function::new_elementary(|x| x * x);
+    assert_eq!(label_center(square, CurveLabelAnchor::At(4.0)), None);
+    // Nowhere visible at all.
+    let nan = Function::new_elementary(|_| f64::NAN);
+    assert_eq!(label_center(nan, CurveLabelAnchor::Auto), None);
+    let above = Function::new_elementary(|_| 10.0);
+    assert_eq!(label_center(above, CurveLabelAnchor::Auto), None);
+}
@@ -0,0 +1,60 @@
+use yapl::data::{read_series, Column, Delimiter, Table};
+use yapl::elements::SeriesMode;
+
+#[test]
+fn test_read_csv_with_header() {
+    let csv = "# Measured decay\ntime,\"count, corrected\"\n0,100\n1,61.5\n\n2,\n3,NA\n";
+    let table = Table::read(csv.as_bytes(), Delimiter::Comma).unwrap();
+    assert_eq!(table.header, Some(vec!["time".to_string(), "count, corrected".to_string()]));
+    assert_eq!(table.len(), 4);
+    let counts = table.column(Column::Name("count, corrected")).unwrap();
+    assert_eq!(&counts[..2], &[100.0, 61.5]);
+    assert!(counts[2].is_nan() && counts[3].is_nan());
+    assert_eq!(table.column(Column::Index(0)).unwrap(), vec![0.0, 1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_read_headerless_whitespace() {
+    let text = "1 2 3\n4   5\n  7 8 9\n";
+    let table = Table::read(text.as_bytes(), Delimiter::Whitespace).unwrap();
+    assert!(table.header.is_none());
+    let third = table.column(Column::Index(2)).unwrap();
+    assert_eq!(third[0], 3.0);
+    assert!(third[1].is_nan());
+    assert_eq!(third[2], 9.0);
+}
+
+#[test]
+fn test_read_tsv_widening_rows() {
+    let tsv = "1\n2\t3\n";
+    let table = Table::read(tsv.as_bytes(), Delimiter::Tab).unwrap();
+    let second = table.column(Column::Index(1)).unwrap();
+    assert!(second[0].is_nan());
+    assert_eq!(second[1], 3.0);
+}
+
+#[test]
+fn test_read_invalid_cell() {
+    let csv = "x,y\n1,2\n3,abc\n";
+    let error = Table::read(csv.as_bytes(), Delimiter::Comma).err().unwrap();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("line 3"));
+}
+
+#[test]
+fn test_select_missing_column() {
+    let csv = "x,y\n1,2\n";
+    let table = Table::read(csv.as_bytes(), Delimiter::Comma).unwrap();
+    let error = table.column(Column::Name("z")).err().unwrap();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(table.column(Column::Index(2)).is_err());
+}
+
+#[test]
+fn test_read_series_breaks_at_missing_cells() {
+    let csv = "x,y\n0,0\n1,1\n2,\n3,1\n4,0\n";
+    let series = read_series(csv.as_bytes(), Delimiter::Comma, Column::Name("x"),
+        Column::Name("y"), SeriesMode::Polyline).unwrap();
+    let brect = yapl::elements::CoordinatePlane::new_elementary().extent.brect;
+    assert_eq!(series.polylines(&brect).len(), 2);
+}
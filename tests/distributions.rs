@@ -0,0 +1,123 @@
+mod common;
+
+use yapl::distributions::Distribution;
+use yapl::elements::{CoordinatePlane, LabelPlacement};
+use yapl::math::{self, Vec2D};
+use common::assert_close;
+
+#[test]
+fn test_special_functions() {
+    assert_close(math::ln_gamma(5.0), 24f64.ln(), 1e-12);
+    assert_close(math::ln_gamma(0.5), std::f64::consts::PI.sqrt().ln(), 1e-12);
+    assert_close(math::erf(1.0), 0.842_700_792_949_714_9, 1e-12);
+    assert_close(math::erf(-0.5), -0.520_499_877_813_046_5, 1e-12);
+    assert_close(math::regularized_gamma(1.0, 2.0), 1.0 - (-2f64).exp(), 1e-12);
+    assert_close(math::regularized_beta(2.0, 3.0, 0.4), 0.5248, 1e-12);
+    assert_close(math::regularized_beta(2.0, 3.0, 0.9), 0.9963, 1e-12);
+}
+
+#[test]
+fn test_normal_distribution() {
+    let normal = Distribution::standard_normal();
+    assert_close(normal.pdf(0.0), 1.0 / (2.0 * std::f64::consts::PI).sqrt(), 1e-15);
+    assert_close(normal.cdf(1.96), 0.975_002_104_851_780, 1e-12);
+    assert_close(normal.probability(-1.0, 1.0), 0.682_689_492_137_085_9, 1e-12);
+    assert_close(normal.probability(f64::NEG_INFINITY, f64::INFINITY), 1.0, 1e-15);
+
+    // The upper tail of a discrete distribution with infinite support.
+    let poisson = Distribution::poisson(3.0);
+    assert_close(poisson.probability(1.0, f64::INFINITY), 1.0 - (4.0 * (-3.0f64).exp()), 1e-12);
+    assert_eq!(poisson.probability_tex(1.0, f64::INFINITY, 4), "P(X > 1) = 0.8009");
+}
+
+#[test]
+fn test_continuous_distributions() {
+    // The t-distribution with one degree of freedom is the Cauchy distribution.
+    let cauchy = Distribution::student_t(1.0);
+    assert_close(cauchy.cdf(1.0), 0.75, 1e-12);
+    assert_close(cauchy.pdf(0.0), 1.0 / std::f64::consts::PI, 1e-12);
+    assert_close(Distribution::student_t(10.0).cdf(2.228_138_851_986_274), 0.975, 1e-9);
+
+    let chi_squared = Distribution::chi_squared(2.0);
+    assert_close(chi_squared.cdf(3.0), 1.0 - (-1.5f64).exp(), 1e-12);
+    assert_close(chi_squared.pdf(0.0), 0.5, 1e-15);
+    assert_close(Distribution::chi_squared(3.0).cdf(7.814_727_903_251_178), 0.95, 1e-9);
+
+    let uniform = Distribution::uniform(1.0, 3.0);
+    assert_eq!(uniform.pdf(2.0), 0.5);
+    assert_eq!(uniform.pdf(4.0), 0.0);
+    assert_eq!(uniform.probability(0.0, 2.5), 0.75);
+
+    let exponential = Distribution::exponential(2.0);
+    assert_close(exponential.cdf(1.0), 1.0 - (-2f64).exp(), 1e-15);
+    assert_eq!(exponential.pdf(-1.0), 0.0);
+    assert_eq!(exponential.mean(), 0.5);
+}
+
+#[test]
+fn test_discrete_distributions() {
+    let binomial = Distribution::binomial(4, 0.5);
+    assert_close(binomial.pdf(2.0), 6.0 / 16.0, 1e-12);
+    assert_eq!(binomial.pdf(1.5), 0.0);
+    assert_close(binomial.cdf(1.0), 5.0 / 16.0, 1e-12);
+    assert_close(binomial.cdf(1.5), 5.0 / 16.0, 1e-12);
+    assert_eq!(binomial.cdf(4.0), 1.0);
+    // The event 1 < X < 3 includes only the outcome 2.
+    assert_close(binomial.probability(1.0, 3.0), 6.0 / 16.0, 1e-12);
+    assert_close(binomial.probability(0.5, 3.5), 14.0 / 16.0, 1e-12);
+    assert_eq!(Distribution::binomial(3, 1.0).pdf(3.0), 1.0);
+
+    let poisson = Distribution::poisson(3.0);
+    assert_close(poisson.pdf(2.0), 4.5 * (-3f64).exp(), 1e-12);
+    assert_close(poisson.cdf(1.0), 4.0 * (-3f64).exp(), 1e-12);
+    let (first, last) = poisson.support();
+    assert_eq!(first, 0);
+    assert!(1.0 - poisson.cdf(last as f64) <= 1e-6);
+    assert!(1.0 - poisson.cdf((last - 1) as f64) > 1e-6);
+}
+
+#[test]
+fn test_stems_highlight() {
+    let binomial = Distribution::binomial(4, 0.5);
+    let mut stems = binomial.stems();
+    assert_eq!(stems.stems.len(), 5);
+    stems.highlight(1.0, 3.0, "shaded");
+    let classes: Vec<Option<&str>> = stems.stems.iter().map(|stem| stem.style_class).collect();
+    assert_eq!(classes, vec![None, None, Some("shaded"), None, None]);
+    let heads = stems.heads();
+    assert_eq!(heads[2].style_class, Some("shaded"));
+    assert_close(heads[2].pos.y, 6.0 / 16.0, 1e-12);
+
+    let cplane = CoordinatePlane::new_elementary();
+    let lines = stems.lines(&cplane.extent.brect);
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[0].1, Vec2D { x: 0.0, y: 0.0 });
+}
+
+#[test]
+fn test_cdf_steps() {
+    let steps = Distribution::binomial(2, 0.5).cdf_steps();
+    assert_eq!(steps.pieces.len(), 4);
+    assert_close((steps.pieces[2].function.eval)(1.5), 0.75, 1e-12);
+    // The jumps at 0, 1 and 2 are each marked by an open dot and a closed dot.
+    assert_eq!(steps.endpoints().len(), 6);
+}
+
+#[test]
+fn test_shade_and_annotation() {
+    let normal = Distribution::standard_normal();
+    let cplane = CoordinatePlane::new_elementary();
+    let band = normal.shade(-1.0, 1.0);
+    let polygons = band.polygons(&cplane.extent);
+    assert_eq!(polygons.len(), 1);
+    let xs: Vec<f64> = polygons[0].iter().map(|p| p.x).collect();
+    assert_eq!(xs.iter().copied().fold(f64::INFINITY, f64::min), -1.0);
+    assert_eq!(xs.iter().copied().fold(f64::NEG_INFINITY, f64::max), 1.0);
+
+    assert_eq!(normal.probability_tex(-1.0, 1.0, 4), "P(-1 < X < 1) = 0.6827");
+    assert_eq!(normal.probability_tex(f64::NEG_INFINITY, 1.5, 2), "P(X < 1.5) = 0.93");
+    let annotation = normal.annotation(1.0, f64::INFINITY);
+    assert_eq!(annotation.pos.x, 2.0);
+    assert_eq!(annotation.placement, LabelPlacement::Above);
+    assert_eq!(annotation.tex, "P(X > 1) = 0.1587");
+}
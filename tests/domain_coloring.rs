@@ -0,0 +1,91 @@
+mod common;
+
+use yapl::elements::{CoordinatePlane, DomainColoring};
+use yapl::elements::domain_coloring::domain_color;
+use yapl::math::Complex;
+use yapl::misc::base64_encode;
+use yapl::raster::{self, Raster};
+use common::assert_close;
+
+#[test]
+fn test_complex_arithmetic() {
+    let z = Complex::new(3.0, 4.0);
+    assert_eq!(z.modulus(), 5.0);
+    assert_eq!(z * z.conj(), Complex::from(25.0));
+    assert_close(z / z, Complex::from(1.0), 1e-15);
+    assert_close(Complex::I * Complex::I, Complex::from(-1.0), 1e-15);
+    assert_close((Complex::I * std::f64::consts::PI).exp(), Complex::from(-1.0), 1e-15);
+    assert_close(z.ln().exp(), z, 1e-14);
+    assert_close(z.sqrt() * z.sqrt(), z, 1e-14);
+    assert_close(z.powi(3), z * z * z, 1e-12);
+    assert_close(Complex::I.powc(Complex::I), Complex::from((-std::f64::consts::FRAC_PI_2).exp()),
+        1e-15);
+    let w = Complex::new(0.5, -1.5);
+    let (sin, cos) = (w.sin(), w.cos());
+    assert_close((sin * sin) + (cos * cos), Complex::from(1.0), 1e-14);
+}
+
+#[test]
+fn test_checksums_and_base64() {
+    assert_eq!(raster::crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(raster::adler32(b"Wikipedia"), 0x11E6_0398);
+    assert_eq!(base64_encode(b"Man"), "TWFu");
+    assert_eq!(base64_encode(b"Ma"), "TWE=");
+    assert_eq!(base64_encode(b"M"), "TQ==");
+    assert_eq!(base64_encode(b""), "");
+}
+
+#[test]
+fn test_png_encoding() {
+    let mut raster = Raster::new(2, 1);
+    raster.set(1, 0, [255, 0, 0]);
+    let png = raster.encode_png();
+    assert_eq!(&png[..8], &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]);
+    assert_eq!(&png[12..16], b"IHDR");
+    assert_eq!(&png[16..24], &[0, 0, 0, 2, 0, 0, 0, 1]);
+    // The IEND chunk is empty and its checksum is fixed.
+    assert_eq!(&png[png.len() - 12..], &[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60,
+        0x82]);
+    // The image data is a filter byte followed by the pixels, stored in a single block.
+    let scanline = [0, 0, 0, 0, 255, 0, 0];
+    let zlib = raster::zlib_stored(&scanline);
+    assert_eq!(&zlib[..7], &[0x78, 0x01, 0x01, 7, 0, !7, 0xFF]);
+    assert_eq!(&zlib[7..14], &scanline);
+    assert!(raster.to_data_uri().starts_with("data:image/png;base64,iVBORw0KGgo"));
+
+    // Data longer than a stored block is split across several blocks.
+    let zlib = raster::zlib_stored(&vec![0; 70000]);
+    assert_eq!(zlib.len(), 2 + (2 * 5) + 70000 + 4);
+    assert_eq!(zlib[2], 0);
+    assert_eq!(zlib[2 + 5 + 0xFFFF], 1);
+}
+
+#[test]
+fn test_domain_colors() {
+    assert_eq!(domain_color(Complex::from(0.0), false), [0, 0, 0]);
+    assert_eq!(domain_color(Complex::new(f64::INFINITY, 0.0), false), [255, 255, 255]);
+    assert_eq!(domain_color(Complex::new(f64::NAN, 0.0), false), [128, 128, 128]);
+    // Unit modulus has medium brightness, at which the hues are fully saturated.
+    assert_eq!(domain_color(Complex::from(1.0), false), [255, 0, 0]);
+    assert_eq!(domain_color(Complex::from(-1.0), false), [0, 255, 255]);
+    assert_eq!(domain_color(Complex::I, false), [128, 255, 0]);
+    let [r, g, b] = domain_color(Complex::from(2.0), false);
+    assert!(r == 255 && g == b && g > 0);
+}
+
+#[test]
+fn test_domain_coloring_raster() {
+    let mut cplane = CoordinatePlane::new_complex();
+    cplane.extent.y_scale = 0.5;
+    let mut coloring = DomainColoring::new_default(|z| z);
+    coloring.resolution = 10;
+    assert_eq!(coloring.dimensions(&cplane.extent), (10, 5));
+    let raster = coloring.rasterize(&cplane.extent);
+    assert_eq!(raster.pixels.len(), 50);
+    // The top right pixel lies in the first quadrant, and the bottom left pixel in the third.
+    let [r, g, _] = raster.get(9, 0);
+    assert!(r > 0 && g > 0);
+    let [_, g, b] = raster.get(0, 4);
+    assert!(b > 0 && g < b);
+    assert!(cplane.vertical_axis.unwrap().title.is_some());
+}
@@ -0,0 +1,52 @@
+use yapl::elements::{CoordinatePlane, EpsilonDelta, Function, LabelPlacement, PointFill};
+use yapl::math::Vec2D;
+
+fn figure<'a>() -> EpsilonDelta<'a> {
+    // The limit of (x^2 - 1) / (x - 1) as x approaches 1 is 2, but the function is undefined
+    // at 1.
+    let function = Function::new_elementary(|x| ((x * x) - 1.0) / (x - 1.0));
+    EpsilonDelta::new_default(function, 1.0, 2.0, 0.5, 0.25)
+}
+
+#[test]
+fn test_epsilon_delta_bands() {
+    let cplane = CoordinatePlane::new_elementary();
+    let [epsilon, delta] = figure().bands(&cplane.extent.brect);
+    let epsilon = epsilon.unwrap();
+    assert_eq!((epsilon.x.begin(), epsilon.x.end()), (-5.0, 5.0));
+    assert_eq!((epsilon.y.begin(), epsilon.y.end()), (1.5, 2.5));
+    let delta = delta.unwrap();
+    assert_eq!((delta.x.begin(), delta.x.end()), (0.75, 1.25));
+    assert_eq!((delta.y.begin(), delta.y.end()), (-5.0, 5.0));
+
+    let mut outside = figure();
+    outside.limit = 10.0;
+    assert!(outside.bands(&cplane.extent.brect)[0].is_none());
+}
+
+#[test]
+fn test_epsilon_delta_guides() {
+    let cplane = CoordinatePlane::new_elementary();
+    let guides = figure().guides(&cplane.extent.brect);
+    assert_eq!(guides, vec![vec![
+        Vec2D { x: 1.0, y: 0.0 }, Vec2D { x: 1.0, y: 2.0 }, Vec2D { x: 0.0, y: 2.0 }]]);
+}
+
+#[test]
+fn test_epsilon_delta_point() {
+    assert_eq!(figure().point().fill, PointFill::Open);
+    let attained = EpsilonDelta::new_default(Function::new_elementary(|x| 2.0 * x), 1.0, 2.0,
+        0.5, 0.25);
+    assert_eq!(attained.point().fill, PointFill::Closed);
+}
+
+#[test]
+fn test_epsilon_delta_labels() {
+    let cplane = CoordinatePlane::new_elementary();
+    let [epsilon, delta] = figure().labels(&cplane.extent.brect);
+    assert_eq!(epsilon.tex, "L \\pm \\varepsilon");
+    assert_eq!(epsilon.pos, Vec2D { x: 5.0, y: 2.0 });
+    assert_eq!(epsilon.placement, LabelPlacement::Left);
+    assert_eq!(delta.tex, "a \\pm \\delta");
+    assert_eq!(delta.pos, Vec2D { x: 1.0, y: 5.0 });
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use yapl::elements::{CoordinatePlane, Figure, Function, Line, Panel, PanelTitle, SharedAxes};
+use yapl::math::{BoundingRect, ClosedInterval, NonDecreasing, Vec2D};
+use common::{assert_close, attribute, compile_figure, numbers, tags};
+
+fn interval(begin: f64, end: f64) -> ClosedInterval {
+    ClosedInterval::new(NonDecreasing::new(begin, end))
+}
+
+fn assert_all_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) { assert_close(*a, *e, 1e-9); }
+}
+
+#[test]
+fn test_grid_layout() {
+    let mut figure = Figure::new_grid(2);
+    for tex in ["f", "f'", "f''"] {
+        let mut panel = Panel::new_default(CoordinatePlane::new_elementary());
+        panel.cplane.fns.push(Function::new_elementary(|x| x * x));
+        panel.title = Some(PanelTitle::new_default(tex));
+        figure.panels.push(panel);
+    }
+    let svg = compile_figure(&figure);
+    let title = 1.5 * PanelTitle::DEFAULT_TYPOGRAPHY_HEIGHT;
+    let spacing = Figure::DEFAULT_SPACING;
+    // Two rows of square planes, each beneath the space reserved for the titles.
+    assert_all_close(&numbers(attribute(tags(&svg, "svg")[0], "viewBox").unwrap()),
+        &[0.0, 0.0, 2.0 + spacing, 2.0 * (title + 1.0) + spacing]);
+    // The rectangle of each plane, skipping the titles and tick labels, which have no viewBox.
+    let panels: Vec<Vec<f64>> = tags(&svg, "svg").into_iter()
+        .filter(|tag| attribute(tag, "x").is_some() && attribute(tag, "viewBox").is_some())
+        .map(|tag| ["x", "y", "width", "height"].into_iter()
+            .map(|name| attribute(tag, name).unwrap().parse::<f64>().unwrap())
+            .collect())
+        .collect();
+    assert_eq!(panels.len(), 3);
+    assert_all_close(&panels[1], &[1.0 + spacing, title, 1.0, 1.0]);
+    assert_all_close(&panels[2], &[0.0, 2.0 * title + 1.0 + spacing, 1.0, 1.0]);
+}
+
+#[test]
+fn test_single_merged_stylesheet() {
+    let mut figure = Figure::new_grid(2);
+    let mut left = CoordinatePlane::new_elementary();
+    left.fns.push(Function::new_elementary(|x| x));
+    let mut right = CoordinatePlane::new_minimal();
+    right.lines.push(Line::new_segment(Vec2D { x: 0.0, y: 0.0 }, Vec2D { x: 1.0, y: 1.0 }));
+    figure.panels.push(Panel::new_default(left));
+    figure.panels.push(Panel::new_default(right));
+    let svg = compile_figure(&figure);
+    assert_eq!(svg.matches("<style>").count(), 1);
+    // The style classes used by either panel are emitted, each exactly once.
+    assert_eq!(svg.matches(".yapl-def-fn {").count(), 1);
+    assert_eq!(svg.matches(".yapl-def-line {").count(), 1);
+    assert_eq!(svg.matches(".yapl-def-axis {").count(), 1);
+    assert!(svg.ends_with("</style></svg>"));
+}
+
+#[test]
+fn test_shared_axes() {
+    let mut figure = Figure::new_grid(2);
+    for end in [1.0, 2.0, 3.0, 4.0] {
+        let mut cplane = CoordinatePlane::new_boxed();
+        cplane.extent.brect.x = interval(0.0, end);
+        cplane.extent.brect.y = interval(-end, 0.0);
+        figure.panels.push(Panel::new_default(cplane));
+    }
+    figure.share_axes(SharedAxes::Horizontal);
+    for panel in &figure.panels {
+        let x = panel.cplane.extent.brect.x;
+        assert!(x.begin() == 0.0 && x.end() == 4.0);
+    }
+    assert_eq!(figure.panels[0].cplane.extent.brect.y.begin(), -1.0);
+    // Only the bottom row keeps the tick labels along its bottom edge.
+    let labeled: Vec<bool> = figure.panels.iter()
+        .map(|p| p.cplane.horizontal_axis.unwrap().tick_label.is_some())
+        .collect();
+    assert_eq!(labeled, vec![false, false, true, true]);
+    assert!(figure.panels.iter().all(|p| p.cplane.vertical_axis.unwrap().tick_label.is_some()));
+
+    figure.share_axes(SharedAxes::Vertical);
+    assert!(figure.panels.iter().all(|p| p.cplane.extent.brect.y.begin() == -4.0));
+    assert!(figure.panels[1].cplane.vertical_axis.unwrap().tick_label.is_none());
+}
+
+#[test]
+fn test_explicit_rect() {
+    let mut figure = Figure::new_grid(1);
+    figure.panels.push(Panel::new_default(CoordinatePlane::new_elementary()));
+    let mut inset = Panel::new_default(CoordinatePlane::new_elementary());
+    inset.rect = Some(BoundingRect { x: interval(1.5, 2.0), y: interval(-0.5, 0.0) });
+    figure.panels.push(inset);
+    let svg = compile_figure(&figure);
+    assert!(svg.starts_with("<svg viewBox=\"0 -0.5 2 1.5\""));
+    assert!(svg.contains("<svg x=\"1.5\" y=\"-0.5\" width=\"0.5\" height=\"0.5\""));
+}
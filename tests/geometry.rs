@@ -0,0 +1,91 @@
+mod common;
+
+use yapl::elements::{AngleMark, CongruenceMark, CoordinatePlane, MarkStroke, ParallelMark};
+use yapl::math::Vec2D;
+use common::assert_close;
+
+fn v(x: f64, y: f64) -> Vec2D { Vec2D { x, y } }
+
+#[test]
+fn test_angle_mark_compensates_for_scale() {
+    let mut cplane = CoordinatePlane::new_elementary();
+    let mark = AngleMark::new_default(v(0.0, 0.0), v(1.0, 0.0), v(1.0, 1.0));
+    assert_close(mark.apparent_angle(&cplane.extent).unwrap(), std::f64::consts::FRAC_PI_4,
+        1e-12);
+    // Stretching the plane vertically steepens the second segment as drawn.
+    cplane.extent.y_scale = 2.0;
+    assert_close(mark.apparent_angle(&cplane.extent).unwrap(), 2f64.atan(), 1e-12);
+
+    let Some((vertex, _, v)) = mark.rays(&cplane.extent) else { panic!() };
+    let strokes = mark.strokes(&cplane.extent);
+    let MarkStroke::Arc { end, radius, .. } = strokes[0] else { panic!() };
+    // The arc ends on the segment as drawn.
+    assert_close(end.x, vertex.x + (radius * v.x), 1e-12);
+    assert_close(end.y, vertex.y + (radius * v.y), 1e-12);
+    assert_close((end.y - vertex.y) / (end.x - vertex.x), -2.0, 1e-12);
+}
+
+#[test]
+fn test_angle_mark_arcs() {
+    let cplane = CoordinatePlane::new_elementary();
+    let mut mark = AngleMark::new_default(v(0.0, 0.0), v(1.0, 0.0), v(0.0, 1.0));
+    mark.arcs = 2;
+    let strokes = mark.strokes(&cplane.extent);
+    assert_eq!(strokes.len(), 2);
+    // Turning from the positive horizontal direction towards the positive vertical direction
+    // is counterclockwise as drawn.
+    let MarkStroke::Arc { clockwise, .. } = strokes[0] else { panic!() };
+    assert!(!clockwise);
+    let reversed = AngleMark::new_default(v(0.0, 0.0), v(0.0, 1.0), v(1.0, 0.0));
+    let MarkStroke::Arc { clockwise, .. } = reversed.strokes(&cplane.extent)[0] else { panic!() };
+    assert!(clockwise);
+    assert!(mark.label_center(&cplane.extent).is_none());
+    mark.label = Some("\\theta");
+    let center = mark.label_center(&cplane.extent).unwrap();
+    let vertex = cplane.extent.transform().apply(v(0.0, 0.0));
+    // The label lies on the bisector, up and to the right of the vertex as drawn.
+    assert_close(center.x - vertex.x, vertex.y - center.y, 1e-12);
+    assert!(center.x > vertex.x);
+}
+
+#[test]
+fn test_right_angle_mark() {
+    let mut cplane = CoordinatePlane::new_elementary();
+    cplane.extent.x_scale = 3.0;
+    let mark = AngleMark::new_right_angle(v(1.0, 1.0), v(4.0, 1.0), v(1.0, -2.0));
+    let strokes = mark.strokes(&cplane.extent);
+    let MarkStroke::Polyline(vertices) = &strokes[0] else { panic!() };
+    let vertex = cplane.extent.transform().apply(v(1.0, 1.0));
+    let side = mark.radius;
+    // The square has equal sides as drawn, despite the unequal scales.
+    assert_eq!(vertices.len(), 3);
+    assert_close(vertices[0].x - vertex.x, side, 1e-12);
+    assert_close(vertices[1].x - vertex.x, side, 1e-12);
+    assert_close(vertices[1].y - vertex.y, side, 1e-12);
+    assert_close(vertices[2].y - vertex.y, side, 1e-12);
+    assert!(AngleMark::new_default(v(0.0, 0.0), v(0.0, 0.0), v(1.0, 0.0))
+        .strokes(&cplane.extent).is_empty());
+}
+
+#[test]
+fn test_congruence_and_parallel_marks() {
+    let mut cplane = CoordinatePlane::new_elementary();
+    cplane.extent.y_scale = 0.5;
+    let ticks = CongruenceMark::new_default(v(-2.0, -2.0), v(2.0, 2.0), 3).strokes(&cplane.extent);
+    assert_eq!(ticks.len(), 3);
+    let midpoint = cplane.extent.transform().apply(v(0.0, 0.0));
+    let MarkStroke::Polyline(middle) = &ticks[1] else { panic!() };
+    // The middle tick is centered on the midpoint and perpendicular to the segment as drawn.
+    assert_close(0.5 * (middle[0].x + middle[1].x), midpoint.x, 1e-12);
+    assert_close(0.5 * (middle[0].y + middle[1].y), midpoint.y, 1e-12);
+    let d = (middle[1].x - middle[0].x, middle[1].y - middle[0].y);
+    assert_close((d.0 * 1.0) + (d.1 * -0.5), 0.0, 1e-12);
+
+    let arrows = ParallelMark::new_default(v(-2.0, 0.0), v(2.0, 0.0), 2).strokes(&cplane.extent);
+    assert_eq!(arrows.len(), 2);
+    let MarkStroke::Polyline(chevron) = &arrows[1] else { panic!() };
+    // The arrowheads point from the first endpoint towards the second.
+    assert!(chevron[1].x > chevron[0].x);
+    assert_close(chevron[0].x, chevron[2].x, 1e-12);
+    assert_close(chevron[1].y, midpoint.y, 1e-12);
+}
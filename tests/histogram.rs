@@ -0,0 +1,111 @@
+use yapl::elements::{BarChart, Binning, CoordinatePlane, Histogram};
+
+fn counts(histogram: &Histogram) -> Vec<usize> {
+    histogram.bins.iter().map(|bin| bin.count).collect()
+}
+
+#[test]
+fn test_histogram_count_binning() {
+    let histogram = Histogram::new_default(&[0.0, 1.0, 1.5, 2.0, 3.9, 4.0, f64::NAN],
+        Binning::Count(4));
+    assert_eq!(counts(&histogram), vec![1, 2, 1, 2]);
+    assert_eq!(histogram.bins[0].edges.begin(), 0.0);
+    assert_eq!(histogram.bins[3].edges.end(), 4.0);
+    assert_eq!(histogram.total(), 6);
+}
+
+#[test]
+fn test_histogram_width_binning() {
+    let histogram = Histogram::new_default(&[-0.5, 0.0, 0.25, 0.75, 1.0], Binning::Width(0.5));
+    let edges: Vec<f64> = histogram.bins.iter().map(|bin| bin.edges.begin()).collect();
+    assert_eq!(edges, vec![-0.5, 0.0, 0.5]);
+    assert_eq!(counts(&histogram), vec![1, 2, 2]);
+}
+
+#[test]
+fn test_histogram_explicit_edges() {
+    let histogram = Histogram::new_default(&[-1.0, 0.0, 0.5, 1.0, 3.0, 9.0],
+        Binning::Edges(vec![0.0, 1.0, 3.0]));
+    assert_eq!(counts(&histogram), vec![2, 2]);
+}
+
+#[test]
+fn test_histogram_auto_binning() {
+    let samples: Vec<f64> = (0..1000).map(|i| ((i as f64) * 0.618).fract()).collect();
+    let histogram = Histogram::new_default(&samples, Binning::Auto);
+    // The Freedman–Diaconis width of uniform samples on [0, 1) is about 1 / cbrt(1000).
+    assert!((9..=11).contains(&histogram.bins.len()));
+    assert_eq!(histogram.total(), 1000);
+
+    let histogram = Histogram::new_default(&[2.0, 2.0, 2.0], Binning::Auto);
+    assert_eq!(histogram.bins.len(), 1);
+    assert_eq!(histogram.bins[0].edges.begin(), 1.5);
+    assert_eq!(histogram.bins[0].count, 3);
+
+    let histogram = Histogram::new_default(&[], Binning::Auto);
+    assert!(histogram.bins.is_empty());
+}
+
+#[test]
+fn test_histogram_auto_binning_outliers() {
+    // A single distant outlier would stretch the Freedman–Diaconis bins over a million empty
+    // bins. Infinite samples are not counted at all.
+    let mut samples: Vec<f64> = (0..1000).map(|i| ((i as f64) * 0.618).fract()).collect();
+    samples.extend([1.0e5, f64::INFINITY, f64::NEG_INFINITY]);
+    let histogram = Histogram::new_default(&samples, Binning::Auto);
+    assert_eq!(histogram.bins.len(), Histogram::MAX_AUTO_BINS);
+    assert_eq!(histogram.total(), 1001);
+    assert_eq!(histogram.bins.last().unwrap().edges.end(), 1.0e5);
+}
+
+#[test]
+fn test_histogram_density() {
+    let histogram = Histogram::new_density(&[0.0, 0.1, 0.2, 1.5, 2.5, 3.0],
+        Binning::Edges(vec![0.0, 1.0, 2.0, 4.0]));
+    let area: f64 = histogram.bars().iter()
+        .map(|bar| (bar.span.end() - bar.span.begin()) * bar.value)
+        .sum();
+    assert!((area - 1.0).abs() < 1e-12);
+    assert_eq!(histogram.height(&histogram.bins[0]), 0.5);
+    assert_eq!(histogram.height(&histogram.bins[2]), 1.0 / 6.0);
+}
+
+#[test]
+fn test_histogram_rects_clipped() {
+    let cplane = CoordinatePlane::new_elementary();
+    let mut samples = vec![-6.0, 0.0];
+    samples.extend(std::iter::repeat_n(1.5, 7));
+    let histogram = Histogram::new_default(&samples, Binning::Width(1.0));
+    let rects = histogram.rects(&cplane.extent.brect);
+    // The bin of -6 lies outside of the extent and the empty bins are omitted.
+    assert_eq!(rects.len(), 2);
+    assert_eq!(rects[0].1.y.end(), 1.0);
+    assert_eq!(rects[1].1.y.end(), 5.0);
+    assert_eq!(rects[1].1.x.begin(), 1.0);
+}
+
+#[test]
+fn test_bar_chart_categorical() {
+    let mut chart = BarChart::new_categorical(&[("A", 3.0), ("B", -1.0), ("\\alpha", 2.0)]);
+    chart.bars[1].style_class = Some("highlight");
+    assert!(chart.has_labels());
+    assert_eq!(chart.bars[2].center(), 3.0);
+    assert_eq!(chart.bars[2].label, Some("\\alpha"));
+
+    let cplane = CoordinatePlane::new_elementary();
+    let rects = chart.rects(&cplane.extent.brect);
+    assert_eq!(rects.len(), 3);
+    assert_eq!(rects[1].0.style_class, Some("highlight"));
+    assert_eq!(rects[1].1.y.begin(), -1.0);
+    assert_eq!(rects[1].1.y.end(), 0.0);
+    assert!((rects[0].1.x.len() - BarChart::DEFAULT_BAR_WIDTH_FRACTION).abs() < 1e-12);
+}
+
+#[test]
+fn test_bar_chart_numeric() {
+    let chart = BarChart::new_numeric(&[(0.0, 1.0), (0.5, 2.0), (2.0, 3.0)]);
+    assert!(!chart.has_labels());
+    for bar in &chart.bars {
+        assert!((bar.span.end() - bar.span.begin() - 0.4).abs() < 1e-12);
+    }
+}
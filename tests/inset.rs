@@ -0,0 +1,92 @@
+mod common;
+
+use yapl::elements::{CoordinatePlane, Function, Inset};
+use yapl::math::{BoundingRect, ClosedInterval, NonDecreasing, Vec2D};
+use common::{assert_close, attribute, compile, numbers, tags};
+
+fn rect(x: (f64, f64), y: (f64, f64)) -> BoundingRect {
+    BoundingRect {
+        x: ClosedInterval::new(NonDecreasing::new(x.0, x.1)),
+        y: ClosedInterval::new(NonDecreasing::new(y.0, y.1))
+    }
+}
+
+fn v(x: f64, y: f64) -> Vec2D { Vec2D { x, y } }
+
+/// The plane of `sin(1/x)` from the snapshot tests, with an inset magnifying the peaks of the
+/// oscillations near zero.
+fn sin_1_over_x() -> CoordinatePlane<'static> {
+    let mut cplane = CoordinatePlane::new_minimal();
+    cplane.extent.brect = rect((-0.5, 0.5), (-1.1, 1.1));
+    cplane.extent.x_scale = 8.0;
+    let mut f = Function::new_elementary(|x| (1.0 / x).sin());
+    f.zero_tolerance_factor = 10.0f64.powi(7);
+    cplane.fns.push(f);
+    cplane.insets.push(Inset::new_default(rect((0.02, 0.04), (0.8, 1.0)), v(0.15, -0.2), 4.0));
+    return cplane;
+}
+
+/// Parses the coordinates of the anchors of the `index`th path of the given SVG.
+fn path_anchors(svg: &str, index: usize) -> Vec<Vec2D> {
+    let data = attribute(tags(svg, "path")[index], "d").unwrap();
+    return numbers(data).chunks(2).map(|pair| v(pair[0], pair[1])).collect();
+}
+
+#[test]
+fn test_inset_geometry() {
+    let inset = Inset::new_default(rect((0.0, 0.1), (0.0, 0.1)), v(0.3, 0.9), 4.0);
+    let occupied = inset.rect();
+    assert_close(v(occupied.x.begin(), occupied.y.begin()), v(0.3, 0.5), 1e-12);
+    assert_close(v(occupied.x.end(), occupied.y.end()), v(0.7, 0.9), 1e-12);
+
+    // The inset lies up and to the right of the region, so the connectors join the top left
+    // and bottom right corners, passing beside both rectangles.
+    let connectors = inset.connectors();
+    assert_eq!(connectors.len(), 2);
+    assert!(connectors.contains(&(v(0.0, 0.1), v(0.3, 0.9))));
+    assert!(connectors.iter()
+        .any(|&(a, b)| a == v(0.1, 0.0) && (b - v(0.7, 0.5)).len() < 1e-12));
+
+    let overlapping = Inset::new_default(rect((0.0, 0.1), (0.0, 0.1)), v(0.05, 0.5), 4.0);
+    assert!(overlapping.connectors().is_empty());
+}
+
+#[test]
+fn test_inset_extent() {
+    let cplane = sin_1_over_x();
+    let inset = &cplane.insets[0];
+    let extent = inset.extent(&cplane.extent);
+    assert_eq!(extent.brect.x.begin(), 0.02);
+    // The normalized coordinate system of the inset has the unit length of the enclosing
+    // plane, so the inset spans exactly the normalized size of its rectangle.
+    let transform = cplane.extent.transform();
+    let occupied = inset.rect();
+    let size = transform.apply(v(occupied.x.end(), occupied.y.begin()))
+        - transform.apply(v(occupied.x.begin(), occupied.y.end()));
+    let inset_size = extent.transform().apply(v(extent.brect.x.end(), extent.brect.y.begin()));
+    assert_close(inset_size, size, 1e-12);
+}
+
+#[test]
+fn test_inset_codegen() {
+    let cplane = sin_1_over_x();
+    let svg = compile(&cplane);
+    assert_eq!(svg.matches("<rect").count(), 2);
+    assert_eq!(svg.matches("<line").count(), 2);
+    assert_eq!(svg.matches("class=\" yapl-def-inset\"").count(), 3);
+    assert_eq!(svg.matches("class=\" yapl-def-inset-frame\"").count(), 1);
+    assert!(svg.contains(".yapl-def-inset {") && svg.contains(".yapl-def-inset-frame {"));
+
+    // The function is sampled anew over the region, rather than scaled up from the enclosing
+    // plane, so the inset samples the oscillations more finely than the enclosing plane does.
+    let transform = cplane.extent.transform();
+    let region = cplane.insets[0].region();
+    let top_left = transform.apply(v(region.x.begin(), region.y.end()));
+    let bottom_right = transform.apply(v(region.x.end(), region.y.begin()));
+    let enclosing = path_anchors(&svg, 0).iter()
+        .filter(|p| p.x >= top_left.x && p.x <= bottom_right.x)
+        .filter(|p| p.y >= top_left.y && p.y <= bottom_right.y)
+        .count();
+    let magnified = path_anchors(&svg, 1).len();
+    assert!(magnified > enclosing, "{} anchors versus {}", magnified, enclosing);
+}
@@ -0,0 +1,69 @@
+use yapl::math::{ClosedInterval, Endpoint, Interval, IntervalSet};
+use yapl::math::{NonDecreasing, OpenInterval};
+
+fn closed(begin: f64, end: f64) -> Interval { Interval::closed(NonDecreasing::new(begin, end)) }
+fn open(begin: f64, end: f64) -> Interval { Interval::open(NonDecreasing::new(begin, end)) }
+
+fn set(intervals: &[Interval]) -> IntervalSet { intervals.iter().copied().collect() }
+
+#[test]
+fn test_interval_conversions() {
+    let bounds = NonDecreasing::new(0.0, 1.0);
+    assert_eq!(Interval::from(ClosedInterval::new(bounds)), closed(0.0, 1.0));
+    assert_eq!(Interval::from(OpenInterval::new(bounds)), open(0.0, 1.0));
+    let half_open = Interval::open_closed(bounds);
+    assert!(half_open.begin_endpoint() == Endpoint::Open);
+    assert!(half_open.end_endpoint() == Endpoint::Closed);
+    assert!(!half_open.includes(0.0) && half_open.includes(1.0));
+    assert!(Interval::new(NonDecreasing::new(2.0, 2.0), Endpoint::Closed, Endpoint::Open)
+        .is_empty());
+    assert!(!closed(2.0, 2.0).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_interval_closed_at_infinity() {
+    Interval::new(NonDecreasing::new(0.0, f64::INFINITY), Endpoint::Closed, Endpoint::Closed);
+}
+
+#[test]
+fn test_interval_intersection() {
+    assert_eq!(closed(0.0, 2.0).intersection(&open(1.0, 3.0)),
+        Some(Interval::new(NonDecreasing::new(1.0, 2.0), Endpoint::Open, Endpoint::Closed)));
+    // Touching at an endpoint excluded from one of the intervals leaves nothing.
+    assert_eq!(closed(0.0, 1.0).intersection(&open(1.0, 2.0)), None);
+    assert_eq!(closed(0.0, 1.0).intersection(&closed(1.0, 2.0)), Some(closed(1.0, 1.0)));
+    assert_eq!(open(0.0, 1.0).intersection(&Interval::unbounded()), Some(open(0.0, 1.0)));
+}
+
+#[test]
+fn test_union_is_canonical() {
+    let half_open = Interval::new(NonDecreasing::new(0.0, 1.0), Endpoint::Closed, Endpoint::Open);
+    assert_eq!(set(&[closed(1.0, 2.0), half_open]), set(&[closed(0.0, 2.0)]));
+    assert_eq!(set(&[open(0.0, 1.0), open(1.0, 2.0)]).intervals().len(), 2);
+    assert_eq!(set(&[open(3.0, 4.0), open(0.0, 5.0), closed(0.0, 0.0)]),
+        set(&[Interval::new(NonDecreasing::new(0.0, 5.0), Endpoint::Closed, Endpoint::Open)]));
+    assert!(set(&[open(1.0, 1.0)]).is_empty());
+    let union = set(&[closed(0.0, 1.0)]).union(&set(&[open(1.0, 2.0), closed(5.0, 6.0)]));
+    assert_eq!(union.intervals().len(), 2);
+    assert!(union.includes(1.5) && !union.includes(2.0) && union.includes(5.0));
+}
+
+#[test]
+fn test_complement_and_difference() {
+    let complement = set(&[closed(0.0, 1.0), open(2.0, 3.0)]).complement();
+    assert_eq!(complement, set(&[
+        open(f64::NEG_INFINITY, 0.0),
+        Interval::new(NonDecreasing::new(1.0, 2.0), Endpoint::Open, Endpoint::Closed),
+        Interval::new(NonDecreasing::new(3.0, f64::INFINITY), Endpoint::Closed, Endpoint::Open)
+    ]));
+    assert_eq!(complement.complement(), set(&[closed(0.0, 1.0), open(2.0, 3.0)]));
+    assert_eq!(IntervalSet::empty().complement(), IntervalSet::unbounded());
+    assert!(IntervalSet::unbounded().complement().is_empty());
+    // Removing a point splits an interval in two.
+    let punctured = set(&[closed(0.0, 2.0)]).difference(&set(&[closed(1.0, 1.0)]));
+    assert_eq!(punctured.intervals().len(), 2);
+    assert!(!punctured.includes(1.0) && punctured.includes(0.0) && punctured.includes(2.0));
+    let x = set(&[open(-1.0, 4.0)]);
+    assert_eq!(x.intersection(&x.complement()), IntervalSet::empty());
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use yapl::elements::{Corner, CoordinatePlane, Function, Legend, LegendPosition};
+use yapl::elements::line::Line;
+use yapl::math::{ClosedInterval, NonDecreasing, Vec2D};
+use common::{assert_close, attribute, compile, tags};
+
+/// Parses the position and size of the frame of the legend in the given SVG.
+fn frame(svg: &str) -> (Vec2D, Vec2D) {
+    let tag = tags(svg, "rect")[0];
+    let number = |name: &str| attribute(tag, name).unwrap().parse::<f64>().unwrap();
+    return (Vec2D { x: number("x"), y: number("y") },
+        Vec2D { x: number("width"), y: number("height") });
+}
+
+/// A plane whose extent is twice as wide as it is tall, with a legend of two entries.
+fn wide_plane(position: LegendPosition) -> CoordinatePlane<'static> {
+    let mut cplane = CoordinatePlane::new_minimal();
+    cplane.extent.brect.x = ClosedInterval::new(NonDecreasing::new(0.0, 4.0));
+    cplane.extent.brect.y = ClosedInterval::new(NonDecreasing::new(0.0, 2.0));
+    let mut legend = Legend::new_default(position);
+    legend.push_function(&Function::new_elementary(|x| x), "x");
+    legend.push_line(&Line::new_segment(Vec2D { x: 0.0, y: 0.0 }, Vec2D { x: 1.0, y: 1.0 }),
+        "y = x");
+    cplane.legend = Some(legend);
+    return cplane;
+}
+
+#[test]
+fn test_legend_frame_size() {
+    let mut legend = Legend::new_default(LegendPosition::Corner(Corner::TopLeft));
+    let padding = Legend::DEFAULT_PADDING;
+    let width = (3.0 * padding) + Legend::DEFAULT_SWATCH_LEN + Legend::DEFAULT_LABEL_WIDTH;
+    for n in 0..4 {
+        let row_height = Legend::ROW_HEIGHT_FACTOR * Legend::DEFAULT_TYPOGRAPHY_HEIGHT;
+        assert_close(legend.width(), width, 1e-12);
+        assert_close(legend.height(), (2.0 * padding) + (n as f64 * row_height), 1e-12);
+        legend.push_function(&Function::new_elementary(|x| x), "f");
+    }
+
+    let cplane = wide_plane(LegendPosition::Corner(Corner::TopLeft));
+    let (_, size) = frame(&compile(&cplane));
+    let legend = cplane.legend.as_ref().unwrap();
+    assert_close(size, Vec2D { x: legend.width(), y: legend.height() }, 1e-12);
+}
+
+#[test]
+fn test_legend_corners() {
+    let cplane = wide_plane(LegendPosition::Corner(Corner::TopLeft));
+    let legend = cplane.legend.as_ref().unwrap();
+    let (padding, width, height) = (legend.padding, legend.width(), legend.height());
+    // The normalized extent spans the unit length horizontally and half of it vertically.
+    let bound = Vec2D { x: 1.0, y: 0.5 };
+
+    let expected = [
+        (Corner::TopLeft, Vec2D { x: padding, y: padding }),
+        (Corner::TopRight, Vec2D { x: bound.x - padding - width, y: padding }),
+        (Corner::BottomLeft, Vec2D { x: padding, y: bound.y - padding - height }),
+        (Corner::BottomRight, Vec2D { x: bound.x - padding - width, y: bound.y - padding - height })
+    ];
+    for (corner, origin) in expected {
+        let (actual, _) = frame(&compile(&wide_plane(LegendPosition::Corner(corner))));
+        assert_close(actual, origin, 1e-12);
+    }
+
+    // The coordinate (1, 1.5) lies a quarter of the way across and an eighth of the way down.
+    let at = Vec2D { x: 1.0, y: 1.5 };
+    let (actual, _) = frame(&compile(&wide_plane(LegendPosition::At(at))));
+    assert_close(actual, Vec2D { x: 0.25, y: 0.125 }, 1e-12);
+}
+
+#[test]
+fn test_legend_swatch_default_style_classes() {
+    // The plane has neither functions nor lines of its own, so the default classes of these
+    // are only emitted because the swatches use them.
+    let mut cplane = wide_plane(LegendPosition::Corner(Corner::TopRight));
+    let svg = compile(&cplane);
+    assert_eq!(svg.matches("class=\" yapl-def-fn\"").count(), 1);
+    assert_eq!(svg.matches("class=\" yapl-def-line\"").count(), 1);
+    assert!(svg.contains(".yapl-def-legend {"));
+    assert!(svg.contains(".yapl-def-fn {"));
+    assert!(svg.contains(".yapl-def-line {"));
+
+    for entry in &mut cplane.legend.as_mut().unwrap().entries {
+        entry.swatch.apply_default_style_class = false;
+    }
+    let svg = compile(&cplane);
+    assert!(svg.contains(".yapl-def-legend {"));
+    assert!(!svg.contains("yapl-def-fn"));
+    assert!(!svg.contains("yapl-def-line"));
+}
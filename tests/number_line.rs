@@ -0,0 +1,86 @@
+mod common;
+
+use yapl::elements::{ArrowheadEnds, NumberLine, NumberLineInterval, PointFill};
+use yapl::math::{ClosedInterval, Endpoint, Interval, IntervalSet};
+use yapl::math::{NonDecreasing, OpenInterval};
+use common::compile_number_line;
+
+fn range() -> ClosedInterval { ClosedInterval::new(NonDecreasing::new(-5.0, 5.0)) }
+
+#[test]
+fn test_half_open_intervals() {
+    let closed_open = Interval::closed_open(NonDecreasing::new(1.0, 2.0));
+    assert!(closed_open.includes(1.0) && !closed_open.includes(2.0));
+    assert!(closed_open.begin_endpoint() == Endpoint::Closed);
+    let open_closed = Interval::open_closed(NonDecreasing::new(f64::NEG_INFINITY, 2.0));
+    assert!(open_closed.includes(-1e300) && open_closed.includes(2.0));
+    assert!(open_closed.end_endpoint() == Endpoint::Closed);
+    assert!(Interval::closed_open(NonDecreasing::new(3.0, 3.0)).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_half_open_interval_closed_at_infinity() {
+    Interval::closed_open(NonDecreasing::new(f64::NEG_INFINITY, 0.0));
+}
+
+#[test]
+fn test_interval_endpoints_and_arrowheads() {
+    let interval = NumberLineInterval::from(Interval::closed_open(
+        NonDecreasing::new(-2.0, f64::INFINITY)));
+    assert_eq!(interval.segment(range()), Some((-2.0, 5.0)));
+    assert!(interval.arrowhead_ends(range()) == Some(ArrowheadEnds::Positive));
+    // The unbounded end has no dot.
+    let dots = interval.endpoints(range());
+    assert_eq!(dots.len(), 1);
+    assert!(dots[0].pos.x == -2.0 && dots[0].fill == PointFill::Closed);
+
+    let open = NumberLineInterval::from(OpenInterval::new(NonDecreasing::new(-1.0, 1.0)));
+    assert!(open.arrowhead_ends(range()).is_none());
+    assert!(open.endpoints(range()).iter().all(|d| d.fill == PointFill::Open));
+
+    // A finite endpoint beyond the visible range is drawn like an unbounded one.
+    let wide = NumberLineInterval::from(ClosedInterval::new(NonDecreasing::new(-9.0, 9.0)));
+    assert!(wide.arrowhead_ends(range()) == Some(ArrowheadEnds::Both));
+    assert!(wide.endpoints(range()).is_empty());
+}
+
+#[test]
+fn test_degenerate_intervals() {
+    let point = NumberLineInterval::from(ClosedInterval::new(NonDecreasing::new(1.0, 1.0)));
+    assert_eq!(point.segment(range()), Some((1.0, 1.0)));
+    assert_eq!(point.endpoints(range()).len(), 1);
+    let empty = NumberLineInterval::from(OpenInterval::new(NonDecreasing::new(1.0, 1.0)));
+    assert!(empty.is_empty());
+    assert!(empty.segment(range()).is_none() && empty.endpoints(range()).is_empty());
+    let outside = NumberLineInterval::from(OpenInterval::new(NonDecreasing::new(6.0, 7.0)));
+    assert!(outside.segment(range()).is_none());
+}
+
+#[test]
+fn test_number_line_codegen() {
+    let mut line = NumberLine::new_default(range());
+    line.intervals.push(NumberLineInterval::from(Interval::open_closed(
+        NonDecreasing::new(f64::NEG_INFINITY, -1.0))));
+    line.intervals.push(NumberLineInterval::from(OpenInterval::new(
+        NonDecreasing::new(2.0, 4.0))));
+    let svg = compile_number_line(&line);
+    assert!(svg.starts_with("<svg viewBox=\"0 0 1 0.1\""));
+    assert_eq!(svg.matches("class=\" yapl-def-number-line-interval\"").count(), 2);
+    // The axis and the unbounded interval each reference their own arrowhead.
+    assert_eq!(svg.matches("<marker").count(), 2);
+    assert_eq!(svg.matches("yapl-def-open-point").count(), 3);
+    assert!(svg.contains(".yapl-def-number-line-interval {"));
+}
+
+#[test]
+fn test_solution_set() {
+    // The solution set of |x| > 2 is the complement of [-2, 2].
+    let set = IntervalSet::from(Interval::closed(NonDecreasing::new(-2.0, 2.0))).complement();
+    let mut line = NumberLine::new_default(range());
+    line.push_set(&set);
+    assert_eq!(line.intervals.len(), 2);
+    assert!(line.intervals[0].arrowhead_ends(range()) == Some(ArrowheadEnds::Negative));
+    let dots = line.intervals[1].endpoints(range());
+    assert!(dots.len() == 1 && dots[0].pos.x == 2.0 && dots[0].fill == PointFill::Open);
+}
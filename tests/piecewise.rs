@@ -0,0 +1,66 @@
+use yapl::analysis::{analyze, sample_runs};
+use yapl::elements::{CoordinatePlane, Function, Piecewise, PointFill};
+use yapl::math::{Interval, NonDecreasing};
+
+#[test]
+fn test_restricted_domain_sampling() {
+    let cplane = CoordinatePlane::new_elementary();
+    let f = Function::new_restricted(Interval::closed_open(NonDecreasing::new(1.0, f64::INFINITY)),
+        |x| x);
+    let runs = sample_runs(&f, &cplane.extent);
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0][0], 1.0);
+    assert_eq!(*runs[0].last().unwrap(), 5.0);
+}
+
+#[test]
+fn test_restricted_domain_outside_extent() {
+    let cplane = CoordinatePlane::new_elementary();
+    let f = Function::new_restricted(Interval::closed(NonDecreasing::new(6.0, 7.0)), |x| x);
+    assert!(f.plotfn_params(&cplane.extent).is_none());
+    assert!(sample_runs(&f, &cplane.extent).is_empty());
+}
+
+#[test]
+fn test_restricted_domain_analysis() {
+    let cplane = CoordinatePlane::new_elementary();
+    let f = Function::new_restricted(Interval::closed(NonDecreasing::new(0.5, 5.0)), |x| x.sin());
+    let analysis = analyze(&f, &cplane.extent);
+    assert_eq!(analysis.zeros.len(), 1);
+    assert!((analysis.zeros[0].input - std::f64::consts::PI).abs() < 1e-6);
+}
+
+#[test]
+fn test_piecewise_jump_endpoints() {
+    let mut piecewise = Piecewise::new_default();
+    piecewise.push(Interval::open(NonDecreasing::new(f64::NEG_INFINITY, 1.0)), |x| x);
+    piecewise.push(Interval::closed(NonDecreasing::new(1.0, 3.0)), |_| 3.0);
+    let dots = piecewise.endpoints();
+    assert_eq!(dots.len(), 3);
+    assert_eq!((dots[0].pos.x, dots[0].pos.y, dots[0].fill), (1.0, 1.0, PointFill::Open));
+    assert_eq!((dots[1].pos.x, dots[1].pos.y, dots[1].fill), (1.0, 3.0, PointFill::Closed));
+    assert_eq!((dots[2].pos.x, dots[2].pos.y, dots[2].fill), (3.0, 3.0, PointFill::Closed));
+}
+
+#[test]
+fn test_piecewise_continuous_joint() {
+    let mut piecewise = Piecewise::new_default();
+    piecewise.push(Interval::open(NonDecreasing::new(-1.0, 0.0)), |x| -x);
+    piecewise.push(Interval::closed_open(NonDecreasing::new(0.0, 1.0)), |x| x);
+    let dots = piecewise.endpoints();
+    let at_zero: Vec<PointFill> = dots.iter()
+        .filter(|dot| dot.pos.x == 0.0)
+        .map(|dot| dot.fill)
+        .collect();
+    assert_eq!(at_zero, vec![PointFill::Closed]);
+    assert_eq!(dots.len(), 3);
+}
+
+#[test]
+fn test_piecewise_removable_singularity() {
+    let mut piecewise = Piecewise::new_default();
+    piecewise.push(Interval::open_closed(NonDecreasing::new(0.0, 2.0)), |x| x.sin() / x);
+    let dots = piecewise.endpoints();
+    assert!((dots[0].pos.y - 1.0).abs() < 1e-6);
+    assert_eq!(dots[0].fill, PointFill::Open);
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use yapl::elements::{CoordinatePlane, Function, Inequality, Region};
+use common::assert_close;
+
+#[test]
+fn test_region_below_line() {
+    let cplane = CoordinatePlane::new_elementary();
+    let region = Region::new_graph(Function::new_elementary(|x| x), Inequality::Less);
+    let trace = region.trace(&cplane.extent);
+    assert_close(trace.area(), 50.0, 1e-9);
+    assert_eq!(trace.boundary.len(), 1);
+    assert!(region.is_strict());
+}
+
+#[test]
+fn test_region_above_parabola() {
+    let cplane = CoordinatePlane::new_elementary();
+    let region = Region::new_graph(Function::new_elementary(|x| x * x), Inequality::GreaterEqual);
+    let trace = region.trace(&cplane.extent);
+    assert_close(trace.area(), (20.0 / 3.0) * 5f64.sqrt(), 1e-2);
+    assert!(!region.is_strict());
+}
+
+#[test]
+fn test_region_spans_breaks_in_graph() {
+    // Where 1/x leaves the extent near zero, the region spans the extent to the left of zero
+    // and is empty to the right of zero. By symmetry the area is half of the extent.
+    let cplane = CoordinatePlane::new_elementary();
+    let region = Region::new_graph(Function::new_elementary(|x| 1.0 / x), Inequality::Greater);
+    let trace = region.trace(&cplane.extent);
+    assert_close(trace.area(), 50.0, 1e-2);
+    assert_eq!(trace.boundary.len(), 2);
+}
+
+#[test]
+fn test_region_predicate_disk() {
+    let cplane = CoordinatePlane::new_elementary();
+    let region = Region::new_predicate(|x, y| x * x + y * y <= 4.0, false);
+    let trace = region.trace(&cplane.extent);
+    assert_close(trace.area(), 4.0 * std::f64::consts::PI, 5e-2);
+    assert_eq!(trace.boundary.len(), 1);
+    let circle = &trace.boundary[0];
+    assert_eq!(circle.first().unwrap(), circle.last().unwrap());
+    for p in circle {
+        assert_close((p.x * p.x + p.y * p.y).sqrt(), 2.0, 5e-2);
+    }
+}
+
+#[test]
+fn test_region_predicate_feasible_set() {
+    let cplane = CoordinatePlane::new_elementary();
+    let region = Region::new_predicate(|x, y| x >= 0.0 && y >= 0.0 && x + y <= 4.0, false);
+    let trace = region.trace(&cplane.extent);
+    assert_close(trace.area(), 8.0, 5e-2);
+}
@@ -0,0 +1,66 @@
+use yapl::elements::{Cobweb, CoordinatePlane, Sequence};
+use yapl::math::Vec2D;
+
+#[test]
+fn test_sequence_explicit() {
+    let sequence = Sequence::new_explicit(1..=4, |n| 1.0 / (n as f64));
+    assert_eq!(sequence.first, 1);
+    assert_eq!(sequence.samples(), vec![(1.0, 1.0), (2.0, 0.5), (3.0, 1.0 / 3.0), (4.0, 0.25)]);
+    let dots = sequence.dots();
+    assert_eq!(dots.len(), 4);
+    assert_eq!(dots[1].pos, Vec2D { x: 2.0, y: 0.5 });
+}
+
+#[test]
+fn test_sequence_recursive() {
+    let sequence = Sequence::new_recursive(0, 1.0, 4, |a| 2.0 * a);
+    assert_eq!(sequence.terms, vec![1.0, 2.0, 4.0, 8.0]);
+}
+
+#[test]
+fn test_sequence_divergent_terms_break_line() {
+    let mut sequence = Sequence::new_default(0, vec![1.0, 2.0, f64::INFINITY, 2.0, 1.0]);
+    sequence.connect = true;
+    assert_eq!(sequence.dots().len(), 4);
+    let cplane = CoordinatePlane::new_elementary();
+    let polylines = sequence.polylines(&cplane.extent.brect);
+    assert_eq!(polylines.len(), 2);
+    assert_eq!(polylines[1], vec![Vec2D { x: 3.0, y: 2.0 }, Vec2D { x: 4.0, y: 1.0 }]);
+}
+
+#[test]
+fn test_sequence_stems() {
+    let mut sequence = Sequence::new_default(-1, vec![1.0, -1.0, 0.0]);
+    sequence.stems = true;
+    sequence.style_class = Some("alternating");
+    let stems = sequence.to_stems();
+    assert!(stems.heads().is_empty());
+    let cplane = CoordinatePlane::new_elementary();
+    let lines = stems.lines(&cplane.extent.brect);
+    // The stem of the zero term has no length and is omitted.
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1].2, Vec2D { x: 0.0, y: -1.0 });
+    assert_eq!(stems.style_class, Some("alternating"));
+}
+
+#[test]
+fn test_cobweb_path() {
+    let cobweb = Cobweb::new_recursive(0.5, 2, |x| x * x);
+    assert_eq!(cobweb.terms, vec![0.5, 0.25, 0.0625]);
+    assert_eq!(cobweb.path(), vec![
+        Vec2D { x: 0.5, y: 0.0 },
+        Vec2D { x: 0.5, y: 0.25 },
+        Vec2D { x: 0.25, y: 0.25 },
+        Vec2D { x: 0.25, y: 0.0625 },
+        Vec2D { x: 0.0625, y: 0.0625 }]);
+
+    let mut cobweb = Sequence::new_recursive(0, 3.0, 3, |x| x * x).cobweb();
+    cobweb.start_on_axis = false;
+    let path = cobweb.path();
+    assert_eq!(path[0], Vec2D { x: 3.0, y: 3.0 });
+    // The terms 9 and 81 leave the extent, so the path is cut short at its edge.
+    let cplane = CoordinatePlane::new_elementary();
+    let polylines = cobweb.polylines(&cplane.extent.brect);
+    assert_eq!(polylines.len(), 1);
+    assert_eq!(polylines[0], vec![Vec2D { x: 3.0, y: 3.0 }, Vec2D { x: 3.0, y: 5.0 }]);
+}
@@ -0,0 +1,42 @@
+use yapl::elements::{CoordinatePlane, Series};
+use yapl::math::Vec2D;
+
+#[test]
+fn test_series_polyline_unbroken() {
+    let cplane = CoordinatePlane::new_elementary();
+    let series = Series::new_polyline(vec![(-1.0, 0.0), (0.0, 1.0), (1.0, 0.5)]);
+    let polylines = series.polylines(&cplane.extent.brect);
+    assert_eq!(polylines, vec![vec![
+        Vec2D { x: -1.0, y: 0.0 }, Vec2D { x: 0.0, y: 1.0 }, Vec2D { x: 1.0, y: 0.5 }]]);
+}
+
+#[test]
+fn test_series_polyline_breaks_at_nan() {
+    let cplane = CoordinatePlane::new_elementary();
+    let series = Series::new_polyline(vec![(-2.0, 0.0), (-1.0, 1.0), (0.0, f64::NAN), (1.0, 1.0),
+        (2.0, 0.0)]);
+    let polylines = series.polylines(&cplane.extent.brect);
+    assert_eq!(polylines.len(), 2);
+    assert_eq!(polylines[0], vec![Vec2D { x: -2.0, y: 0.0 }, Vec2D { x: -1.0, y: 1.0 }]);
+    assert_eq!(polylines[1], vec![Vec2D { x: 1.0, y: 1.0 }, Vec2D { x: 2.0, y: 0.0 }]);
+}
+
+#[test]
+fn test_series_polyline_clipped() {
+    let cplane = CoordinatePlane::new_elementary();
+    let series = Series::new_polyline(vec![(0.0, 0.0), (0.0, 10.0), (1.0, 0.0)]);
+    let polylines = series.polylines(&cplane.extent.brect);
+    assert_eq!(polylines.len(), 2);
+    assert_eq!(polylines[0], vec![Vec2D { x: 0.0, y: 0.0 }, Vec2D { x: 0.0, y: 5.0 }]);
+    assert_eq!(polylines[1][1], Vec2D { x: 1.0, y: 0.0 });
+    assert!((polylines[1][0].x - 0.5).abs() < 1e-12);
+    assert!((polylines[1][0].y - 5.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_series_markers_outside_extent() {
+    let cplane = CoordinatePlane::new_elementary();
+    let series = Series::new_scatter(vec![(0.0, 0.0), (6.0, 0.0), (f64::NAN, 1.0), (5.0, 5.0)]);
+    let markers: Vec<Vec2D> = series.markers(&cplane.extent.brect).collect();
+    assert_eq!(markers, vec![Vec2D { x: 0.0, y: 0.0 }, Vec2D { x: 5.0, y: 5.0 }]);
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use yapl::elements::{CoordinatePlane, Function, Series};
+use yapl::math::{Interpolation, Spline};
+use common::{assert_close, compile};
+
+const TOLERANCE: f64 = 1e-9;
+
+const SAMPLES: [(f64, f64); 5] = [(0.0, 0.0), (1.0, 2.0), (2.0, 1.0), (4.0, 3.0), (5.0, 3.0)];
+
+#[test]
+fn test_spline_interpolates_samples() {
+    for interpolation in [Interpolation::Linear, Interpolation::NaturalCubic,
+        Interpolation::MonotoneCubic, Interpolation::CatmullRom]
+    {
+        let spline = Spline::new(interpolation, &SAMPLES);
+        for (x, y) in SAMPLES {
+            assert_close(spline.eval(x), y, TOLERANCE);
+        }
+        assert!(spline.eval(-0.1).is_nan());
+        assert!(spline.eval(5.1).is_nan());
+    }
+}
+
+#[test]
+fn test_linear_spline() {
+    let spline = Spline::new(Interpolation::Linear, &SAMPLES);
+    assert_close(spline.eval(0.5), 1.0, TOLERANCE);
+    assert_close(spline.eval(3.0), 2.0, TOLERANCE);
+}
+
+#[test]
+fn test_natural_cubic_spline() {
+    let spline = Spline::new(Interpolation::NaturalCubic, &[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]);
+    assert_close(spline.slopes[0][0], 1.5, TOLERANCE);
+    assert_close(spline.slopes[0][1], 0.0, TOLERANCE);
+    assert_close(spline.slopes[1][1], -1.5, TOLERANCE);
+
+    // The second derivative is continuous at the interior knots and vanishes at the ends.
+    let spline = Spline::new(Interpolation::NaturalCubic, &SAMPLES);
+    let curvature = |i: usize, t: f64| {
+        let h = spline.knots[i + 1] - spline.knots[i];
+        let [m0, m1] = spline.slopes[i];
+        let (y0, y1) = (spline.values[i], spline.values[i + 1]);
+        ((12.0 * t - 6.0) * (y0 - y1) / (h * h)) + ((6.0 * t - 4.0) * m0 / h)
+            + ((6.0 * t - 2.0) * m1 / h)
+    };
+    assert_close(curvature(0, 0.0), 0.0, TOLERANCE);
+    assert_close(curvature(3, 1.0), 0.0, TOLERANCE);
+    for i in 0..3 {
+        assert_close(curvature(i, 1.0), curvature(i + 1, 0.0), TOLERANCE);
+    }
+}
+
+#[test]
+fn test_monotone_cubic_spline() {
+    let samples = [(0.0, 0.0), (1.0, 0.0), (2.0, 1.0), (3.0, 1.0), (3.5, 5.0), (6.0, 5.1)];
+    let spline = Spline::new(Interpolation::MonotoneCubic, &samples);
+    let mut previous = f64::NEG_INFINITY;
+    for i in 0..=600 {
+        let y = spline.eval(i as f64 / 100.0);
+        assert!(y >= previous - 1e-12);
+        assert!((0.0..=5.1 + 1e-12).contains(&y));
+        previous = y;
+    }
+}
+
+#[test]
+fn test_catmull_rom_spline() {
+    let spline = Spline::new(Interpolation::CatmullRom, &SAMPLES);
+    assert_close(spline.slopes[1][0], (1.0 - 0.0) / (2.0 - 0.0), TOLERANCE);
+    assert_close(spline.slopes[2][0], (3.0 - 2.0) / (4.0 - 1.0), TOLERANCE);
+    assert_close(spline.slopes[0][0], 2.0, TOLERANCE);
+}
+
+#[test]
+fn test_spline_bezier_matches_eval() {
+    let spline = Spline::new(Interpolation::NaturalCubic, &SAMPLES);
+    for i in 0..spline.segments() {
+        let [p0, p1, p2, p3] = spline.bezier(i);
+        let at = |t: f64, a: f64, b: f64, c: f64, d: f64| {
+            let s = 1.0 - t;
+            (s * s * s * a) + (3.0 * s * s * t * b) + (3.0 * s * t * t * c) + (t * t * t * d)
+        };
+        for t in [0.25, 0.5, 0.75] {
+            let x = at(t, p0.x, p1.x, p2.x, p3.x);
+            let y = at(t, p0.y, p1.y, p2.y, p3.y);
+            assert_close(y, spline.eval(x), TOLERANCE);
+        }
+    }
+}
+
+#[test]
+fn test_series_interpolant() {
+    let mut series = Series::new_polyline(vec![(0.0, 0.0), (1.0, 1.0), (2.0, f64::NAN), (3.0, 1.0),
+        (4.0, 0.0)]);
+    series.interpolation = Interpolation::CatmullRom;
+    assert_eq!(series.splines().len(), 2);
+    let f = Function::new_elementary(series.interpolant());
+    assert_close((f.eval)(0.5), 0.5, TOLERANCE);
+    assert!((f.eval)(2.0).is_nan());
+    assert_close((f.eval)(3.5), 0.5, TOLERANCE);
+}
+
+#[test]
+fn test_series_splines_skip_invalid_stretches() {
+    // Infinite samples break the series just as NaN samples do, and the middle stretch doubles
+    // back on itself, so it is omitted rather than interpolated.
+    let mut series = Series::new_polyline(vec![(0.0, 0.0), (1.0, 1.0), (2.0, f64::INFINITY),
+        (3.0, 1.0), (2.5, 0.0), (f64::NEG_INFINITY, 0.0), (5.0, 1.0), (6.0, 0.0), (6.0, 1.0),
+        (f64::NAN, 0.0), (7.0, 1.0), (8.0, 0.0)]);
+    series.interpolation = Interpolation::MonotoneCubic;
+    let splines = series.splines();
+    assert_eq!(splines.len(), 2);
+    assert_eq!(splines[0].knots, vec![0.0, 1.0]);
+    assert_eq!(splines[1].knots, vec![7.0, 8.0]);
+
+    let mut cplane = CoordinatePlane::new_elementary();
+    cplane.series.push(series);
+    let svg = compile(&cplane);
+    assert_eq!(svg.matches("C ").count(), 2);
+}
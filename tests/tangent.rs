@@ -0,0 +1,43 @@
+use yapl::elements::{Function, FunctionKind};
+use yapl::math::{self, BoundingRect, ClosedInterval, NonDecreasing, Vec2D};
+
+#[test]
+fn test_derivative() {
+    let df = math::derivative(&|x: f64| x.sin(), 1.0);
+    assert!((df - 1.0f64.cos()).abs() < 1e-9);
+
+    let df = math::derivative(&|x: f64| x.exp(), 3.0);
+    assert!((df - 3.0f64.exp()).abs() < 1e-7);
+}
+
+#[test]
+fn test_tangent_and_secant() {
+    let f = Function::new_elementary(|x| x * x);
+    let tangent = f.tangent(1.0);
+    let slope = (tangent.b.y - tangent.a.y) / (tangent.b.x - tangent.a.x);
+    assert!((slope - 2.0).abs() < 1e-9);
+    assert_eq!(tangent.a, Vec2D { x: 1.0, y: 1.0 });
+
+    let secant = f.secant(1.0, 3.0);
+    assert_eq!(secant.a, Vec2D { x: 1.0, y: 1.0 });
+    assert_eq!(secant.b, Vec2D { x: 3.0, y: 9.0 });
+
+    let mut g = Function::new_elementary(|y| y * y);
+    g.kind = FunctionKind::OfY;
+    let tangent = g.tangent_with(1.0, |y| 2.0 * y);
+    let inverse_slope = (tangent.b.x - tangent.a.x) / (tangent.b.y - tangent.a.y);
+    assert_eq!(inverse_slope, 2.0);
+}
+
+#[test]
+fn test_clip_line() {
+    let brect = BoundingRect {
+        x: ClosedInterval::new(NonDecreasing::new(-1.0, 1.0)),
+        y: ClosedInterval::new(NonDecreasing::new(-1.0, 1.0))
+    };
+    let (a, b) = brect.clip_line(Vec2D { x: 0.0, y: 0.0 }, Vec2D { x: 0.1, y: 0.0 }).unwrap();
+    assert_eq!((a, b), (Vec2D { x: -1.0, y: 0.0 }, Vec2D { x: 1.0, y: 0.0 }));
+
+    assert!(brect.clip_line(Vec2D { x: 0.0, y: 2.0 }, Vec2D { x: 1.0, y: 2.0 }).is_none());
+    assert!(brect.clip_segment(Vec2D { x: 2.0, y: 0.0 }, Vec2D { x: 3.0, y: 0.0 }).is_none());
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use yapl::elements::CoordinatePlane;
+use yapl::math::{Transform2D, Vec2D};
+use common::assert_close;
+
+const TOLERANCE: f64 = 1e-12;
+
+fn v(x: f64, y: f64) -> Vec2D { Vec2D { x, y } }
+
+#[test]
+fn test_vector_arithmetic() {
+    let (a, b) = (v(1.0, 2.0), v(3.0, -1.0));
+    assert_eq!(a + b, v(4.0, 1.0));
+    assert_eq!(a - b, v(-2.0, 3.0));
+    assert_eq!(-a, v(-1.0, -2.0));
+    assert_eq!(2.0 * a, a * 2.0);
+    assert_eq!(b / 2.0, v(1.5, -0.5));
+    assert_eq!(a.dot(b), 1.0);
+    assert_eq!(a.cross(b), -7.0);
+    assert_eq!(v(3.0, 4.0).len(), 5.0);
+    assert_eq!(v(0.0, 2.0).unit(), Some(v(0.0, 1.0)));
+    assert_eq!(Vec2D::ZERO.unit(), None);
+    assert_eq!(v(1.0, 0.0).perp(), v(0.0, 1.0));
+}
+
+#[test]
+fn test_composition_and_inverse() {
+    let rotate = Transform2D::new_rotation(std::f64::consts::FRAC_PI_2);
+    let shift = Transform2D::new_translation(v(1.0, 0.0));
+    // Rotating first and shifting second differs from shifting first and rotating second.
+    assert_close(rotate.then(&shift).apply(v(1.0, 0.0)), v(1.0, 1.0), TOLERANCE);
+    assert_close(shift.then(&rotate).apply(v(1.0, 0.0)), v(0.0, 2.0), TOLERANCE);
+    let map = rotate.then(&Transform2D::new_scale(2.0, 3.0)).then(&shift);
+    let inverse = map.inverse().unwrap();
+    for p in [v(0.0, 0.0), v(1.5, -2.0), v(-7.0, 4.0)] {
+        assert_close(inverse.apply(map.apply(p)), p, TOLERANCE);
+        assert_close(map.then(&inverse).apply(p), p, TOLERANCE);
+    }
+    // Displacements are unaffected by the translation.
+    assert_close(map.apply_vector(v(1.0, 0.0)), map.apply(v(1.0, 0.0)) - map.apply(Vec2D::ZERO),
+        TOLERANCE);
+    assert!(Transform2D::new_scale(1.0, 0.0).inverse().is_none());
+}
+
+#[test]
+fn test_extent_transform() {
+    let mut cplane = CoordinatePlane::new_elementary();
+    cplane.extent.y_scale = 0.5;
+    let transform = cplane.extent.transform();
+    // The top left corner is the origin of the viewport and the vertical axis points downward.
+    assert_close(transform.apply(v(-5.0, 5.0)), v(0.0, 0.0), TOLERANCE);
+    assert_close(transform.apply(v(5.0, -5.0)), v(1.0, 0.5), TOLERANCE);
+    assert_close(transform.apply(v(0.0, 0.0)), v(0.5, 0.25), TOLERANCE);
+    // Each coordinate is offset, then divided by the longest side, then scaled, so that it
+    // rounds exactly as when it is computed by hand.
+    let by_hand = v(((0.7 + 5.0) / 10.0) * 1.0, ((5.0 - 0.1) / 10.0) * 0.5);
+    assert_eq!(transform.apply(v(0.7, 0.1)), by_hand);
+    let inverse = transform.inverse().unwrap();
+    assert_close(inverse.apply(v(0.25, 0.125)), v(-2.5, 2.5), TOLERANCE);
+}